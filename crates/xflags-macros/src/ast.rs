@@ -1,23 +1,85 @@
 #[derive(Debug)]
 pub(crate) struct XFlags {
     pub(crate) src: Option<String>,
+    pub(crate) config: Option<String>,
+    pub(crate) env_prefix: Option<String>,
+    /// Path of a `.env` file with values for environment variables.
+    pub(crate) dotenv: Option<String>,
+    pub(crate) settings: Settings,
+    /// Match arms of `run!`, dispatching on the subcommand.
+    pub(crate) dispatch: Option<Dispatch>,
+    /// Set by `parse!`, which returns a `Result` instead of exiting.
+    pub(crate) parse: Option<Parse>,
     pub(crate) cmd: Cmd,
 }
 
+/// Opt-in parsing behaviors, which apply to the whole command tree.
+#[derive(Debug, Default)]
+pub(crate) struct Settings {
+    /// `-abc` is `-a -b -c`, and `-j4` is `-j 4`.
+    pub(crate) gnu_short_flags: bool,
+    /// `--flag=value` is `--flag value`.
+    pub(crate) flag_assign: bool,
+    /// Arguments after the first positional one are all positional.
+    pub(crate) strict_ordering: bool,
+    /// `--help` prints just the usage, to keep the help text out of the
+    /// binary.
+    pub(crate) strip_help: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct Parse {
+    /// Expression for the arguments to parse instead of the process ones.
+    pub(crate) args: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Dispatch {
+    /// Expression for the arguments to parse instead of the process ones.
+    pub(crate) args: Option<String>,
+    /// Name for the parsed root command, visible in the match arms.
+    pub(crate) binding: Option<String>,
+    pub(crate) arms: String,
+}
+
 impl XFlags {
     pub fn is_anon(&self) -> bool {
         self.cmd.name.is_empty()
     }
+
+    /// Whether the generated code is an expression rather than a list of
+    /// items.
+    pub fn is_expr(&self) -> bool {
+        self.is_anon() || self.dispatch.is_some()
+    }
+
+    /// Whether values of flags can come from somewhere besides the command
+    /// line. A named command can be mounted into one which has fallbacks.
+    pub fn has_fallbacks(&self) -> bool {
+        self.config.is_some() || self.env_prefix.is_some() || !self.is_anon()
+    }
 }
 
 #[derive(Debug)]
 pub(crate) struct Cmd {
     pub(crate) name: String,
     pub(crate) aliases: Vec<String>,
+    /// Old names, which still work but print a notice pointing to `name`.
+    pub(crate) deprecated_aliases: Vec<String>,
     pub(crate) doc: Option<String>,
     pub(crate) args: Vec<Arg>,
     pub(crate) flags: Vec<Flag>,
     pub(crate) subcommands: Vec<Cmd>,
+    pub(crate) validate: Option<String>,
+    /// Whether the standard `-v`/`-q` pair was declared with `verbosity`.
+    pub(crate) verbosity: bool,
+    /// Whether the `generate` builtin was declared.
+    pub(crate) generate: bool,
+    /// Whether the `color` builtin declared the `--color` flag.
+    pub(crate) color: bool,
+    /// Path to a command defined elsewhere, which handles the rest of the
+    /// arguments.
+    pub(crate) mount: Option<String>,
     pub(crate) default: bool,
     pub(crate) idx: u8,
 }
@@ -27,6 +89,19 @@ pub(crate) struct Arg {
     pub(crate) arity: Arity,
     pub(crate) doc: Option<String>,
     pub(crate) val: Val,
+    /// Whether to expand glob patterns in the values.
+    pub(crate) glob: Option<Glob>,
+    /// Declared with `repeated!`, at least one value is required.
+    pub(crate) non_empty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Glob {
+    Always,
+    /// Only on Windows, where the shell doesn't expand patterns.
+    Windows,
+    /// Patterns in values of flags can match nothing.
+    Lenient,
 }
 
 #[derive(Debug)]
@@ -36,12 +111,36 @@ pub(crate) struct Flag {
     pub(crate) short: Option<String>,
     pub(crate) doc: Option<String>,
     pub(crate) val: Option<Val>,
+    /// Whether to ask the user for the value if it is missing.
+    pub(crate) prompt: Option<Prompt>,
+    pub(crate) glob: Option<Glob>,
+    /// Representation of a repeated switch other than the default `u32`.
+    pub(crate) count: Option<Count>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Count {
+    pub(crate) ty: String,
+    /// Match arms mapping the number of occurrences to a value of `ty`.
+    /// Without them, `ty` is an integer type.
+    pub(crate) arms: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Prompt {
+    Echo,
+    Hidden,
 }
 
 impl Flag {
     pub(crate) fn is_help(&self) -> bool {
         self.name == "help"
     }
+
+    /// Whether the value is optional and attached with `=`.
+    pub(crate) fn is_bool(&self) -> bool {
+        matches!(&self.val, Some(Val { ty: Ty::Bool, .. }))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,5 +160,20 @@ pub(crate) struct Val {
 pub(crate) enum Ty {
     PathBuf,
     OsString,
+    /// A switch which can be given an explicit value, like `--color=false`.
+    Bool,
+    /// `std::time::Duration`, which is parsed from values like `1h30m`.
+    Duration,
+    /// A `PathBuf` which is checked to exist, or not exist, while parsing.
+    CheckedPath(PathCheck),
     FromStr(String),
+    /// A type implementing `TryFrom<&OsStr>`, declared with `from OsStr`.
+    FromOsStr(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PathCheck {
+    ExistingFile,
+    ExistingDir,
+    NewPath,
 }
@@ -1,4 +1,4 @@
-use crate::{ast, update};
+use crate::{ast, generate, update};
 
 use std::{env, fmt::Write, path::Path};
 
@@ -7,26 +7,27 @@ macro_rules! w {
 }
 
 pub(crate) fn emit(xflags: &ast::XFlags) -> String {
-    let mut buf = String::new();
-
-    if xflags.is_anon() {
-        w!(buf, "{{\n");
-    }
-
-    emit_cmd(&mut buf, &xflags.cmd);
-    blank_line(&mut buf);
-    emit_api(&mut buf, xflags);
-
-    if !xflags.is_anon() && env::var("UPDATE_XFLAGS").is_ok() {
+    let mut api = String::new();
+    emit_cmd(&mut api, &xflags.cmd, xflags.is_anon());
+    blank_line(&mut api);
+    emit_field_enum(&mut api, &xflags.cmd);
+    blank_line(&mut api);
+    emit_api(&mut api, xflags);
+
+    if env::var("UPDATE_XFLAGS").is_ok() {
         if let Some(src) = &xflags.src {
-            update::in_place(&buf, Path::new(src.as_str()))
-        } else {
-            update::stdout(&buf);
+            update::in_place(&api, Path::new(src.as_str()))
+        } else if !xflags.is_expr() {
+            update::stdout(&api);
         }
     }
 
-    if xflags.src.is_some() {
-        buf.clear()
+    let mut buf = String::new();
+    if xflags.is_expr() {
+        w!(buf, "{{\n");
+    }
+    if xflags.src.is_none() {
+        buf.push_str(&api);
     }
 
     blank_line(&mut buf);
@@ -34,18 +35,38 @@ pub(crate) fn emit(xflags: &ast::XFlags) -> String {
     emit_help(&mut buf, xflags);
 
     if xflags.is_anon() {
-        w!(buf, "Flags::from_env_or_exit()");
+        match &xflags.parse {
+            None => w!(buf, "Flags::from_env_or_exit()"),
+            Some(ast::Parse { args: None }) => w!(buf, "Flags::from_env()"),
+            Some(ast::Parse { args: Some(args) }) => w!(buf, "Flags::from_vec({args})"),
+        }
+        w!(buf, "}}\n");
+    }
+
+    if let Some(dispatch) = &xflags.dispatch {
+        let binding = dispatch.binding.as_deref().unwrap_or("flags_");
+        let ident = xflags.cmd.ident();
+        match &dispatch.args {
+            None => w!(buf, "let {binding} = {ident}::from_env_or_exit();\n"),
+            Some(args) => {
+                w!(
+                    buf,
+                    "let {binding} = {ident}::from_vec({args}).unwrap_or_else(|err| err.exit());\n"
+                )
+            }
+        }
+        w!(buf, "match {binding}.subcommand {{ {} }}\n", dispatch.arms);
         w!(buf, "}}\n");
     }
 
     buf
 }
 
-fn emit_cmd(buf: &mut String, cmd: &ast::Cmd) {
+fn emit_cmd(buf: &mut String, cmd: &ast::Cmd, anon: bool) {
     w!(buf, "#[derive(Debug)]\n");
     w!(buf, "pub struct {}", cmd.ident());
-    let flags = cmd.flags.iter().filter(|it| !it.is_help()).collect::<Vec<_>>();
-    if cmd.args.is_empty() && flags.is_empty() && cmd.subcommands.is_empty() {
+    let flags = cmd.field_flags().collect::<Vec<_>>();
+    if cmd.args.is_empty() && flags.is_empty() && !cmd.verbosity && cmd.subcommands.is_empty() {
         w!(buf, ";\n");
         return;
     }
@@ -56,14 +77,17 @@ fn emit_cmd(buf: &mut String, cmd: &ast::Cmd) {
         w!(buf, "    pub {}: {ty},\n", arg.val.ident());
     }
 
-    if !cmd.args.is_empty() && !flags.is_empty() {
+    if !cmd.args.is_empty() && (!flags.is_empty() || cmd.verbosity) {
         blank_line(buf);
     }
 
     for flag in &flags {
-        let ty = gen_flag_ty(flag.arity, flag.val.as_ref().map(|it| &it.ty));
+        let ty = gen_flag_ty(flag);
         w!(buf, "    pub {}: {ty},\n", flag.ident());
     }
+    if cmd.verbosity {
+        w!(buf, "    pub verbosity: i32,\n");
+    }
 
     if cmd.has_subcommands() {
         w!(buf, "    pub subcommand: {},\n", cmd.cmd_enum_ident());
@@ -76,25 +100,87 @@ fn emit_cmd(buf: &mut String, cmd: &ast::Cmd) {
         w!(buf, "pub enum {} {{\n", cmd.cmd_enum_ident());
         for sub in &cmd.subcommands {
             let name = sub.ident();
-            w!(buf, "    {name}({name}),\n");
+            let ty = sub.mount.as_deref().unwrap_or(&name);
+            w!(buf, "    {name}({ty}),\n");
         }
         w!(buf, "}}\n");
 
-        for sub in &cmd.subcommands {
+        if anon {
+            blank_line(buf);
+            emit_subcommand_accessors(buf, cmd);
+        }
+
+        for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
             blank_line(buf);
-            emit_cmd(buf, sub);
+            emit_cmd(buf, sub, anon);
         }
     }
 }
 
-fn gen_flag_ty(arity: ast::Arity, ty: Option<&ast::Ty>) -> String {
-    match ty {
-        None => match arity {
+/// Types generated by `parse_or_exit!` can't be named, so the subcommand is
+/// accessed through methods instead of matching on the enum.
+fn emit_subcommand_accessors(buf: &mut String, cmd: &ast::Cmd) {
+    w!(buf, "impl {} {{\n", cmd.ident());
+    for sub in &cmd.subcommands {
+        let name = sub.ident();
+        let ty = sub.mount.as_deref().unwrap_or(&name);
+        w!(buf, "    #[allow(dead_code, unreachable_patterns)]\n");
+        w!(buf, "    pub fn {}(&self) -> Option<&{ty}> {{\n", snake(&sub.name));
+        w!(buf, "        match &self.subcommand {{\n");
+        w!(buf, "            {}::{}(it) => Some(it),\n", cmd.cmd_enum_ident(), sub.ident());
+        w!(buf, "            _ => None,\n");
+        w!(buf, "        }}\n");
+        w!(buf, "    }}\n");
+    }
+    w!(buf, "}}\n");
+}
+
+fn emit_field_enum(buf: &mut String, cmd: &ast::Cmd) {
+    let fields = cmd.fields();
+    w!(buf, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+    if fields.iter().any(|it| it.variant.contains('_')) {
+        w!(buf, "#[allow(non_camel_case_types)]\n");
+    }
+    w!(buf, "pub enum {} {{\n", cmd.field_enum_ident());
+    for field in &fields {
+        w!(buf, "    {},\n", field.variant);
+    }
+    w!(buf, "}}\n");
+    blank_line(buf);
+    w!(buf, "impl From<{}> for usize {{\n", cmd.field_enum_ident());
+    w!(buf, "    fn from(field: {}) -> usize {{\n", cmd.field_enum_ident());
+    w!(buf, "        match field {{\n");
+    for (i, field) in fields.iter().enumerate() {
+        w!(buf, "            {}::{} => {i},\n", cmd.field_enum_ident(), field.variant);
+    }
+    w!(buf, "        }}\n");
+    w!(buf, "    }}\n");
+    w!(buf, "}}\n");
+}
+
+fn gen_flag_ty(flag: &ast::Flag) -> String {
+    match &flag.val {
+        None => match flag.arity {
             ast::Arity::Optional => "bool".to_string(),
             ast::Arity::Required => "()".to_string(),
-            ast::Arity::Repeated => "u32".to_string(),
+            ast::Arity::Repeated => match &flag.count {
+                Some(count) => count.ty.clone(),
+                None => "u32".to_string(),
+            },
         },
-        Some(ty) => gen_arg_ty(arity, ty),
+        Some(val) => gen_arg_ty(flag.arity, &val.ty),
+    }
+}
+
+/// Converts the number of occurrences of a repeated switch, a `usize`, into
+/// the type of the field.
+fn gen_count(flag: &ast::Flag, count: &str) -> String {
+    match &flag.count {
+        None => format!("{count} as u32"),
+        Some(ast::Count { ty, arms: None }) => format!("{count} as {ty}"),
+        Some(ast::Count { ty, arms: Some(arms) }) => {
+            format!("{{ #[allow(unused_imports)] use {ty}::*; match {count} {{ {arms} }} }}")
+        }
     }
 }
 
@@ -102,7 +188,10 @@ fn gen_arg_ty(arity: ast::Arity, ty: &ast::Ty) -> String {
     let ty = match ty {
         ast::Ty::PathBuf => "PathBuf".into(),
         ast::Ty::OsString => "OsString".into(),
-        ast::Ty::FromStr(it) => it.clone(),
+        ast::Ty::Bool => "bool".into(),
+        ast::Ty::Duration => "std::time::Duration".into(),
+        ast::Ty::CheckedPath(_) => "std::path::PathBuf".into(),
+        ast::Ty::FromStr(it) | ast::Ty::FromOsStr(it) => it.clone(),
     };
     match arity {
         ast::Arity::Optional => format!("Option<{}>", ty),
@@ -130,7 +219,133 @@ fn emit_api(buf: &mut String, xflags: &ast::XFlags) {
     w!(buf, "    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {{\n");
     w!(buf, "        Self::from_vec_(args)\n");
     w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {{\n");
+    w!(buf, "        Self::from_slice_(args)\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(
+        buf,
+        "    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {{\n"
+    );
+    w!(buf, "        self.apply_(args)\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {{\n");
+    w!(buf, "        xflags::ParseOutcome::from_result(Self::from_env_())\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(
+        buf,
+        "    pub fn from_vec_outcome(args: Vec<std::ffi::OsString>) -> xflags::Result<xflags::ParseOutcome<Self>> {{\n"
+    );
+    w!(buf, "        xflags::ParseOutcome::from_result(Self::from_vec_(args))\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    let sources = format!("xflags::Sources<{}>", xflags.cmd.field_enum_ident());
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn from_env_with_sources() -> xflags::Result<(Self, {sources})> {{\n");
+    w!(buf, "        Self::from_env_with_sources_()\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(
+        buf,
+        "    pub fn from_vec_with_sources(args: Vec<std::ffi::OsString>) -> xflags::Result<(Self, {sources})> {{\n"
+    );
+    w!(buf, "        Self::from_vec_with_sources_(args)\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(
+        buf,
+        "    pub fn from_vec_lenient(args: Vec<std::ffi::OsString>) -> xflags::Result<(Self, Vec<xflags::Warning>)> {{\n"
+    );
+    w!(buf, "        Self::from_vec_lenient_(args)\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    emit_help_api(buf, "Self", "");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn set_help_epilogue(epilogue: impl Into<String>) {{\n");
+    w!(buf, "        Self::help_epilogue_().set(epilogue.into())\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn subcommand_path(&self) -> Vec<&'static str> {{\n");
+    w!(buf, "        self.subcommand_path_()\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn spec_json() -> &'static str {{\n");
+    w!(buf, "        Self::SPEC_JSON_\n");
+    w!(buf, "    }}\n");
+    emit_log_level_api(buf, &xflags.cmd);
+    if xflags.cmd.color {
+        blank_line(buf);
+        w!(buf, "    #[allow(dead_code)]\n");
+        w!(buf, "    pub fn color_choice(&self) -> xflags::ColorChoice {{\n");
+        w!(buf, "        self.color.unwrap_or_default()\n");
+        w!(buf, "    }}\n");
+    }
     w!(buf, "}}\n");
+
+    let root = xflags.cmd.ident();
+    for sub in xflags.cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
+        emit_help_api_rec(buf, &root, sub, &format!("{}__", sub.name));
+    }
+}
+
+fn emit_help_api_rec(buf: &mut String, root: &str, cmd: &ast::Cmd, prefix: &str) {
+    blank_line(buf);
+    w!(buf, "impl {} {{\n", cmd.ident());
+    emit_help_api(buf, root, prefix);
+    emit_log_level_api(buf, cmd);
+    w!(buf, "}}\n");
+    for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
+        emit_help_api_rec(buf, root, sub, &format!("{prefix}{}__", sub.name));
+    }
+}
+
+fn emit_log_level_api(buf: &mut String, cmd: &ast::Cmd) {
+    if !cmd.verbosity {
+        return;
+    }
+    blank_line(buf);
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn log_level(&self) -> xflags::LogLevel {{\n");
+    w!(buf, "        xflags::LogLevel::from_verbosity(self.verbosity)\n");
+    w!(buf, "    }}\n");
+}
+
+fn emit_help_api(buf: &mut String, root: &str, prefix: &str) {
+    let const_prefix = snake(prefix).to_uppercase();
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn help_message() -> &'static xflags::Help {{\n");
+    w!(buf, "        &{root}::HELP_{const_prefix}\n");
+    w!(buf, "    }}\n");
+    blank_line(buf);
+
+    w!(buf, "    #[allow(dead_code)]\n");
+    w!(buf, "    pub fn usage() -> &'static str {{\n");
+    w!(buf, "        {root}::USAGE_{const_prefix}\n");
+    w!(buf, "    }}\n");
 }
 
 fn emit_impls(buf: &mut String, xflags: &ast::XFlags) {
@@ -139,67 +354,752 @@ fn emit_impls(buf: &mut String, xflags: &ast::XFlags) {
     w!(buf, "        Self::from_env_().unwrap_or_else(|err| err.exit())\n");
     w!(buf, "    }}\n");
     w!(buf, "    fn from_env_() -> xflags::Result<Self> {{\n");
-    w!(buf, "        let mut p = xflags::rt::Parser::new_from_env();\n");
+    w!(buf, "        let mut p = xflags::rt::Parser::new_from_env()?;\n");
     w!(buf, "        Self::parse_(&mut p)\n");
     w!(buf, "    }}\n");
     w!(buf, "    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {{\n");
     w!(buf, "        let mut p = xflags::rt::Parser::new(args);\n");
     w!(buf, "        Self::parse_(&mut p)\n");
     w!(buf, "    }}\n");
+    w!(buf, "    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {{\n");
+    w!(buf, "        let mut p = xflags::rt::Parser::new_from_slice(args);\n");
+    w!(buf, "        Self::parse_(&mut p)\n");
+    w!(buf, "    }}\n");
+    let sources = format!("xflags::Sources<{}>", xflags.cmd.field_enum_ident());
+    w!(buf, "    fn from_env_with_sources_() -> xflags::Result<(Self, {sources})> {{\n");
+    w!(buf, "        let mut p = xflags::rt::Parser::new_from_env()?;\n");
+    w!(buf, "        let flags = Self::parse_(&mut p)?;\n");
+    w!(buf, "        Ok((flags, p.sources()))\n");
+    w!(buf, "    }}\n");
+    w!(
+        buf,
+        "    fn from_vec_with_sources_(args: Vec<std::ffi::OsString>) -> xflags::Result<(Self, {sources})> {{\n"
+    );
+    w!(buf, "        let mut p = xflags::rt::Parser::new(args);\n");
+    w!(buf, "        let flags = Self::parse_(&mut p)?;\n");
+    w!(buf, "        Ok((flags, p.sources()))\n");
+    w!(buf, "    }}\n");
+    w!(
+        buf,
+        "    fn from_vec_lenient_(args: Vec<std::ffi::OsString>) -> xflags::Result<(Self, Vec<xflags::Warning>)> {{\n"
+    );
+    w!(buf, "        let mut p = xflags::rt::Parser::new_lenient(args);\n");
+    w!(buf, "        let flags = Self::parse_(&mut p)?;\n");
+    w!(buf, "        Ok((flags, p.warnings()))\n");
+    w!(buf, "    }}\n");
+    w!(buf, "    fn help_epilogue_() -> &'static xflags::rt::Epilogue {{\n");
+    w!(buf, "        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();\n");
+    w!(buf, "        &EPILOGUE\n");
+    w!(buf, "    }}\n");
     w!(buf, "}}\n");
     blank_line(buf);
-    emit_parse(buf, &xflags.cmd)
+    emit_parse(buf, xflags);
+    blank_line(buf);
+    emit_apply(buf, &xflags.cmd, &xflags.settings);
+    blank_line(buf);
+    emit_subcommand_path(buf, &xflags.cmd);
+    if !xflags.is_anon() {
+        blank_line(buf);
+        w!(buf, "impl xflags::rt::Mount for {} {{\n", xflags.cmd.ident());
+        w!(buf, "    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {{\n");
+        w!(buf, "        Self::parse_(p_)\n");
+        w!(buf, "    }}\n");
+        w!(buf, "}}\n");
+    }
+}
+
+fn emit_subcommand_path(buf: &mut String, cmd: &ast::Cmd) {
+    w!(buf, "impl {} {{\n", cmd.ident());
+    w!(buf, "fn subcommand_path_(&self) -> Vec<&'static str> {{\n");
+    let root = if cmd.name.is_empty() { String::new() } else { format!("\"{}\"", cmd.name) };
+    if cmd.has_subcommands() {
+        w!(buf, "let mut res_ = vec![{root}];\n");
+        emit_subcommand_path_rec(buf, cmd, "self");
+        w!(buf, "res_\n");
+    } else {
+        w!(buf, "vec![{root}]\n");
+    }
+    w!(buf, "}}\n");
+    w!(buf, "}}\n");
+}
+
+fn emit_subcommand_path_rec(buf: &mut String, cmd: &ast::Cmd, this: &str) {
+    if !cmd.has_subcommands() {
+        return;
+    }
+    w!(buf, "match &{this}.subcommand {{\n");
+    for sub in &cmd.subcommands {
+        let binding = if sub.has_subcommands() || sub.mount.is_some() { "cmd_" } else { "_" };
+        w!(buf, "{}::{}({binding}) => {{\n", cmd.cmd_enum_ident(), sub.ident());
+        w!(buf, "res_.push(\"{}\");\n", sub.name);
+        if sub.mount.is_some() {
+            w!(buf, "res_.extend(cmd_.subcommand_path().into_iter().skip(1));\n");
+        }
+        emit_subcommand_path_rec(buf, sub, "cmd_");
+        w!(buf, "}}\n");
+    }
+    w!(buf, "}}\n");
+}
+
+fn emit_parse(buf: &mut String, xflags: &ast::XFlags) {
+    let cmd = &xflags.cmd;
+    w!(buf, "impl {} {{\n", cmd.ident());
+    let flags = flag_names(cmd).iter().map(|it| format!("{it:?}")).collect::<Vec<_>>().join(", ");
+    w!(buf, "const FLAGS_: &'static [&'static str] = &[{flags}];\n");
+    w!(buf, "fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {{\n");
+    if let Some(env_prefix) = &xflags.env_prefix {
+        w!(buf, "p_.load_env({env_prefix:?});\n");
+        if let Some(dotenv) = &xflags.dotenv {
+            w!(buf, "p_.load_dotenv({env_prefix:?}, {dotenv:?})?;\n");
+        }
+    }
+    if let Some(config) = &xflags.config {
+        w!(buf, "p_.load_config({config:?})?;\n");
+    }
+    if cmd.color {
+        w!(buf, "p_.scan_color({});\n", xflags.settings.flag_assign);
+    }
+    w!(buf, "let mut state_ = 0u8;\n");
+    w!(buf, "Self::parse_state_(p_, &mut state_)");
+    w!(buf, ".map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))\n");
+    w!(buf, "}}\n");
+    blank_line(buf);
+
+    w!(buf, "fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {{\n");
+    w!(buf, "#![allow(non_snake_case, unused_mut)]\n");
+
+    let mut prefix = String::new();
+    emit_parse_loop(buf, &mut prefix, cmd, &xflags.settings);
+    emit_default_transitions(buf, cmd);
+    for (i, field) in cmd.fields().iter().enumerate() {
+        w!(
+            buf,
+            "if !{}.is_empty() {{ p_.set_source({i}, xflags::ValueSource::CommandLine); }}\n",
+            field.values
+        );
+    }
+    if xflags.has_fallbacks() {
+        emit_fallbacks_rec(buf, &mut prefix, &mut Vec::new(), &mut 0, cmd);
+    }
+    if cmd.has_prompts() {
+        emit_prompts_rec(buf, &mut prefix, &mut 0, cmd);
+    }
+    emit_check_rec(buf, &mut prefix, cmd, false);
+
+    w!(buf, "Ok(");
+    emit_record_rec(buf, &mut prefix, cmd);
+    w!(buf, ")");
+
+    w!(buf, "}}\n");
+    w!(buf, "}}\n");
+}
+
+/// Fills flags which weren't specified on the command line from other sources.
+fn emit_fallbacks_rec<'a>(
+    buf: &mut String,
+    prefix: &mut String,
+    path: &mut Vec<&'a str>,
+    field: &mut usize,
+    cmd: &'a ast::Cmd,
+) {
+    let path_lit = path.iter().map(|it| format!("\"{it}\"")).collect::<Vec<_>>().join(", ");
+    // `-v` and `-q` exclude each other, so neither falls back when the
+    // command line has one of them.
+    let verbosity_given = format!("{prefix}verbosity_given_");
+    if cmd.verbosity {
+        let (verbose, quiet) = (format!("l_.{prefix}verbose"), format!("l_.{prefix}quiet"));
+        w!(buf, "let {verbosity_given} = !{verbose}.is_empty() || !{quiet}.is_empty();\n");
+    }
+    for flag in cmd.flags.iter().filter(|it| !it.is_help()) {
+        let local = format!("l_.{prefix}{}", flag.ident());
+        let missing = if cmd.verbosity && matches!(&*flag.name, "verbose" | "quiet") {
+            format!("!{verbosity_given}")
+        } else {
+            format!("{local}.is_empty()")
+        };
+        if cmd.idx == 0 {
+            w!(buf, "if {missing} {{\n");
+        } else {
+            w!(buf, "if matches!(*state_, ");
+            emit_all_ids_rec(buf, cmd);
+            w!(buf, ") && {missing} {{\n");
+        }
+        w!(buf, "let values_ = p_.fallback({field}, &[{path_lit}], \"{}\");\n", flag.name);
+        match (&flag.val, flag.glob) {
+            (Some(_), Some(glob)) => {
+                let (windows_only, lenient) =
+                    (glob == ast::Glob::Windows, glob == ast::Glob::Lenient);
+                w!(buf, "for value_ in values_ {{ {local}.extend(");
+                w!(buf, "p_.glob_value(\"--{}\", value_, {windows_only}, {lenient})?", flag.name);
+                w!(buf, "); }}\n");
+            }
+            (Some(val), None) => {
+                w!(buf, "for value_ in values_ {{ {local}.push(");
+                emit_value(buf, &val.ty, "value_", &format!("\"--{}\"", flag.name));
+                w!(buf, "); }}\n");
+            }
+            (None, _) => {
+                let optional = flag.arity != ast::Arity::Repeated;
+                w!(buf, "for _ in 0..p_.switch_count(\"--{}\", values_, {optional})? ", flag.name);
+                w!(buf, "{{ {local}.push(()); }}\n");
+            }
+        }
+        w!(buf, "}}\n");
+        *field += 1;
+    }
+    *field += cmd.args.len();
+    for sub in &cmd.subcommands {
+        let l = sub.push_prefix(prefix);
+        path.push(&sub.name);
+        emit_fallbacks_rec(buf, prefix, path, field, sub);
+        path.pop();
+        prefix.truncate(l);
+    }
+}
+
+/// Asks for values of missing flags declared with `prompt`.
+fn emit_prompts_rec(buf: &mut String, prefix: &mut String, field: &mut usize, cmd: &ast::Cmd) {
+    for flag in cmd.flags.iter().filter(|it| !it.is_help()) {
+        if let (Some(prompt), Some(val)) = (flag.prompt, &flag.val) {
+            let local = format!("l_.{prefix}{}", flag.ident());
+            w!(buf, "if matches!(*state_, ");
+            emit_all_ids_rec(buf, cmd);
+            w!(buf, ") && {local}.is_empty() {{\n");
+            let hidden = prompt == ast::Prompt::Hidden;
+            w!(buf, "if let Some(value_) = p_.prompt({field}, \"{}\", {hidden})? {{\n", flag.name);
+            w!(buf, "{local}.push(");
+            emit_value(buf, &val.ty, "value_", &format!("\"--{}\"", flag.name));
+            w!(buf, ");\n");
+            w!(buf, "}}\n");
+            w!(buf, "}}\n");
+        }
+        *field += 1;
+    }
+    *field += cmd.args.len();
+    for sub in &cmd.subcommands {
+        let l = sub.push_prefix(prefix);
+        emit_prompts_rec(buf, prefix, field, sub);
+        prefix.truncate(l);
+    }
+}
+
+/// Converts `value`, an `OsString`, into `ty`.
+fn emit_value(buf: &mut String, ty: &ast::Ty, value: &str, flag: &str) {
+    match ty {
+        ast::Ty::OsString => w!(buf, "{value}"),
+        ast::Ty::PathBuf => w!(buf, "{value}.into()"),
+        ast::Ty::Bool => w!(buf, "p_.value_bool({flag}, {value})?"),
+        ast::Ty::Duration => w!(buf, "p_.value_duration({flag}, {value})?"),
+        ast::Ty::CheckedPath(check) => {
+            w!(buf, "p_.checked_path({flag}, {value}, xflags::rt::PathCheck::{check:?})?")
+        }
+        ast::Ty::FromStr(ty) => w!(buf, "p_.value_from_str::<{ty}>({flag}, {value})?"),
+        ast::Ty::FromOsStr(ty) => w!(buf, "p_.value_from_os_str::<{ty}>({flag}, {value})?"),
+    }
+}
+
+fn emit_apply(buf: &mut String, cmd: &ast::Cmd, settings: &ast::Settings) {
+    w!(buf, "impl {} {{\n", cmd.ident());
+    w!(buf, "fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {{\n");
+    w!(buf, "let mut p_ = xflags::rt::Parser::new(args);\n");
+    w!(buf, "let mut state_ = self.state_();\n");
+    w!(buf, "self.apply_state_(&mut p_, &mut state_)");
+    w!(buf, ".map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))\n");
+    w!(buf, "}}\n");
+    blank_line(buf);
+
+    w!(
+        buf,
+        "fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {{\n"
+    );
+    // The validators see the merged values, so the previous ones are kept
+    // aside to be put back if any of them fails.
+    let mut validate = String::new();
+    emit_validate_rec(&mut validate, cmd, "self");
+    if validate.is_empty() {
+        w!(buf, "#![allow(non_snake_case, unused_mut)]\n");
+    } else {
+        w!(buf, "#![allow(non_snake_case, unused_mut, clippy::field_reassign_with_default)]\n");
+    }
+
+    let mut prefix = String::new();
+    emit_parse_loop(buf, &mut prefix, cmd, settings);
+    emit_check_rec(buf, &mut prefix, cmd, true);
+    emit_verbosity_check_rec(buf, &mut prefix, cmd, "self");
+    if validate.is_empty() {
+        emit_merge_rec(buf, &mut prefix, cmd, "self", false);
+        w!(buf, "Ok(())\n");
+    } else {
+        let mut fields = String::new();
+        emit_prev_rec(&mut fields, &mut prefix, cmd);
+        w!(buf, "#[derive(Default)]\n");
+        w!(buf, "struct Prev_ {{\n{fields}}}\n");
+        w!(buf, "let mut b_ = Prev_::default();\n");
+        emit_merge_rec(buf, &mut prefix, cmd, "self", true);
+        w!(buf, "if let Err(err_) = self.validate_() {{\n");
+        emit_restore_rec(buf, &mut prefix, cmd, "self");
+        w!(buf, "return Err(err_);\n");
+        w!(buf, "}}\n");
+        w!(buf, "Ok(())\n");
+    }
+    w!(buf, "}}\n");
+    blank_line(buf);
+
+    if !validate.is_empty() {
+        w!(buf, "fn validate_(&self) -> xflags::Result<()> {{\n{validate}Ok(())\n}}\n");
+        blank_line(buf);
+    }
+
+    w!(buf, "fn state_(&self) -> u8 {{\n");
+    emit_state_rec(buf, cmd, "self");
+    w!(buf, "}}\n");
+    w!(buf, "}}\n");
+}
+
+fn emit_parse_loop(
+    buf: &mut String,
+    prefix: &mut String,
+    cmd: &ast::Cmd,
+    settings: &ast::Settings,
+) {
+    emit_locals(buf, cmd);
+    blank_line(buf);
+
+    // No while loop needed for command with no items (clippy::never_loop)
+    if cmd.args.len() + cmd.flags.len() + cmd.subcommands.len() <= 1 && !settings.gnu_short_flags {
+        w!(buf, "if let Some(arg_) = p_.pop_flag() {{\n");
+    } else {
+        w!(buf, "while let Some(arg_) = p_.pop_flag() {{\n");
+    }
+
+    w!(buf, "match arg_ {{\n");
+    {
+        w!(buf, "Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {{\n");
+        emit_match_flag_rec(buf, prefix, cmd, settings, &flag_names(cmd));
+        w!(buf, "_ => p_.unexpected_flag(&flag_)?,\n");
+        w!(buf, "}}\n");
+
+        w!(buf, "Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {{\n");
+        emit_match_arg_rec(buf, prefix, &mut Vec::new(), cmd, settings);
+        w!(buf, "_ => return Err(p_.unexpected_arg(arg_)),\n");
+        w!(buf, "}}\n");
+    }
+    w!(buf, "}}\n");
+    w!(buf, "}}\n");
+    emit_distribute_rec(buf, prefix, cmd);
+}
+
+/// Hands out the deferred positional arguments of commands where they can't
+/// be taken in order, like `cp SRC... DST`. Each required argument reserves a
+/// value, the rest go to the other arguments in order.
+fn emit_distribute_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
+    if cmd.has_flexible_args() {
+        w!(buf, "{{\n");
+        w!(buf, "let mut pending_ = std::mem::take(&mut l_.{prefix}pending_).into_iter();\n");
+        let n_required =
+            cmd.args.iter().filter(|it| it.arity == ast::Arity::Required || it.non_empty).count();
+        let n_extra = cmd.args.iter().filter(|it| it.arity != ast::Arity::Required).count();
+        w!(buf, "let mut extra_ = pending_.len().saturating_sub({n_required});\n");
+        let mut n_extra_seen = 0;
+        for arg in &cmd.args {
+            let local = format!("l_.{prefix}{}.1", arg.val.ident());
+            match arg.arity {
+                ast::Arity::Required => w!(buf, "if let Some(arg_) = pending_.next() {{\n"),
+                ast::Arity::Optional => {
+                    w!(
+                        buf,
+                        "if let Some(arg_) = (extra_ > 0).then(|| pending_.next()).flatten() {{\n"
+                    );
+                }
+                ast::Arity::Repeated => {
+                    let n = if arg.non_empty { "extra_ + 1" } else { "extra_" };
+                    w!(buf, "for arg_ in pending_.by_ref().take({n}) {{\n");
+                }
+            }
+            w!(buf, "let arg_ = p_.resume_arg(arg_);\n");
+            match arg.glob {
+                Some(glob) => {
+                    let windows_only = glob == ast::Glob::Windows;
+                    w!(buf, "{local}.extend(p_.glob(arg_, {windows_only}));\n");
+                }
+                None => {
+                    w!(buf, "{local}.push(");
+                    emit_value(buf, &arg.val.ty, "arg_", &format!("\"{}\"", arg.val.name));
+                    w!(buf, ");\n");
+                }
+            }
+            w!(buf, "}}\n");
+            if arg.arity != ast::Arity::Required {
+                n_extra_seen += 1;
+                // Nothing is left for the arguments after the repeated one.
+                if n_extra_seen < n_extra {
+                    match arg.arity {
+                        ast::Arity::Repeated => w!(buf, "extra_ = 0;\n"),
+                        _ => w!(buf, "extra_ = extra_.saturating_sub(1);\n"),
+                    }
+                }
+            }
+        }
+        w!(buf, "}}\n");
+    }
+    for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
+        let l = sub.push_prefix(prefix);
+        emit_distribute_rec(buf, prefix, sub);
+        prefix.truncate(l);
+    }
+}
+
+/// Checks the number of values of flags and arguments, before any of them
+/// are taken out of the locals. When `apply`ing, nothing is required.
+fn emit_check_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd, apply: bool) {
+    let mut checks = String::new();
+    for flag in cmd.flags.iter().filter(|it| !it.is_help()) {
+        let local = format!("l_.{prefix}{}", flag.ident());
+        match flag.arity {
+            ast::Arity::Required if !apply => {
+                w!(checks, "p_.check_required(\"--{}\", {local}.len())?;\n", flag.name)
+            }
+            ast::Arity::Optional | ast::Arity::Required => {
+                w!(checks, "p_.check_optional(\"--{}\", {local}.len())?;\n", flag.name)
+            }
+            ast::Arity::Repeated => (),
+        }
+    }
+    if cmd.verbosity {
+        let (verbose, quiet) = (format!("l_.{prefix}verbose"), format!("l_.{prefix}quiet"));
+        w!(
+            checks,
+            "p_.check_exclusive(\"--verbose\", {verbose}.len(), \"--quiet\", {quiet}.len())?;\n"
+        );
+    }
+    for arg in &cmd.args {
+        let local = format!("l_.{prefix}{}.1", arg.val.ident());
+        match arg.arity {
+            ast::Arity::Required if !apply => {
+                w!(checks, "p_.check_required(\"{}\", {local}.len())?;\n", arg.val.name)
+            }
+            ast::Arity::Optional | ast::Arity::Required => {
+                w!(checks, "p_.check_optional(\"{}\", {local}.len())?;\n", arg.val.name)
+            }
+            ast::Arity::Repeated if arg.non_empty && !apply => {
+                w!(checks, "p_.check_non_empty(\"{}\", {local}.len())?;\n", arg.val.name)
+            }
+            ast::Arity::Repeated => (),
+        }
+    }
+    // Like the record, flags of a subcommand are only required when it's the
+    // one which was parsed.
+    if apply || cmd.idx == 0 || checks.is_empty() {
+        buf.push_str(&checks);
+    } else {
+        w!(buf, "if matches!(*state_, ");
+        emit_leaf_ids_rec(buf, cmd);
+        w!(buf, ") {{\n{checks}}}\n");
+    }
+    for sub in &cmd.subcommands {
+        let l = sub.push_prefix(prefix);
+        emit_check_rec(buf, prefix, sub, apply);
+        prefix.truncate(l);
+    }
+}
+
+/// Stores values collected by `apply_` into the existing `this` command. With
+/// `backup`, every value it replaces is kept in `b_` first, for
+/// `emit_restore_rec`.
+fn emit_merge_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd, this: &str, backup: bool) {
+    // Assigns `value` to `field`, keeping the previous value if needed.
+    let set = |field: &str, prev: &str, value: &str| {
+        if backup {
+            format!("{prev} = Some(std::mem::replace(&mut {field}, {value}));")
+        } else {
+            format!("{field} = {value};")
+        }
+    };
+    // Same for `Some(it)` into an optional field.
+    let set_some = |field: &str, prev: &str| {
+        if backup {
+            format!("{prev} = Some({field}.replace(it));")
+        } else {
+            format!("{field} = Some(it);")
+        }
+    };
+    if cmd.mount.is_some() {
+        let set = if backup {
+            format!("b_.{prefix}mounted_ = Some(std::mem::replace({this}, it));")
+        } else {
+            format!("*{this} = it;")
+        };
+        w!(buf, "if let Some(it) = l_.{prefix}mounted_.take() {{ {set} }}\n");
+        return;
+    }
+    for flag in cmd.field_flags() {
+        let local = format!("l_.{prefix}{}", flag.ident());
+        let field = format!("{this}.{}", flag.ident());
+        let prev = format!("b_.{prefix}{}", flag.ident());
+        match (&flag.val, flag.arity) {
+            (Some(_), ast::Arity::Optional) => {
+                let set = set_some(&field, &prev);
+                w!(buf, "if let Some(it) = {local}.pop() {{ {set} }}\n")
+            }
+            (Some(_), ast::Arity::Required) => {
+                let set = set(&field, &prev, "it");
+                w!(buf, "if let Some(it) = {local}.pop() {{ {set} }}\n")
+            }
+            (Some(_), ast::Arity::Repeated) => {
+                if backup {
+                    w!(buf, "{prev} = Some({field}.len());\n");
+                }
+                w!(buf, "{field}.extend(std::mem::take(&mut {local}));\n")
+            }
+            (None, ast::Arity::Optional) => {
+                let set = set(&field, &prev, "true");
+                w!(buf, "if !{local}.is_empty() {{ {set} }}\n")
+            }
+            (None, ast::Arity::Required) => (),
+            (None, ast::Arity::Repeated) => match &flag.count {
+                Some(ast::Count { arms: Some(_), .. }) => {
+                    let value = gen_count(flag, &format!("{local}.len()"));
+                    let set = set(&field, &prev, &value);
+                    w!(buf, "if !{local}.is_empty() {{ {set} }}\n")
+                }
+                _ => {
+                    if backup {
+                        w!(buf, "{prev} = Some({field});\n");
+                    }
+                    w!(buf, "{field} += {};\n", gen_count(flag, &format!("{local}.len()")))
+                }
+            },
+        }
+    }
+    if cmd.verbosity {
+        // `-q` resets the verbosity, each `-v` raises it further.
+        let (verbose, quiet) = (format!("l_.{prefix}verbose"), format!("l_.{prefix}quiet"));
+        let (field, prev) = (format!("{this}.verbosity"), format!("b_.{prefix}verbosity"));
+        w!(buf, "if !{quiet}.is_empty() {{ {} }}\n", set(&field, &prev, "-1"));
+        w!(buf, "else if !{verbose}.is_empty() {{\n");
+        if backup {
+            w!(buf, "{prev} = Some({field});\n");
+        }
+        w!(buf, "{field} += {verbose}.len() as i32;\n");
+        w!(buf, "}}\n");
+    }
+    for arg in &cmd.args {
+        let local = format!("l_.{prefix}{}.1", arg.val.ident());
+        let field = format!("{this}.{}", arg.val.ident());
+        let prev = format!("b_.{prefix}{}", arg.val.ident());
+        match arg.arity {
+            ast::Arity::Optional => {
+                let set = set_some(&field, &prev);
+                w!(buf, "if let Some(it) = {local}.pop() {{ {set} }}\n")
+            }
+            ast::Arity::Required => {
+                let set = set(&field, &prev, "it");
+                w!(buf, "if let Some(it) = {local}.pop() {{ {set} }}\n")
+            }
+            ast::Arity::Repeated => {
+                if backup {
+                    w!(buf, "{prev} = Some({field}.len());\n");
+                }
+                w!(buf, "{field}.extend(std::mem::take(&mut {local}));\n")
+            }
+        }
+    }
+    if cmd.has_subcommands() {
+        w!(buf, "match &mut {this}.subcommand {{\n");
+        for sub in &cmd.subcommands {
+            let mut sub_buf = String::new();
+            let l = sub.push_prefix(prefix);
+            emit_merge_rec(&mut sub_buf, prefix, sub, "cmd_", backup);
+            prefix.truncate(l);
+            let binding = if sub_buf.is_empty() { "_" } else { "cmd_" };
+            w!(buf, "{}::{}({binding}) => {{\n{sub_buf}}}\n", cmd.cmd_enum_ident(), sub.ident());
+        }
+        w!(buf, "}}\n");
+    }
 }
 
-fn emit_parse(buf: &mut String, cmd: &ast::Cmd) {
-    w!(buf, "impl {} {{\n", cmd.ident());
-    w!(buf, "fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {{\n");
-    w!(buf, "#![allow(non_snake_case, unused_mut)]\n");
+/// Rejects `-v` if `-q` was given before `apply`, and the other way around,
+/// like when both are given at once.
+fn emit_verbosity_check_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd, this: &str) {
+    if cmd.verbosity {
+        let (verbose, quiet) = (format!("l_.{prefix}verbose"), format!("l_.{prefix}quiet"));
+        let verbose = format!("{verbose}.len() + ({this}.verbosity > 0) as usize");
+        let quiet = format!("{quiet}.len() + ({this}.verbosity < 0) as usize");
+        w!(buf, "p_.check_exclusive(\"--verbose\", {verbose}, \"--quiet\", {quiet})?;\n");
+    }
+    let mut arms = Vec::new();
+    for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
+        let mut sub_buf = String::new();
+        let l = sub.push_prefix(prefix);
+        emit_verbosity_check_rec(&mut sub_buf, prefix, sub, "cmd_");
+        prefix.truncate(l);
+        if !sub_buf.is_empty() {
+            arms.push((format!("{}::{}(cmd_)", cmd.cmd_enum_ident(), sub.ident()), sub_buf));
+        }
+    }
+    match arms.as_slice() {
+        [] => (),
+        [(pat, body)] => w!(buf, "if let {pat} = &{this}.subcommand {{\n{body}}}\n"),
+        _ => {
+            w!(buf, "match &{this}.subcommand {{\n");
+            for (pat, body) in &arms {
+                w!(buf, "{pat} => {{\n{body}}}\n");
+            }
+            if arms.len() < cmd.subcommands.len() {
+                w!(buf, "_ => (),\n");
+            }
+            w!(buf, "}}\n");
+        }
+    }
+}
 
-    let mut prefix = String::new();
-    emit_locals_rec(buf, &mut prefix, cmd);
-    blank_line(buf);
-    w!(buf, "let mut state_ = 0u8;\n");
+/// Runs the validators of the command and of its current subcommand, the
+/// innermost first, like the record step does.
+fn emit_validate_rec(buf: &mut String, cmd: &ast::Cmd, this: &str) {
+    if cmd.has_subcommands() {
+        let mut arms = Vec::new();
+        for sub in &cmd.subcommands {
+            let mut sub_buf = String::new();
+            emit_validate_rec(&mut sub_buf, sub, "cmd_");
+            if !sub_buf.is_empty() {
+                arms.push((format!("{}::{}(cmd_)", cmd.cmd_enum_ident(), sub.ident()), sub_buf));
+            }
+        }
+        match arms.as_slice() {
+            [] => (),
+            [(pat, body)] => w!(buf, "if let {pat} = &{this}.subcommand {{\n{body}}}\n"),
+            _ => {
+                w!(buf, "match &{this}.subcommand {{\n");
+                for (pat, body) in &arms {
+                    w!(buf, "{pat} => {{\n{body}}}\n");
+                }
+                if arms.len() < cmd.subcommands.len() {
+                    w!(buf, "_ => (),\n");
+                }
+                w!(buf, "}}\n");
+            }
+        }
+    }
+    if let Some(validate) = &cmd.validate {
+        w!(buf, "{validate}({this})?;\n");
+    }
+}
 
-    // No while loop needed for command with no items (clippy::never_loop)
-    if cmd.args.len() + cmd.flags.len() + cmd.subcommands.len() <= 1 {
-        w!(buf, "if let Some(arg_) = p_.pop_flag() {{\n");
-    } else {
-        w!(buf, "while let Some(arg_) = p_.pop_flag() {{\n");
+/// Declares where `emit_merge_rec` keeps the values it replaces: the previous
+/// value of each field, or the previous length of repeated ones.
+fn emit_prev_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
+    if let Some(path) = &cmd.mount {
+        w!(buf, "{prefix}mounted_: Option<{path}>,\n");
+        return;
+    }
+    for flag in cmd.field_flags() {
+        let ty = match (&flag.val, flag.arity) {
+            (None, ast::Arity::Required) => continue,
+            (Some(_), ast::Arity::Repeated) => "usize".to_string(),
+            _ => gen_flag_ty(flag),
+        };
+        w!(buf, "{prefix}{}: Option<{ty}>,\n", flag.ident());
+    }
+    if cmd.verbosity {
+        w!(buf, "{prefix}verbosity: Option<i32>,\n");
+    }
+    for arg in &cmd.args {
+        let ty = match arg.arity {
+            ast::Arity::Repeated => "usize".to_string(),
+            arity => gen_arg_ty(arity, &arg.val.ty),
+        };
+        w!(buf, "{prefix}{}: Option<{ty}>,\n", arg.val.ident());
+    }
+    for sub in &cmd.subcommands {
+        let l = sub.push_prefix(prefix);
+        emit_prev_rec(buf, prefix, sub);
+        prefix.truncate(l);
     }
+}
 
-    w!(buf, "match arg_ {{\n");
-    {
-        w!(buf, "Ok(flag_) => match (state_, flag_.as_str()) {{\n");
-        emit_match_flag_rec(buf, &mut prefix, cmd);
-        w!(buf, "_ => return Err(p_.unexpected_flag(&flag_)),\n");
+/// Puts back the values kept by `emit_merge_rec`, undoing a failed `apply`.
+fn emit_restore_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd, this: &str) {
+    if cmd.mount.is_some() {
+        w!(buf, "if let Some(it) = b_.{prefix}mounted_.take() {{ *{this} = it; }}\n");
+        return;
+    }
+    let flags = cmd.field_flags().filter_map(|it| match (&it.val, it.arity) {
+        (None, ast::Arity::Required) => None,
+        (val, arity) => Some((it.ident(), val.is_some() && arity == ast::Arity::Repeated)),
+    });
+    let verbosity = cmd.verbosity.then(|| ("verbosity".to_string(), false));
+    let args = cmd.args.iter().map(|it| (it.val.ident(), it.arity == ast::Arity::Repeated));
+    let fields = flags.chain(verbosity).chain(args);
+    for (ident, repeated) in fields {
+        let (prev, field) = (format!("b_.{prefix}{ident}"), format!("{this}.{ident}"));
+        if repeated {
+            w!(buf, "if let Some(it) = {prev} {{ {field}.truncate(it); }}\n");
+        } else {
+            w!(buf, "if let Some(it) = {prev}.take() {{ {field} = it; }}\n");
+        }
+    }
+    if cmd.has_subcommands() {
+        w!(buf, "match &mut {this}.subcommand {{\n");
+        for sub in &cmd.subcommands {
+            let mut sub_buf = String::new();
+            let l = sub.push_prefix(prefix);
+            emit_restore_rec(&mut sub_buf, prefix, sub, "cmd_");
+            prefix.truncate(l);
+            let binding = if sub_buf.is_empty() { "_" } else { "cmd_" };
+            w!(buf, "{}::{}({binding}) => {{\n{sub_buf}}}\n", cmd.cmd_enum_ident(), sub.ident());
+        }
         w!(buf, "}}\n");
+    }
+}
 
-        w!(buf, "Err(arg_) => match (state_, arg_.to_str().unwrap_or(\"\")) {{\n");
-        emit_match_arg_rec(buf, &mut prefix, cmd);
-        w!(buf, "_ => return Err(p_.unexpected_arg(arg_)),\n");
-        w!(buf, "}}\n");
+fn emit_state_rec(buf: &mut String, cmd: &ast::Cmd, this: &str) {
+    if !cmd.has_subcommands() {
+        w!(buf, "{}\n", cmd.idx);
+        return;
+    }
+    w!(buf, "match &{this}.subcommand {{\n");
+    for sub in &cmd.subcommands {
+        let binding = if sub.has_subcommands() { "cmd_" } else { "_" };
+        w!(buf, "{}::{}({binding}) => ", cmd.cmd_enum_ident(), sub.ident());
+        emit_state_rec(buf, sub, "cmd_");
+        w!(buf, ",\n");
     }
     w!(buf, "}}\n");
-    w!(buf, "}}\n");
-    emit_default_transitions(buf, cmd);
-
-    w!(buf, "Ok(");
-    emit_record_rec(buf, &mut prefix, cmd);
-    w!(buf, ")");
+}
 
-    w!(buf, "}}\n");
-    w!(buf, "}}\n");
+/// Declares the values collected while parsing as fields of a single local,
+/// `l_`. Early returns then drop just one value instead of every local, which
+/// keeps the generated code fast to compile for large grammars.
+fn emit_locals(buf: &mut String, cmd: &ast::Cmd) {
+    let mut fields = String::new();
+    emit_locals_rec(&mut fields, &mut String::new(), cmd);
+    if fields.is_empty() {
+        return;
+    }
+    w!(buf, "#[derive(Default)]\n");
+    w!(buf, "struct Locals_ {{\n{fields}}}\n");
+    w!(buf, "let mut l_ = Locals_::default();\n");
 }
 
 fn emit_locals_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
+    if let Some(path) = &cmd.mount {
+        w!(buf, "{prefix}mounted_: Option<{path}>,\n");
+    }
     for flag in &cmd.flags {
         if !flag.is_help() {
-            w!(buf, "let mut {prefix}{} = Vec::new();\n", flag.ident());
+            let ty = match &flag.val {
+                Some(val) => gen_arg_ty(ast::Arity::Repeated, &val.ty),
+                None => "Vec<()>".to_string(),
+            };
+            w!(buf, "{prefix}{}: {ty},\n", flag.ident());
         }
     }
     for arg in &cmd.args {
-        w!(buf, "let mut {prefix}{} = (false, Vec::new());\n", arg.val.ident());
+        let ty = gen_arg_ty(ast::Arity::Repeated, &arg.val.ty);
+        w!(buf, "{prefix}{}: (bool, {ty}),\n", arg.val.ident());
+    }
+    if cmd.has_flexible_args() {
+        w!(buf, "{prefix}pending_: Vec<(Option<usize>, std::ffi::OsString)>,\n");
     }
     for sub in &cmd.subcommands {
         let l = sub.push_prefix(prefix);
@@ -208,152 +1108,314 @@ fn emit_locals_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
     }
 }
 
-fn emit_match_flag_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
-    w!(
-        buf,
-        "({}, \"--help\" | \"-h\") => return Err(p_.help(Self::HELP_{})),\n",
-        cmd.idx,
-        snake(prefix).to_uppercase()
-    );
+/// Flags are matched by their position in `flags`, the sorted names of all
+/// flags, which is looked up with a binary search.
+fn emit_match_flag_rec(
+    buf: &mut String,
+    prefix: &mut String,
+    cmd: &ast::Cmd,
+    settings: &ast::Settings,
+    flags: &[String],
+) {
+    let index = |name: &str| flags.binary_search_by(|it| it.as_str().cmp(name)).unwrap();
+    let help = if cmd.idx == 0 {
+        "p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_())".to_string()
+    } else {
+        format!("p_.help(&Self::HELP_{})", snake(prefix).to_uppercase())
+    };
+    let (long, short) = (index("--help"), index("-h"));
+    w!(buf, "({}, {long} | {short}) => return Err({help}),\n", cmd.idx);
+    if settings.gnu_short_flags {
+        w!(buf, "({}, _) if flag_.starts_with(\"-h\") => return Err({help}),\n", cmd.idx);
+    }
     for flag in cmd.flags.iter().filter(|f| !f.is_help()) {
         w!(buf, "(");
         emit_all_ids_rec(buf, cmd);
-        w!(buf, ", \"--{}\"", flag.name);
+        w!(buf, ", {}", index(&format!("--{}", flag.name)));
         if let Some(short) = &flag.short {
-            w!(buf, "| \"-{short}\"");
+            w!(buf, " | {}", index(&format!("-{short}")));
         }
         w!(buf, ") => ");
-        w!(buf, "{prefix}{}.push(", flag.ident());
-        match &flag.val {
-            Some(val) => match &val.ty {
-                ast::Ty::OsString | ast::Ty::PathBuf => {
-                    w!(buf, "p_.next_value(&flag_)?.into()")
-                }
-                ast::Ty::FromStr(ty) => {
-                    w!(buf, "p_.next_value_from_str::<{ty}>(&flag_)?")
-                }
-            },
-            None => w!(buf, "()"),
+        if let Some(glob) = flag.glob {
+            let (windows_only, lenient) = (glob == ast::Glob::Windows, glob == ast::Glob::Lenient);
+            w!(buf, "l_.{prefix}{}.extend(", flag.ident());
+            w!(buf, "p_.next_glob(&flag_, {windows_only}, {lenient})?),\n");
+        } else {
+            w!(buf, "l_.{prefix}{}.push(", flag.ident());
+            match &flag.val {
+                Some(val) => match &val.ty {
+                    ast::Ty::OsString => w!(buf, "p_.next_value(&flag_)?"),
+                    ast::Ty::PathBuf => w!(buf, "p_.next_value(&flag_)?.into()"),
+                    ast::Ty::Bool => w!(buf, "true"),
+                    ast::Ty::Duration => w!(buf, "p_.next_value_duration(&flag_)?"),
+                    ast::Ty::CheckedPath(check) => {
+                        w!(buf, "p_.next_checked_path(&flag_, xflags::rt::PathCheck::{check:?})?")
+                    }
+                    ast::Ty::FromStr(ty) => {
+                        w!(buf, "p_.next_value_from_str::<{ty}>(&flag_)?")
+                    }
+                    ast::Ty::FromOsStr(ty) => {
+                        w!(buf, "p_.next_value_from_os_str::<{ty}>(&flag_)?")
+                    }
+                },
+                None => w!(buf, "()"),
+            }
+            w!(buf, "),\n");
+        }
+        if flag.is_bool() || (settings.flag_assign && flag.val.is_some()) {
+            emit_attached_value(buf, prefix, cmd, flag, &format!("--{}=", flag.name));
+        }
+        if let (true, Some(short)) = (settings.gnu_short_flags, &flag.short) {
+            let short = format!("-{short}");
+            if flag.val.is_some() && !flag.is_bool() {
+                emit_attached_value(buf, prefix, cmd, flag, &short);
+            } else {
+                // The rest of a cluster like `-vvq` is more short flags.
+                let value = if flag.is_bool() { "true" } else { "()" };
+                w!(buf, "(");
+                emit_all_ids_rec(buf, cmd);
+                w!(buf, ", _) if flag_.starts_with(\"{short}\") => {{\n");
+                w!(buf, "l_.{prefix}{}.push({value});\n", flag.ident());
+                w!(buf, "p_.push_back(Ok(format!(\"-{{}}\", &flag_[\"{short}\".len()..])));\n");
+                w!(buf, "}}\n");
+            }
         }
-        w!(buf, "),\n");
     }
     if let Some(sub) = cmd.default_subcommand() {
-        w!(buf, "({}, _) => {{ p_.push_back(Ok(flag_)); state_ = {}; }}", cmd.idx, sub.idx);
+        w!(buf, "({}, _) => {{ p_.push_back(Ok(flag_)); *state_ = {}; }}", cmd.idx, sub.idx);
     }
-    for sub in &cmd.subcommands {
+    for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
         let l = sub.push_prefix(prefix);
-        emit_match_flag_rec(buf, prefix, sub);
+        emit_match_flag_rec(buf, prefix, sub, settings, flags);
         prefix.truncate(l);
     }
 }
 
-fn emit_match_arg_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
+/// Names of all flags of the command tree, including their short forms, in
+/// the order expected by `flag_index`.
+fn flag_names(cmd: &ast::Cmd) -> Vec<String> {
+    fn rec(acc: &mut Vec<String>, cmd: &ast::Cmd) {
+        for flag in &cmd.flags {
+            acc.push(format!("--{}", flag.name));
+            if let Some(short) = &flag.short {
+                acc.push(format!("-{short}"));
+            }
+        }
+        for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
+            rec(acc, sub);
+        }
+    }
+    let mut res = vec!["--help".to_string(), "-h".to_string()];
+    rec(&mut res, cmd);
+    res.sort();
+    res.dedup();
+    res
+}
+
+/// Matches a flag with the value attached after `start`, like `--color=never`
+/// or `-j4`.
+fn emit_attached_value(
+    buf: &mut String,
+    prefix: &str,
+    cmd: &ast::Cmd,
+    flag: &ast::Flag,
+    start: &str,
+) {
+    let Some(val) = &flag.val else { return };
+    let local = format!("l_.{prefix}{}", flag.ident());
+    w!(buf, "(");
+    emit_all_ids_rec(buf, cmd);
+    w!(buf, ", _) if flag_.starts_with(\"{start}\") => {{\n");
+    w!(buf, "let value_: std::ffi::OsString = flag_[\"{start}\".len()..].into();\n");
+    match flag.glob {
+        Some(glob) => {
+            let (windows_only, lenient) = (glob == ast::Glob::Windows, glob == ast::Glob::Lenient);
+            w!(buf, "{local}.extend(");
+            w!(buf, "p_.glob_value(\"--{}\", value_, {windows_only}, {lenient})?", flag.name);
+            w!(buf, ");\n");
+        }
+        None => {
+            w!(buf, "{local}.push(");
+            emit_value(buf, &val.ty, "value_", &format!("\"--{}\"", flag.name));
+            w!(buf, ");\n");
+        }
+    }
+    w!(buf, "}}\n");
+}
+
+fn emit_match_arg_rec<'a>(
+    buf: &mut String,
+    prefix: &mut String,
+    path: &mut Vec<&'a str>,
+    cmd: &'a ast::Cmd,
+    settings: &ast::Settings,
+) {
     for sub in cmd.named_subcommands() {
-        let sub_match =
-            sub.all_identifiers().map(|s| format!("\"{s}\"")).collect::<Vec<_>>().join(" | ");
-        w!(buf, "({}, {}) => state_ = {},\n", cmd.idx, sub_match, sub.idx);
+        let sub_match = sub.all_identifiers().map(|s| byte_lit(s)).collect::<Vec<_>>().join(" | ");
+        match &sub.mount {
+            Some(ty) => {
+                let path_lit =
+                    path.iter().copied().chain([&*sub.name]).map(|it| format!("\"{it}\""));
+                let path_lit = path_lit.collect::<Vec<_>>().join(", ");
+                w!(buf, "({}, {}) => {{\n", cmd.idx, sub_match);
+                w!(buf, "*state_ = {};\n", sub.idx);
+                w!(
+                    buf,
+                    "l_.{prefix}{}__mounted_ = Some(p_.mount::<{ty}>(&[{path_lit}])?);\n",
+                    snake(&sub.name)
+                );
+                w!(buf, "}}\n");
+            }
+            None => w!(buf, "({}, {}) => *state_ = {},\n", cmd.idx, sub_match, sub.idx),
+        }
+        for alias in &sub.deprecated_aliases {
+            w!(buf, "({}, {}) => {{\n", cmd.idx, byte_lit(alias));
+            w!(buf, "p_.deprecated({alias:?}, {:?});\n", sub.name);
+            w!(buf, "*state_ = {};\n", sub.idx);
+            w!(buf, "}}\n");
+        }
     }
 
-    if cmd.args.is_empty() {
-        // add `help` subcommand only if command takes no args to make sure it doesn't take precedence
-        w!(
-            buf,
-            "({}, \"help\") => return Err(p_.help(Self::HELP_{})),\n",
-            cmd.idx,
-            snake(prefix).to_uppercase()
-        );
+    if cmd.generate {
+        w!(buf, "({}, b\"generate\") => {{\n", cmd.idx);
+        w!(buf, "let what_ = p_.next_value(\"generate\")?;\n");
+        w!(buf, "return Err(p_.generated(what_, Self::GENERATED_));\n");
+        w!(buf, "}}\n");
+    }
+
+    // `help` is a command where names of subcommands are expected, otherwise
+    // it might be a value of an argument. The help is printed after the rest
+    // of the arguments, so that `help foo bar` navigates to `bar`.
+    if cmd.args.is_empty() || cmd.has_subcommands() {
+        w!(buf, "({}, b\"help\") => p_.defer_help(),\n", cmd.idx);
     }
 
     if !cmd.args.is_empty() || cmd.has_subcommands() {
         w!(buf, "({}, _) => {{\n", cmd.idx);
-        for arg in &cmd.args {
+        // With a repeated argument, all of them are taken.
+        let takes_all = cmd.args.iter().any(|it| it.arity == ast::Arity::Repeated);
+        if cmd.has_flexible_args() {
+            if !takes_all {
+                w!(buf, "if l_.{prefix}pending_.len() < {} {{\n", cmd.args.len());
+            }
+            w!(buf, "l_.{prefix}pending_.push(p_.defer_arg(arg_));\n");
+            if settings.strict_ordering {
+                w!(buf, "p_.end_of_flags();\n");
+            }
+            if !takes_all {
+                w!(buf, "continue;\n");
+                w!(buf, "}}\n");
+            }
+        }
+        for arg in cmd.args.iter().filter(|_| !cmd.has_flexible_args()) {
             let done = match arg.arity {
                 ast::Arity::Optional | ast::Arity::Required => "done_ @ ",
                 ast::Arity::Repeated => "",
             };
-            w!(buf, "if let ({done}false, buf_) = &mut {prefix}{} {{\n", arg.val.ident());
-            w!(buf, "buf_.push(");
-            match &arg.val.ty {
-                ast::Ty::OsString | ast::Ty::PathBuf => {
-                    w!(buf, "arg_.into()")
+            w!(buf, "if let ({done}false, buf_) = &mut l_.{prefix}{} {{\n", arg.val.ident());
+            match arg.glob {
+                Some(glob) => {
+                    let windows_only = glob == ast::Glob::Windows;
+                    w!(buf, "buf_.extend(p_.glob(arg_, {windows_only}));\n");
                 }
-                ast::Ty::FromStr(ty) => {
-                    w!(buf, "p_.value_from_str::<{ty}>(\"{}\", arg_)?", arg.val.name);
+                None => {
+                    w!(buf, "buf_.push(");
+                    emit_value(buf, &arg.val.ty, "arg_", &format!("\"{}\"", arg.val.name));
+                    w!(buf, ");\n");
                 }
             }
-            w!(buf, ");\n");
             match arg.arity {
                 ast::Arity::Optional | ast::Arity::Required => {
                     w!(buf, "*done_ = true;\n");
                 }
                 ast::Arity::Repeated => (),
             }
+            if settings.strict_ordering {
+                w!(buf, "p_.end_of_flags();\n");
+            }
             w!(buf, "continue;\n");
             w!(buf, "}}\n");
         }
 
-        if let Some(sub) = cmd.default_subcommand() {
-            w!(buf, "p_.push_back(Err(arg_)); state_ = {};", sub.idx);
-        } else {
-            w!(buf, "return Err(p_.unexpected_arg(arg_));");
+        // A repeated flexible argument has already taken the value, so there
+        // is nothing to fall back to.
+        if !(cmd.has_flexible_args() && takes_all) {
+            if let Some(sub) = cmd.default_subcommand() {
+                w!(buf, "p_.push_back(Err(arg_)); *state_ = {};", sub.idx);
+            } else {
+                w!(buf, "return Err(p_.unexpected_arg(arg_));");
+            }
         }
 
         w!(buf, "}}\n");
     }
 
-    for sub in &cmd.subcommands {
+    for sub in cmd.subcommands.iter().filter(|it| it.mount.is_none()) {
         let l = sub.push_prefix(prefix);
-        emit_match_arg_rec(buf, prefix, sub);
+        path.push(&sub.name);
+        emit_match_arg_rec(buf, prefix, path, sub, settings);
+        path.pop();
         prefix.truncate(l);
     }
 }
 
+/// A byte string literal, for matching positional arguments without checking
+/// that they are valid UTF-8.
+fn byte_lit(s: &str) -> String {
+    let mut res = String::from("b\"");
+    for b in s.bytes() {
+        match b {
+            b'"' | b'\\' => w!(res, "\\{}", b as char),
+            b' '..=b'~' => res.push(b as char),
+            _ => w!(res, "\\x{b:02x}"),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// Moves the values out of the locals into the parsed command. This can't
+/// fail, as the number of values was checked by `emit_check_rec`.
 fn emit_record_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
+    if cmd.mount.is_some() {
+        w!(buf, "l_.{prefix}mounted_.take().unwrap()");
+        return;
+    }
+    if cmd.validate.is_some() {
+        w!(buf, "{{ let cmd_ = ");
+    }
     w!(buf, "{} {{\n", cmd.ident());
 
-    for flag in &cmd.flags {
-        if flag.is_help() {
-            continue;
-        }
-        w!(buf, "{}: ", flag.ident());
-        match &flag.val {
-            Some(_val) => match flag.arity {
-                ast::Arity::Optional => {
-                    w!(buf, "p_.optional(\"--{}\", {prefix}{})?", flag.name, flag.ident())
-                }
-                ast::Arity::Required => {
-                    w!(buf, "p_.required(\"--{}\", {prefix}{})?", flag.name, flag.ident())
-                }
-                ast::Arity::Repeated => w!(buf, "{prefix}{}", flag.ident()),
-            },
-            None => match flag.arity {
-                ast::Arity::Optional => {
-                    w!(buf, "p_.optional(\"--{}\", {prefix}{})?.is_some()", flag.name, flag.ident())
-                }
-                ast::Arity::Required => {
-                    w!(buf, "p_.required(\"--{}\", {prefix}{})?", flag.name, flag.ident())
-                }
-                ast::Arity::Repeated => w!(buf, "{prefix}{}.len() as u32", flag.ident()),
-            },
-        }
-        w!(buf, ",\n");
+    for flag in cmd.field_flags() {
+        let ident = flag.ident();
+        let local = format!("l_.{prefix}{ident}");
+        let value = match (&flag.val, flag.arity) {
+            (Some(_), ast::Arity::Optional) => format!("{local}.pop()"),
+            (None, ast::Arity::Optional) => format!("!{local}.is_empty()"),
+            (Some(_), ast::Arity::Required) => format!("{local}.pop().unwrap()"),
+            (None, ast::Arity::Required) => "()".to_string(),
+            (Some(_), ast::Arity::Repeated) => format!("std::mem::take(&mut {local})"),
+            (None, ast::Arity::Repeated) => gen_count(flag, &format!("{local}.len()")),
+        };
+        w!(buf, "{ident}: {value},\n");
+    }
+    if cmd.verbosity {
+        let (verbose, quiet) = (format!("l_.{prefix}verbose"), format!("l_.{prefix}quiet"));
+        w!(buf, "verbosity: {verbose}.len() as i32 - {quiet}.len() as i32,\n");
     }
     for arg in &cmd.args {
         let val = &arg.val;
+        let local = format!("l_.{prefix}{}.1", val.ident());
         w!(buf, "{}: ", val.ident());
         match arg.arity {
-            ast::Arity::Optional => {
-                w!(buf, "p_.optional(\"{}\", {prefix}{}.1)?", val.name, val.ident())
-            }
-            ast::Arity::Required => {
-                w!(buf, "p_.required(\"{}\", {prefix}{}.1)?", val.name, val.ident())
-            }
-            ast::Arity::Repeated => w!(buf, "{prefix}{}.1", val.ident()),
+            ast::Arity::Optional => w!(buf, "{local}.pop()"),
+            ast::Arity::Required => w!(buf, "{local}.pop().unwrap()"),
+            ast::Arity::Repeated => w!(buf, "std::mem::take(&mut {local})"),
         }
         w!(buf, ",\n");
     }
     if cmd.has_subcommands() {
-        w!(buf, "subcommand: match state_ {{\n");
+        w!(buf, "subcommand: match *state_ {{\n");
         for sub in &cmd.subcommands {
             emit_leaf_ids_rec(buf, sub);
             w!(buf, " => {}::{}(", cmd.cmd_enum_ident(), sub.ident());
@@ -369,28 +1431,45 @@ fn emit_record_rec(buf: &mut String, prefix: &mut String, cmd: &ast::Cmd) {
     }
 
     w!(buf, "}}");
+    if let Some(validate) = &cmd.validate {
+        w!(buf, "; {validate}(&cmd_)?; cmd_ }}");
+    }
 }
 
 fn emit_leaf_ids_rec(buf: &mut String, cmd: &ast::Cmd) {
-    if cmd.has_subcommands() {
-        for sub in &cmd.subcommands {
-            emit_leaf_ids_rec(buf, sub)
-        }
-    } else {
-        w!(buf, "| {}", cmd.idx)
-    }
+    let mut ids = Vec::new();
+    cmd.leaf_ids(&mut ids);
+    emit_ids(buf, ids);
 }
 
 fn emit_all_ids_rec(buf: &mut String, cmd: &ast::Cmd) {
-    w!(buf, "| {}", cmd.idx);
-    for sub in &cmd.subcommands {
-        emit_all_ids_rec(buf, sub)
+    let mut ids = Vec::new();
+    cmd.all_ids(&mut ids);
+    emit_ids(buf, ids);
+}
+
+/// Emits a pattern matching any of the `ids`, folding consecutive runs into
+/// ranges.
+fn emit_ids(buf: &mut String, mut ids: Vec<u8>) {
+    ids.sort_unstable();
+    let mut i = 0;
+    while i < ids.len() {
+        let start = ids[i];
+        while i + 1 < ids.len() && ids[i + 1] == ids[i] + 1 {
+            i += 1;
+        }
+        if start == ids[i] {
+            w!(buf, "| {start}");
+        } else {
+            w!(buf, "| {start}..={}", ids[i]);
+        }
+        i += 1;
     }
 }
 
 fn emit_default_transitions(buf: &mut String, cmd: &ast::Cmd) {
     if let Some(sub) = cmd.default_subcommand() {
-        w!(buf, "state_ = if state_ == {} {{ {} }} else {{ state_ }};", cmd.idx, sub.idx);
+        w!(buf, "*state_ = if *state_ == {} {{ {} }} else {{ *state_ }};", cmd.idx, sub.idx);
     }
     for sub in &cmd.subcommands {
         emit_default_transitions(buf, sub);
@@ -400,17 +1479,49 @@ fn emit_default_transitions(buf: &mut String, cmd: &ast::Cmd) {
 fn emit_help(buf: &mut String, xflags: &ast::XFlags) {
     w!(buf, "impl {} {{\n", xflags.cmd.ident());
 
-    cmd_help_rec(buf, &xflags.cmd, "");
+    cmd_help_rec(buf, &xflags.cmd, "", xflags.settings.strip_help);
+
+    w!(buf, "fn usage_(state_: u8) -> &'static str {{\n");
+    w!(buf, "match state_ {{\n");
+    emit_usage_arms_rec(buf, &xflags.cmd, "");
+    w!(buf, "_ => \"\",\n");
+    w!(buf, "}}\n");
+    w!(buf, "}}\n");
+
+    let mut spec = String::new();
+    w!(spec, "{{\"version\":{SPEC_VERSION},\"command\":");
+    cmd_spec_rec(&mut spec, &xflags.cmd, false);
+    w!(spec, "}}");
+    w!(buf, "const SPEC_JSON_: &'static str = {spec:?};\n");
+
+    if xflags.cmd.generate {
+        w!(buf, "const GENERATED_: &'static [(&'static str, &'static str)] = &[\n");
+        for (name, text) in generate::all(&xflags.cmd) {
+            w!(buf, "(\"{name}\", {text:?}),\n");
+        }
+        w!(buf, "];\n");
+    }
 
     w!(buf, "}}\n");
 }
 
-fn cmd_help_rec(buf: &mut String, cmd: &ast::Cmd, prefix: &str) {
-    let mut help_buf = String::new();
-    w!(help_buf, "Usage: {}", cmd.name);
+fn emit_usage_arms_rec(buf: &mut String, cmd: &ast::Cmd, prefix: &str) {
+    if cmd.mount.is_some() {
+        return;
+    }
+    w!(buf, "{} => Self::USAGE_{},\n", cmd.idx, snake(prefix).to_uppercase());
+    for subcommand in &cmd.subcommands {
+        let prefix = format!("{}{}__", prefix, subcommand.name);
+        emit_usage_arms_rec(buf, subcommand, &prefix);
+    }
+}
+
+fn cmd_usage(cmd: &ast::Cmd) -> String {
+    let mut buf = String::new();
+    w!(buf, "Usage: {}", cmd.name);
     for arg in cmd.args_with_default() {
-        let (l, r) = arg.arity.brackets();
-        w!(help_buf, " {l}{}{r}", arg.val.name);
+        let (l, r) = arg.brackets();
+        w!(buf, " {l}{}{r}", arg.val.name);
     }
     for flag in cmd.flags_with_default() {
         // <-f> doesn't make sense, if it has to be included it should just be -f
@@ -421,49 +1532,175 @@ fn cmd_help_rec(buf: &mut String, cmd: &ast::Cmd, prefix: &str) {
         let f = flag.short.clone().unwrap_or_else(|| format!("-{}", flag.name));
 
         match &flag.val {
-            Some(v) => w!(help_buf, " {l}-{f} <{}>{r}", v.name),
-            None => w!(help_buf, " {l}-{f}{r}"),
+            // Values of bool switches can only be attached to the long name.
+            Some(v) if flag.is_bool() => w!(buf, " {l}--{}[=<{}>]{r}", flag.name, v.name),
+            Some(v) => w!(buf, " {l}-{f} <{}>{r}", v.name),
+            None => w!(buf, " {l}-{f}{r}"),
         }
     }
     if cmd.has_subcommands() {
-        w!(help_buf, " <COMMAND>")
+        w!(buf, " <COMMAND>")
+    }
+    buf
+}
+
+/// Emits the help as static pieces, which are put together by the `Display`
+/// impl of `xflags::Help`.
+fn cmd_help_rec(buf: &mut String, cmd: &ast::Cmd, prefix: &str, strip: bool) {
+    if cmd.mount.is_some() {
+        return;
+    }
+    for subcommand in &cmd.subcommands {
+        let prefix = format!("{}{}__", prefix, subcommand.name);
+        cmd_help_rec(buf, subcommand, &prefix, strip);
     }
-    if let Some(doc) = &cmd.doc {
-        w!(help_buf, "\n\n{}\n", doc);
+    let const_prefix = snake(prefix).to_uppercase();
+    w!(buf, "const USAGE_{const_prefix}: &'static str = \"{}\";\n", cmd_usage(cmd));
+    let usage = format!("Self::USAGE_{const_prefix}");
+    if strip {
+        w!(
+            buf,
+            "const HELP_{const_prefix}: xflags::Help = xflags::Help::new({usage}, None, &[]);\n"
+        );
+        return;
     }
+
+    let mut sections = String::new();
     let args_with_default = cmd.args_with_default();
     if !args_with_default.is_empty() {
-        w!(help_buf, "\nArguments:\n");
+        w!(sections, "xflags::HelpSection {{ title: \"Arguments\", entries: &[");
         for arg in args_with_default {
-            let (l, r) = arg.arity.brackets();
-            let pre_doc = format!("{l}{}{r}", arg.val.name);
-            w!(help_buf, "  {:<20} {}\n", pre_doc, arg.doc.as_deref().unwrap_or(""));
+            let (l, r) = arg.brackets();
+            let doc = arg.doc.as_deref().unwrap_or("");
+            w!(sections, "(\"{l}{}{r}\", {}), ", arg.val.name, str_lit(doc));
         }
+        w!(sections, "] }}, ");
     }
     let flags_with_default = cmd.flags_with_default();
     if !flags_with_default.is_empty() {
-        w!(help_buf, "\nOptions:\n");
+        w!(sections, "xflags::HelpSection {{ title: \"Options\", entries: &[");
         for flag in flags_with_default {
             let short = flag.short.as_ref().map(|it| format!("-{it}, ")).unwrap_or_default();
-            let value = flag.val.as_ref().map(|it| format!(" <{}>", it.name)).unwrap_or_default();
-            let pre_doc = format!("{short}--{}{value}", flag.name);
-            w!(help_buf, "  {:<20} {}\n", pre_doc, flag.doc.as_deref().unwrap_or(""));
+            let value = match &flag.val {
+                Some(val) if flag.is_bool() => format!("[=<{}>]", val.name),
+                Some(val) => format!(" <{}>", val.name),
+                None => String::new(),
+            };
+            let doc = flag.doc.as_deref().unwrap_or("");
+            w!(sections, "(\"{short}--{}{value}\", {}), ", flag.name, str_lit(doc));
         }
+        w!(sections, "] }}, ");
     }
-    w!(help_buf, "\nCommands:");
+    w!(sections, "xflags::HelpSection {{ title: \"Commands\", entries: &[");
     for subcommand in cmd.named_subcommands() {
-        w!(help_buf, "\n  {:<20} {}", subcommand.name, subcommand.doc.as_deref().unwrap_or(""));
+        let doc = subcommand.doc.as_deref().unwrap_or("");
+        w!(sections, "(\"{}\", {}), ", subcommand.name, str_lit(doc));
     }
-    for subcommand in &cmd.subcommands {
-        let prefix = format!("{}{}__", prefix, subcommand.name);
-        cmd_help_rec(buf, subcommand, &prefix);
+    if cmd.generate {
+        w!(sections, "xflags::rt::GENERATE_ENTRY, ");
+    }
+    w!(sections, "xflags::rt::HELP_ENTRY] }}");
+
+    let doc = match &cmd.doc {
+        Some(doc) => format!("Some({})", str_lit(doc)),
+        None => "None".to_string(),
+    };
+    w!(buf, "const HELP_{const_prefix}: xflags::Help = ");
+    w!(buf, "xflags::Help::new({usage}, {doc}, &[{sections}]);\n");
+}
+
+/// Version of the format produced by `spec_json`, bumped on incompatible
+/// changes.
+const SPEC_VERSION: u32 = 1;
+
+/// A mounted command is described by its name only: its grammar is expanded
+/// by a separate macro invocation, so it isn't known here.
+fn cmd_spec_rec(buf: &mut String, cmd: &ast::Cmd, is_default: bool) {
+    w!(buf, "{{\"name\":{},\"aliases\":[", json_str(&cmd.name));
+    for (i, alias) in cmd.aliases.iter().enumerate() {
+        w!(buf, "{}{}", if i == 0 { "" } else { "," }, json_str(alias));
+    }
+    w!(buf, "],\"deprecated_aliases\":[");
+    for (i, alias) in cmd.deprecated_aliases.iter().enumerate() {
+        w!(buf, "{}{}", if i == 0 { "" } else { "," }, json_str(alias));
+    }
+    w!(buf, "],\"doc\":{},\"default\":{is_default},\"args\":[", json_opt_str(&cmd.doc));
+    for (i, arg) in cmd.args.iter().enumerate() {
+        w!(buf, "{}{{\"name\":{},", if i == 0 { "" } else { "," }, json_str(&arg.val.name));
+        w!(buf, "\"arity\":\"{}\",", arg.arity.spec_name());
+        w!(buf, "\"non_empty\":{},", arg.non_empty);
+        w!(buf, "\"type\":{},", json_str(arg.val.ty.name()));
+        w!(buf, "\"doc\":{}}}", json_opt_str(&arg.doc));
     }
-    w!(help_buf, "\n  {:<20} ", "help");
-    w!(help_buf, "Print this message or the help of the given subcommand(s)");
-    w!(buf, "const HELP_{}: &'static str = \"{help_buf}\";\n", snake(prefix).to_uppercase());
+    w!(buf, "],\"flags\":[");
+    for (i, flag) in cmd.flags.iter().enumerate() {
+        w!(buf, "{}{{\"name\":{},", if i == 0 { "" } else { "," }, json_str(&flag.name));
+        w!(buf, "\"short\":{},", json_opt_str(&flag.short));
+        w!(buf, "\"arity\":\"{}\",", flag.arity.spec_name());
+        match &flag.val {
+            Some(val) => w!(
+                buf,
+                "\"value\":{{\"name\":{},\"type\":{}}},",
+                json_str(&val.name),
+                json_str(val.ty.name())
+            ),
+            None => w!(buf, "\"value\":null,"),
+        }
+        w!(buf, "\"doc\":{}}}", json_opt_str(&flag.doc));
+    }
+    w!(buf, "],\"subcommands\":[");
+    for (i, sub) in cmd.subcommands.iter().enumerate() {
+        if i > 0 {
+            w!(buf, ",");
+        }
+        cmd_spec_rec(buf, sub, cmd.default && i == 0);
+    }
+    w!(buf, "]}}");
+}
+
+fn json_opt_str(s: &Option<String>) -> String {
+    match s {
+        Some(s) => json_str(s),
+        None => "null".to_string(),
+    }
+}
+
+/// A string literal, with the newlines kept as is so that multi-line docs
+/// stay readable in the generated code.
+fn str_lit(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn json_str(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if (c as u32) < 0x20 => w!(res, "\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// A field of one of the generated structs, as seen from the root command.
+struct Field {
+    /// Name of the variant in the generated `Field` enum.
+    variant: String,
+    /// Expression with the values collected for the field during parsing.
+    values: String,
 }
 
 impl ast::Cmd {
+    /// Flags stored in a field of their own: all but `--help`, and the
+    /// `-v`/`-q` pair of `verbosity`, which share the `verbosity` field.
+    fn field_flags(&self) -> impl Iterator<Item = &ast::Flag> {
+        let verbosity = |it: &ast::Flag| self.verbosity && matches!(&*it.name, "verbose" | "quiet");
+        self.flags.iter().filter(move |it| !it.is_help() && !verbosity(it))
+    }
     fn ident(&self) -> String {
         if self.name.is_empty() {
             return "Flags".to_string();
@@ -476,12 +1713,75 @@ impl ast::Cmd {
     fn cmd_enum_ident(&self) -> String {
         format!("{}Cmd", self.ident())
     }
+    fn field_enum_ident(&self) -> String {
+        format!("{}Field", self.ident())
+    }
+    /// All fields of this command and its subcommands, in the order of
+    /// declaration of parsing locals.
+    fn fields(&self) -> Vec<Field> {
+        let mut res = Vec::new();
+        self.fields_rec(&mut String::new(), "", &mut res);
+        res
+    }
+    /// Variants are named by the path to the field, like `Build_Jobs` for
+    /// `--jobs` of `build`. Names never contain `_`, so `--build-jobs` of the
+    /// root command, `BuildJobs`, is distinct.
+    fn fields_rec(&self, prefix: &mut String, path: &str, acc: &mut Vec<Field>) {
+        let variant = |name: &str| format!("{path}{}", camel(&name.replace('_', "-")));
+        for flag in self.flags.iter().filter(|it| !it.is_help()) {
+            let values = format!("l_.{prefix}{}", flag.ident());
+            acc.push(Field { variant: variant(&flag.name), values });
+        }
+        for arg in &self.args {
+            let values = format!("l_.{prefix}{}.1", arg.val.ident());
+            acc.push(Field { variant: variant(&arg.val.name), values });
+        }
+        for sub in &self.subcommands {
+            let l = sub.push_prefix(prefix);
+            sub.fields_rec(prefix, &format!("{path}{}_", camel(&sub.name)), acc);
+            prefix.truncate(l);
+        }
+    }
     fn push_prefix(&self, buf: &mut String) -> usize {
         let l = buf.len();
         buf.push_str(&snake(&self.name));
         buf.push_str("__");
         l
     }
+    fn leaf_ids(&self, acc: &mut Vec<u8>) {
+        if self.has_subcommands() {
+            for sub in &self.subcommands {
+                sub.leaf_ids(acc)
+            }
+        } else {
+            acc.push(self.idx)
+        }
+    }
+    fn all_ids(&self, acc: &mut Vec<u8>) {
+        acc.push(self.idx);
+        for sub in &self.subcommands {
+            sub.all_ids(acc)
+        }
+    }
+    fn has_prompts(&self) -> bool {
+        self.flags.iter().any(|it| it.prompt.is_some())
+            || self.subcommands.iter().any(|it| it.has_prompts())
+    }
+    /// Whether the positional arguments can't be taken in order, because an
+    /// argument follows a repeated one or a required one follows an optional
+    /// one.
+    fn has_flexible_args(&self) -> bool {
+        let mut optional = false;
+        let mut repeated = false;
+        for arg in &self.args {
+            if repeated || (optional && arg.arity == ast::Arity::Required) {
+                return true;
+            }
+            optional |= arg.arity == ast::Arity::Optional;
+            repeated |= arg.arity == ast::Arity::Repeated;
+        }
+        false
+    }
     fn has_subcommands(&self) -> bool {
         !self.subcommands.is_empty()
     }
@@ -518,6 +1818,15 @@ impl ast::Flag {
     }
 }
 
+impl ast::Arg {
+    fn brackets(&self) -> (&str, &str) {
+        if self.non_empty {
+            return ("<", ">...");
+        }
+        self.arity.brackets()
+    }
+}
+
 impl ast::Arity {
     fn brackets(&self) -> (&str, &str) {
         match self {
@@ -526,6 +1835,28 @@ impl ast::Arity {
             ast::Arity::Repeated => ("[", "]..."),
         }
     }
+    fn spec_name(&self) -> &str {
+        match self {
+            ast::Arity::Optional => "optional",
+            ast::Arity::Required => "required",
+            ast::Arity::Repeated => "repeated",
+        }
+    }
+}
+
+impl ast::Ty {
+    fn name(&self) -> &str {
+        match self {
+            ast::Ty::PathBuf => "PathBuf",
+            ast::Ty::OsString => "OsString",
+            ast::Ty::Bool => "bool",
+            ast::Ty::Duration => "Duration",
+            ast::Ty::CheckedPath(ast::PathCheck::ExistingFile) => "ExistingFile",
+            ast::Ty::CheckedPath(ast::PathCheck::ExistingDir) => "ExistingDir",
+            ast::Ty::CheckedPath(ast::PathCheck::NewPath) => "NewPath",
+            ast::Ty::FromStr(it) | ast::Ty::FromOsStr(it) => it,
+        }
+    }
 }
 
 impl ast::Val {
@@ -543,12 +1874,8 @@ fn camel(s: &str) -> String {
 }
 
 fn first_upper(s: &str) -> String {
-    s.chars()
-        .next()
-        .map(|it| it.to_ascii_uppercase())
-        .into_iter()
-        .chain(s.chars().skip(1))
-        .collect()
+    let mut chars = s.chars();
+    chars.next().into_iter().flat_map(char::to_uppercase).chain(chars).collect()
 }
 
 fn snake(s: &str) -> String {
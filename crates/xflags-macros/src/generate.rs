@@ -0,0 +1,274 @@
+//! Shell completions and man pages, printed by the `generate` builtin.
+//!
+//! They are generated from the grammar at hand alone, so mounted commands
+//! appear without their flags and subcommands.
+use std::fmt::Write;
+
+use crate::ast;
+
+macro_rules! w {
+    ($($tt:tt)*) => {{ let _ = write!($($tt)*); }};
+}
+
+const NAMES: &str = "bash zsh fish man";
+
+/// Names accepted by `generate`, together with the generated text.
+pub(crate) fn all(cmd: &ast::Cmd) -> Vec<(&'static str, String)> {
+    vec![("bash", bash(cmd)), ("zsh", zsh(cmd)), ("fish", fish(cmd)), ("man", man(cmd))]
+}
+
+/// Every command together with its path, like `app__build`, which is used as
+/// the state of the shell completion functions.
+fn states(cmd: &ast::Cmd) -> Vec<(String, &ast::Cmd)> {
+    fn rec<'a>(acc: &mut Vec<(String, &'a ast::Cmd)>, path: String, cmd: &'a ast::Cmd) {
+        acc.push((path.clone(), cmd));
+        for sub in &cmd.subcommands {
+            rec(acc, format!("{path}__{}", sub.name), sub);
+        }
+    }
+    let mut res = Vec::new();
+    rec(&mut res, cmd.name.clone(), cmd);
+    res
+}
+
+/// Words which can follow the command: flags and names of subcommands.
+fn words(cmd: &ast::Cmd, is_root: bool) -> Vec<String> {
+    let mut res = Vec::new();
+    for flag in &cmd.flags {
+        if let Some(short) = &flag.short {
+            res.push(format!("-{short}"));
+        }
+        res.push(format!("--{}", flag.name));
+    }
+    for sub in &cmd.subcommands {
+        res.extend(sub.all_identifiers().cloned());
+    }
+    res.push("help".to_string());
+    if is_root {
+        res.push("generate".to_string());
+    }
+    res
+}
+
+/// Transitions between the completion states, `(state, word, next_state)`.
+fn transitions(cmd: &ast::Cmd) -> Vec<(String, String, String)> {
+    let mut res = Vec::new();
+    for (state, cmd) in states(cmd) {
+        for sub in &cmd.subcommands {
+            for name in sub.all_identifiers() {
+                res.push((state.clone(), name.clone(), format!("{state}__{}", sub.name)));
+            }
+        }
+    }
+    res
+}
+
+fn bash(cmd: &ast::Cmd) -> String {
+    let func = format!("_{}", cmd.name.replace('-', "_"));
+    let mut buf = String::new();
+    w!(buf, "{func}() {{\n");
+    w!(buf, "    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\" state=\"{}\" i\n", cmd.name);
+    w!(buf, "    for ((i = 1; i < COMP_CWORD; i++)); do\n");
+    w!(buf, "        case \"$state,${{COMP_WORDS[i]}}\" in\n");
+    for (state, word, next) in transitions(cmd) {
+        w!(buf, "            \"{state},{word}\") state=\"{next}\" ;;\n");
+    }
+    w!(buf, "            \"{0},generate\") state=\"{0}__generate_\" ;;\n", cmd.name);
+    w!(buf, "        esac\n");
+    w!(buf, "    done\n");
+    w!(buf, "    case \"$state\" in\n");
+    for (state, sub) in states(cmd) {
+        let words = words(sub, sub.idx == 0).join(" ");
+        w!(buf, "        \"{state}\") COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\")) ;;\n");
+    }
+    w!(
+        buf,
+        "        \"{}__generate_\") COMPREPLY=($(compgen -W \"{NAMES}\" -- \"$cur\")) ;;\n",
+        cmd.name
+    );
+    w!(buf, "    esac\n");
+    w!(buf, "}}\n");
+    w!(buf, "complete -o default -F {func} {}\n", cmd.name);
+    buf
+}
+
+fn zsh(cmd: &ast::Cmd) -> String {
+    let func = format!("_{}", cmd.name.replace('-', "_"));
+    let mut buf = String::new();
+    w!(buf, "#compdef {}\n\n", cmd.name);
+    w!(buf, "{func}() {{\n");
+    w!(buf, "    local state=\"{}\" i\n", cmd.name);
+    w!(buf, "    for ((i = 2; i < CURRENT; i++)); do\n");
+    w!(buf, "        case \"$state,${{words[i]}}\" in\n");
+    for (state, word, next) in transitions(cmd) {
+        w!(buf, "            \"{state},{word}\") state=\"{next}\" ;;\n");
+    }
+    w!(buf, "            \"{0},generate\") state=\"{0}__generate_\" ;;\n", cmd.name);
+    w!(buf, "        esac\n");
+    w!(buf, "    done\n");
+    w!(buf, "    case \"$state\" in\n");
+    for (state, sub) in states(cmd) {
+        let words = words(sub, sub.idx == 0).join(" ");
+        w!(buf, "        \"{state}\") compadd -- {words} ;;\n");
+    }
+    w!(buf, "        \"{}__generate_\") compadd -- {NAMES}; return ;;\n", cmd.name);
+    w!(buf, "    esac\n");
+    w!(buf, "    _files\n");
+    w!(buf, "}}\n\n");
+    w!(buf, "{func} \"$@\"\n");
+    buf
+}
+
+fn fish(cmd: &ast::Cmd) -> String {
+    let mut buf = String::new();
+    fish_rec(&mut buf, &cmd.name, cmd, None);
+    let bin = &cmd.name;
+    w!(buf, "complete -c {bin} -f -n '__fish_use_subcommand' -a generate");
+    w!(buf, " -d 'Print shell completions or a man page'\n");
+    w!(buf, "complete -c {bin} -f -n '__fish_seen_subcommand_from generate' -a '{NAMES}'\n");
+    buf
+}
+
+fn fish_rec(buf: &mut String, bin: &str, cmd: &ast::Cmd, seen: Option<Vec<&String>>) {
+    let children = cmd.subcommands.iter().flat_map(|it| it.all_identifiers()).collect::<Vec<_>>();
+    let flag_cond = match &seen {
+        None => String::new(),
+        Some(names) => format!(" -n '__fish_seen_subcommand_from {}'", join(names)),
+    };
+    let mut sub_cond = match &seen {
+        None => "__fish_use_subcommand".to_string(),
+        Some(names) => format!("__fish_seen_subcommand_from {}", join(names)),
+    };
+    if seen.is_some() && !children.is_empty() {
+        w!(sub_cond, "; and not __fish_seen_subcommand_from {}", join(&children));
+    }
+
+    for flag in &cmd.flags {
+        w!(buf, "complete -c {bin}{flag_cond}");
+        if let Some(short) = &flag.short {
+            if short.chars().all(char::is_alphanumeric) {
+                w!(buf, " -s {short}");
+            } else {
+                w!(buf, " -s '{short}'");
+            }
+        }
+        w!(buf, " -l {}", flag.name);
+        if flag.val.is_some() && !flag.is_bool() {
+            w!(buf, " -r");
+        }
+        fish_doc(buf, &flag.doc);
+        w!(buf, "\n");
+    }
+    for sub in &cmd.subcommands {
+        for name in sub.all_identifiers() {
+            w!(buf, "complete -c {bin} -f -n '{sub_cond}' -a {name}");
+            fish_doc(buf, &sub.doc);
+            w!(buf, "\n");
+        }
+    }
+    for sub in &cmd.subcommands {
+        fish_rec(buf, bin, sub, Some(sub.all_identifiers().collect()));
+    }
+}
+
+fn fish_doc(buf: &mut String, doc: &Option<String>) {
+    if let Some(doc) = doc {
+        let line = doc.lines().next().unwrap_or_default();
+        w!(buf, " -d '{}'", line.replace('\\', "\\\\").replace('\'', "\\'"));
+    }
+}
+
+fn join(names: &[&String]) -> String {
+    names.iter().map(|it| it.as_str()).collect::<Vec<_>>().join(" ")
+}
+
+fn man(cmd: &ast::Cmd) -> String {
+    let mut buf = String::new();
+    w!(buf, ".TH {} 1\n", roff(&cmd.name.to_uppercase()));
+    w!(buf, ".SH NAME\n");
+    match cmd.doc.as_deref().and_then(|it| it.lines().next()) {
+        Some(line) => w!(buf, "{} \\- {}\n", roff(&cmd.name), roff(line)),
+        None => w!(buf, "{}\n", roff(&cmd.name)),
+    }
+    man_rec(&mut buf, cmd, &cmd.name);
+    buf
+}
+
+fn man_rec(buf: &mut String, cmd: &ast::Cmd, path: &str) {
+    let section = if cmd.idx == 0 { ".SH" } else { ".SS" };
+    if cmd.idx == 0 {
+        w!(buf, ".SH SYNOPSIS\n");
+    } else {
+        w!(buf, ".SH {}\n", roff(&path.to_uppercase()));
+    }
+    w!(buf, "\\fB{}\\fR", roff(path));
+    for arg in &cmd.args {
+        let arg = match arg.arity {
+            ast::Arity::Optional => format!("[{}]", arg.val.name),
+            ast::Arity::Required => format!("<{}>", arg.val.name),
+            ast::Arity::Repeated if arg.non_empty => format!("<{}>...", arg.val.name),
+            ast::Arity::Repeated => format!("[{}]...", arg.val.name),
+        };
+        w!(buf, " {}", roff(&arg));
+    }
+    if !cmd.subcommands.is_empty() {
+        w!(buf, " <COMMAND>");
+    }
+    w!(buf, "\n");
+    if let Some(doc) = &cmd.doc {
+        w!(buf, "{section} DESCRIPTION\n{}\n", roff(doc));
+    }
+    if !cmd.args.is_empty() {
+        w!(buf, "{section} ARGUMENTS\n");
+        for arg in &cmd.args {
+            w!(buf, ".TP\n\\fI{}\\fR\n", roff(&arg.val.name));
+            roff_doc(buf, &arg.doc);
+        }
+    }
+    if !cmd.flags.is_empty() {
+        w!(buf, "{section} OPTIONS\n");
+        for flag in &cmd.flags {
+            w!(buf, ".TP\n");
+            if let Some(short) = &flag.short {
+                w!(buf, "\\fB\\-{}\\fR, ", roff(short));
+            }
+            w!(buf, "\\fB\\-\\-{}\\fR", roff(&flag.name));
+            if let Some(val) = &flag.val {
+                w!(buf, " \\fI{}\\fR", roff(&val.name));
+            }
+            w!(buf, "\n");
+            roff_doc(buf, &flag.doc);
+        }
+    }
+    if !cmd.subcommands.is_empty() {
+        w!(buf, "{section} COMMANDS\n");
+        for sub in &cmd.subcommands {
+            w!(buf, ".TP\n\\fB{}\\fR\n", roff(&sub.name));
+            roff_doc(buf, &sub.doc);
+        }
+    }
+    for sub in &cmd.subcommands {
+        man_rec(buf, sub, &format!("{path} {}", sub.name));
+    }
+}
+
+fn roff_doc(buf: &mut String, doc: &Option<String>) {
+    if let Some(doc) = doc {
+        w!(buf, "{}\n", roff(doc));
+    }
+}
+
+/// Escapes text for use in a man page.
+fn roff(text: &str) -> String {
+    let mut res = String::new();
+    for line in text.lines() {
+        if !res.is_empty() {
+            res.push('\n');
+        }
+        if line.starts_with(['.', '\'']) {
+            res.push_str("\\&");
+        }
+        res.push_str(&line.replace('\\', "\\e").replace('-', "\\-"));
+    }
+    res
+}
@@ -1,6 +1,7 @@
 mod ast;
 mod parse;
 mod emit;
+mod generate;
 mod update;
 
 #[proc_macro]
@@ -36,6 +37,42 @@ pub fn parse_or_exit(_ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+#[proc_macro]
+pub fn parse(_ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Stub out the code, but let rust-analyzer resolve the invocation
+    #[cfg(not(test))]
+    {
+        let text = match parse::parse(_ts) {
+            Ok(cmd) => emit::emit(&cmd),
+            Err(err) => format!("compile_error!(\"invalid flags syntax, {err}\")"),
+        };
+        text.parse().unwrap()
+    }
+    #[cfg(test)]
+    {
+        let _ = parse::parse;
+        unimplemented!();
+    }
+}
+
+#[proc_macro]
+pub fn run(_ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Stub out the code, but let rust-analyzer resolve the invocation
+    #[cfg(not(test))]
+    {
+        let text = match parse::run(_ts) {
+            Ok(cmd) => emit::emit(&cmd),
+            Err(err) => format!("compile_error!(\"invalid flags syntax, {err}\")"),
+        };
+        text.parse().unwrap()
+    }
+    #[cfg(test)]
+    {
+        let _ = parse::run;
+        unimplemented!();
+    }
+}
+
 #[cfg(test)]
 pub fn compile(src: &str) -> String {
     use proc_macro2::TokenStream;
@@ -22,23 +22,134 @@ impl fmt::Display for Error {
     }
 }
 
+macro_rules! format_err {
+    ($($tt:tt)*) => {
+        Error { msg: format!($($tt)*) }
+        // panic!($($tt)*)
+    };
+}
+
+macro_rules! bail {
+    ($($tt:tt)*) => {
+        return Err(format_err!($($tt)*))
+    };
+}
+
 pub(crate) fn xflags(ts: TokenStream) -> Result<ast::XFlags> {
     let p = &mut Parser::new(ts);
-    let src = if p.eat_keyword("src") { Some(p.expect_string()?) } else { None };
+    xflags_impl(p)
+}
+
+fn xflags_impl(p: &mut Parser) -> Result<ast::XFlags> {
+    let mut src = None;
+    let mut config = None;
+    let mut env_prefix = None;
+    let mut dotenv = None;
+    let mut settings = ast::Settings::default();
+    loop {
+        if p.eat_keyword("src") {
+            src = Some(p.expect_string()?);
+        } else if p.eat_keyword("config") {
+            config = Some(p.expect_string()?);
+        } else if p.eat_dashed_keyword("env-prefix") {
+            env_prefix = Some(p.expect_string()?);
+        } else if p.eat_keyword("dotenv") {
+            let path = if p.at_string() { p.expect_string()? } else { ".env".to_string() };
+            dotenv = Some(path);
+        } else if p.eat_keyword("settings") {
+            settings = self::settings(p)?;
+        } else {
+            break;
+        }
+    }
+    if dotenv.is_some() && env_prefix.is_none() {
+        bail!("`dotenv` requires `env-prefix`")
+    }
     let doc = opt_doc(p)?;
     let mut cmd = cmd(p)?;
     cmd.doc = doc;
     add_help(&mut cmd);
-    let res = ast::XFlags { src, cmd };
+    if settings.strip_help {
+        strip_docs(&mut cmd);
+    }
+    let res =
+        ast::XFlags { src, config, env_prefix, dotenv, settings, dispatch: None, parse: None, cmd };
     Ok(res)
 }
 
 pub(crate) fn parse_or_exit(ts: TokenStream) -> Result<ast::XFlags> {
     let p = &mut Parser::new(ts);
+    anon_xflags(p, None)
+}
+
+pub(crate) fn parse(ts: TokenStream) -> Result<ast::XFlags> {
+    let p = &mut Parser::new(ts);
+    let args = opt_args(p)?;
+    anon_xflags(p, Some(ast::Parse { args }))
+}
+
+/// The `args = ...;` expression of `parse!` and `run!`.
+fn opt_args(p: &mut Parser) -> Result<Option<String>> {
+    if !p.eat_keyword("args") {
+        return Ok(None);
+    }
+    p.expect_punct('=')?;
+    let mut expr = TokenStream::new();
+    while !p.eat_punct(';') {
+        match p.ts.pop() {
+            Some(tt) => expr.extend([tt]),
+            None => bail!("expected `;` after `args = ...`"),
+        }
+    }
+    Ok(Some(expr.to_string()))
+}
+
+fn anon_xflags(p: &mut Parser, parse: Option<ast::Parse>) -> Result<ast::XFlags> {
+    let src = if p.eat_keyword("src") { Some(p.expect_string()?) } else { None };
+    let settings = if p.eat_keyword("settings") { settings(p)? } else { Default::default() };
     let mut cmd = anon_cmd(p)?;
-    assert!(cmd.subcommands.is_empty());
+    check_accessors(&cmd)?;
     add_help(&mut cmd);
-    let res = ast::XFlags { src: None, cmd };
+    if settings.strip_help {
+        strip_docs(&mut cmd);
+    }
+    let res = ast::XFlags {
+        src,
+        config: None,
+        env_prefix: None,
+        dotenv: None,
+        settings,
+        dispatch: None,
+        parse,
+        cmd,
+    };
+    Ok(res)
+}
+
+fn settings(p: &mut Parser) -> Result<ast::Settings> {
+    let mut res = ast::Settings::default();
+    p.enter_delim(Delimiter::Brace)?;
+    while !p.end() {
+        let name = p.expect_name()?;
+        let setting = match name.as_str() {
+            "gnu-short-flags" => &mut res.gnu_short_flags,
+            "flag-assign" => &mut res.flag_assign,
+            "strict-ordering" => &mut res.strict_ordering,
+            "strip-help" => &mut res.strip_help,
+            _ => bail!(
+                "unknown setting `{name}`, expected one of: \
+                 gnu-short-flags, flag-assign, strict-ordering, strip-help"
+            ),
+        };
+        if *setting {
+            bail!("`{name}` is specified multiple times")
+        }
+        *setting = true;
+        if !p.eat_punct(',') && !p.end() {
+            bail!("expected `,` after `{name}`")
+        }
+    }
+    p.exit_delim()?;
     Ok(res)
 }
 
@@ -49,21 +160,40 @@ fn add_help(cmd: &mut ast::Cmd) {
         short: Some("h".to_string()),
         doc: Some("Prints help".to_string()),
         val: None,
+        prompt: None,
+        glob: None,
+        count: None,
     };
     cmd.flags.push(help);
 }
 
-macro_rules! format_err {
-    ($($tt:tt)*) => {
-        Error { msg: format!($($tt)*) }
-        // panic!($($tt)*)
-    };
+/// With `strip-help`, docs are dropped altogether, as besides help, they would
+/// end up in the completions, the man page and the JSON spec.
+fn strip_docs(cmd: &mut ast::Cmd) {
+    cmd.doc = None;
+    cmd.args.iter_mut().for_each(|it| it.doc = None);
+    cmd.flags.iter_mut().for_each(|it| it.doc = None);
+    cmd.subcommands.iter_mut().for_each(strip_docs);
 }
 
-macro_rules! bail {
-    ($($tt:tt)*) => {
-        return Err(format_err!($($tt)*))
-    };
+pub(crate) fn run(ts: TokenStream) -> Result<ast::XFlags> {
+    let p = &mut Parser::new(ts);
+    let args = opt_args(p)?;
+    let mut res = xflags_impl(p)?;
+    if res.src.is_some() {
+        bail!("`src` is not supported by `run!`")
+    }
+    if res.cmd.subcommands.is_empty() {
+        bail!("`run!` requires subcommands to dispatch on")
+    }
+    p.expect_keyword("match")?;
+    let binding = if p.at_delim(Delimiter::Brace) { None } else { Some(p.expect_ident()?) };
+    let arms = p.expect_group(Delimiter::Brace)?.to_string();
+    if !p.end() {
+        bail!("unexpected tokens after `match {{ ... }}`")
+    }
+    res.dispatch = Some(ast::Dispatch { args, binding, arms });
+    Ok(res)
 }
 
 fn anon_cmd(p: &mut Parser) -> Result<ast::Cmd> {
@@ -82,7 +212,8 @@ fn cmd_impl(p: &mut Parser, anon: bool) -> Result<ast::Cmd> {
         cmd_name(p)?
     };
 
-    let aliases = alias_names(p);
+    let aliases = if anon { Vec::new() } else { alias_names(p) };
+    let deprecated_aliases = if anon { Vec::new() } else { deprecated_aliases(p)? };
 
     let idx = p.idx;
     p.idx += 1;
@@ -90,10 +221,16 @@ fn cmd_impl(p: &mut Parser, anon: bool) -> Result<ast::Cmd> {
     let mut res = ast::Cmd {
         name,
         aliases,
+        deprecated_aliases,
         doc: None,
         args: Vec::new(),
         flags: Vec::new(),
         subcommands: Vec::new(),
+        validate: None,
+        verbosity: false,
+        generate: false,
+        color: false,
+        mount: None,
         default: false,
         idx,
     };
@@ -103,8 +240,70 @@ fn cmd_impl(p: &mut Parser, anon: bool) -> Result<ast::Cmd> {
     }
     while !p.end() {
         let doc = opt_doc(p)?;
-        let default = !anon && p.eat_keyword("default");
-        if !anon && (default || p.at_keyword("cmd")) {
+        if p.eat_keyword("validate") {
+            if res.validate.is_some() {
+                bail!("only one `validate` function can be specified per command")
+            }
+            res.validate = Some(path(p)?);
+            continue;
+        }
+        if p.eat_keyword("builtin") {
+            let name = p.expect_name()?;
+            if name != "generate" && name != "color" {
+                bail!("unknown builtin `{name}`, expected `generate` or `color`")
+            }
+            if anon || idx != 0 {
+                bail!("`builtin {name}` is only supported by the top-level command")
+            }
+            if name == "color" {
+                res.color = true;
+                res.flags.push(color_flag());
+            } else {
+                res.generate = true;
+            }
+            continue;
+        }
+        if !anon && p.eat_keyword("mount") {
+            // Paths start with a module followed by `::`, or with the struct,
+            // so a lowercase name on its own is the name of the subcommand.
+            let lowercase = matches!(
+                p.ts.last(),
+                Some(TokenTree::Ident(it)) if it.to_string().starts_with(char::is_lowercase)
+            );
+            let name =
+                if lowercase && !p.lookahead_punct(':', 1) { Some(p.expect_name()?) } else { None };
+            let path = path(p)?;
+            let name = name.unwrap_or_else(|| kebab(path.rsplit("::").next().unwrap_or_default()));
+            let idx = p.idx;
+            p.idx += 1;
+            res.subcommands.push(ast::Cmd {
+                name,
+                aliases: Vec::new(),
+                deprecated_aliases: Vec::new(),
+                doc,
+                args: Vec::new(),
+                flags: Vec::new(),
+                subcommands: Vec::new(),
+                validate: None,
+                verbosity: false,
+                generate: false,
+                color: false,
+                mount: Some(path),
+                default: false,
+                idx,
+            });
+            continue;
+        }
+        if p.eat_keyword("verbosity") {
+            if res.verbosity {
+                bail!("`verbosity` can be specified only once per command")
+            }
+            res.verbosity = true;
+            res.flags.extend(verbosity_flags());
+            continue;
+        }
+        let default = p.eat_keyword("default");
+        if default || p.at_keyword("cmd") {
             let mut cmd = cmd(p)?;
             cmd.doc = doc;
             res.subcommands.push(cmd);
@@ -117,18 +316,46 @@ fn cmd_impl(p: &mut Parser, anon: bool) -> Result<ast::Cmd> {
             }
         } else {
             let arity = arity(p)?;
+            let non_empty = arity == ast::Arity::Repeated && p.eat_punct('!');
             let is_val = p.lookahead_punct(':', 1);
             let name = p.expect_name()?;
             if name.starts_with('-') {
+                if non_empty {
+                    bail!("`repeated!` is only supported for arguments")
+                }
                 let mut flag = flag(p, name)?;
                 flag.doc = doc;
                 flag.arity = arity;
+                if flag.prompt.is_some() && (flag.val.is_none() || arity != ast::Arity::Required) {
+                    bail!("`prompt` is only supported for required flags with values")
+                }
+                let is_paths = matches!(&flag.val, Some(ast::Val { ty: ast::Ty::PathBuf, .. }));
+                if flag.glob.is_some() && (arity != ast::Arity::Repeated || !is_paths) {
+                    bail!("`glob` is only supported for repeated `PathBuf` flags and arguments")
+                }
+                if flag.count.is_some() && (flag.val.is_some() || arity != ast::Arity::Repeated) {
+                    bail!("`as` is only supported for repeated switches")
+                }
                 res.flags.push(flag)
             } else if is_val {
                 p.expect_punct(':')?;
                 let ty = ty(p)?;
+                let glob = opt_glob(p)?;
+                if glob.is_some()
+                    && (arity != ast::Arity::Repeated || !matches!(ty, ast::Ty::PathBuf))
+                {
+                    bail!("`glob` is only supported for repeated `PathBuf` flags and arguments")
+                }
+                if glob == Some(ast::Glob::Lenient) {
+                    bail!("`glob(lenient)` is only supported for flags")
+                }
+                if arity == ast::Arity::Repeated
+                    && res.args.iter().any(|it| it.arity == ast::Arity::Repeated)
+                {
+                    bail!("only one repeated argument is allowed per command")
+                }
                 let val = ast::Val { name, ty };
-                let arg = ast::Arg { arity, doc, val };
+                let arg = ast::Arg { arity, doc, val, glob, non_empty };
                 res.args.push(arg);
             } else {
                 bail!("expected `--flag` or `arg: Type`")
@@ -139,18 +366,142 @@ fn cmd_impl(p: &mut Parser, anon: bool) -> Result<ast::Cmd> {
         p.exit_delim()?;
     }
 
-    let mut unique_identifiers = std::collections::HashSet::new();
+    if res.generate
+        && res.subcommands.iter().any(|it| it.all_identifiers().any(|it| it == "generate"))
+    {
+        bail!("`generate` is defined multiple times")
+    }
+
+    check_duplicates(&res)?;
+    Ok(res)
+}
 
-    for ident in res.subcommands.iter().flat_map(|cmd| cmd.all_identifiers()) {
-        if !unique_identifiers.insert(ident) {
-            bail!("`{}` is defined multiple times", ident)
+/// Methods generated for commands, which the accessors of subcommands of
+/// `parse!` and `parse_or_exit!` would clash with.
+const GENERATED_METHODS: &[&str] = &[
+    "apply",
+    "apply_",
+    "apply_state_",
+    "color_choice",
+    "from_env",
+    "from_env_",
+    "from_env_or_exit",
+    "from_env_or_exit_",
+    "from_env_outcome",
+    "from_env_with_sources",
+    "from_env_with_sources_",
+    "from_slice",
+    "from_slice_",
+    "from_vec",
+    "from_vec_",
+    "from_vec_lenient",
+    "from_vec_lenient_",
+    "from_vec_outcome",
+    "from_vec_with_sources",
+    "from_vec_with_sources_",
+    "help_epilogue_",
+    "help_message",
+    "log_level",
+    "parse_",
+    "parse_state_",
+    "set_help_epilogue",
+    "spec_json",
+    "state_",
+    "subcommand_path",
+    "subcommand_path_",
+    "usage",
+    "usage_",
+    "validate_",
+];
+
+/// Rejects subcommands of an anonymous command which can't be accessed by
+/// their names.
+fn check_accessors(cmd: &ast::Cmd) -> Result<()> {
+    for sub in &cmd.subcommands {
+        let method = sub.name.replace('-', "_");
+        if GENERATED_METHODS.contains(&&*method) {
+            bail!("subcommand `{}` clashes with the generated `{method}` method", sub.name)
         }
+        check_accessors(sub)?;
     }
+    Ok(())
+}
 
-    Ok(res)
+/// Rejects names of subcommands and flags which would be ambiguous, naming
+/// both definitions.
+fn check_duplicates(cmd: &ast::Cmd) -> Result<()> {
+    let mut seen = std::collections::HashMap::new();
+    for sub in &cmd.subcommands {
+        for ident in sub.all_identifiers().chain(&sub.deprecated_aliases) {
+            match seen.insert(ident, &sub.name) {
+                Some(prev) if prev == &sub.name => bail!("`{ident}` is defined multiple times"),
+                Some(prev) => bail!("`{ident}` is used by both `{prev}` and `{}`", sub.name),
+                None => (),
+            }
+        }
+    }
+
+    let mut seen = std::collections::HashMap::new();
+    seen.insert("-h".to_string(), "help");
+    for flag in &cmd.flags {
+        let names = flag.short.iter().map(|it| format!("-{it}"));
+        for name in [format!("--{}", flag.name)].into_iter().chain(names) {
+            match seen.insert(name.clone(), &flag.name) {
+                Some(prev) if prev == flag.name => bail!("`{name}` is defined multiple times"),
+                Some(prev) => bail!("`{name}` is used by both `--{prev}` and `--{}`", flag.name),
+                None => (),
+            }
+        }
+    }
+    Ok(())
 }
 
-fn flag(p: &mut Parser, name: String) -> Result<ast::Flag> {
+fn verbosity_flags() -> [ast::Flag; 2] {
+    let verbose = ast::Flag {
+        arity: ast::Arity::Repeated,
+        name: "verbose".to_string(),
+        short: Some("v".to_string()),
+        doc: Some("Print more output, can be repeated".to_string()),
+        val: None,
+        prompt: None,
+        glob: None,
+        count: None,
+    };
+    let quiet = ast::Flag {
+        arity: ast::Arity::Optional,
+        name: "quiet".to_string(),
+        short: Some("q".to_string()),
+        doc: Some("Print less output".to_string()),
+        val: None,
+        prompt: None,
+        glob: None,
+        count: None,
+    };
+    [verbose, quiet]
+}
+
+fn color_flag() -> ast::Flag {
+    ast::Flag {
+        arity: ast::Arity::Optional,
+        name: "color".to_string(),
+        short: None,
+        doc: Some("When to use colors: auto, always or never".to_string()),
+        val: Some(ast::Val {
+            name: "when".to_string(),
+            ty: ast::Ty::FromStr("xflags::ColorChoice".to_string()),
+        }),
+        prompt: None,
+        glob: None,
+        count: None,
+    }
+}
+
+fn flag(p: &mut Parser, mut name: String) -> Result<ast::Flag> {
+    if name == "-" {
+        if let Some(c) = p.eat_short_symbol() {
+            name.push(c);
+        }
+    }
     let short;
     let long;
     if name.starts_with("--") {
@@ -172,15 +523,65 @@ fn flag(p: &mut Parser, name: String) -> Result<ast::Flag> {
     }
 
     let val = opt_val(p)?;
+    let glob = opt_glob(p)?;
+    let prompt = opt_prompt(p)?;
+    let count = opt_count(p)?;
     Ok(ast::Flag {
         arity: ast::Arity::Required,
         name: long[2..].to_string(),
         short: short.map(|it| it[1..].to_string()),
         doc: None,
         val,
+        prompt,
+        glob,
+        count,
     })
 }
 
+fn opt_count(p: &mut Parser) -> Result<Option<ast::Count>> {
+    if !p.eat_keyword("as") {
+        return Ok(None);
+    }
+    let ty = path(p)?;
+    let arms = if p.at_delim(Delimiter::Brace) {
+        Some(p.expect_group(Delimiter::Brace)?.to_string())
+    } else {
+        None
+    };
+    Ok(Some(ast::Count { ty, arms }))
+}
+
+fn opt_glob(p: &mut Parser) -> Result<Option<ast::Glob>> {
+    if !p.eat_keyword("glob") {
+        return Ok(None);
+    }
+    if !p.at_delim(Delimiter::Parenthesis) {
+        return Ok(Some(ast::Glob::Always));
+    }
+    p.enter_delim(Delimiter::Parenthesis)?;
+    let res = if p.eat_keyword("lenient") {
+        ast::Glob::Lenient
+    } else {
+        p.expect_keyword("windows")?;
+        ast::Glob::Windows
+    };
+    p.exit_delim()?;
+    Ok(Some(res))
+}
+
+fn opt_prompt(p: &mut Parser) -> Result<Option<ast::Prompt>> {
+    if !p.eat_keyword("prompt") {
+        return Ok(None);
+    }
+    if !p.at_delim(Delimiter::Parenthesis) {
+        return Ok(Some(ast::Prompt::Echo));
+    }
+    p.enter_delim(Delimiter::Parenthesis)?;
+    p.expect_keyword("hidden")?;
+    p.exit_delim()?;
+    Ok(Some(ast::Prompt::Hidden))
+}
+
 fn opt_val(p: &mut Parser) -> Result<Option<ast::Val>, Error> {
     if !p.lookahead_punct(':', 1) {
         return Ok(None);
@@ -209,13 +610,27 @@ fn arity(p: &mut Parser) -> Result<ast::Arity> {
     bail!("expected one of `optional`, `required`, `repeated`, got {:?}", p.ts.pop())
 }
 
+/// The names of the builtin types are reserved only on their own, so a type of
+/// the same name can still be used through a path, like `units::Duration`.
 fn ty(p: &mut Parser) -> Result<ast::Ty> {
-    let name = p.expect_name()?;
+    let name = path(p)?;
     let res = match name.as_str() {
         "PathBuf" => ast::Ty::PathBuf,
         "OsString" => ast::Ty::OsString,
+        "bool" => ast::Ty::Bool,
+        "Duration" | "std::time::Duration" => ast::Ty::Duration,
+        "ExistingFile" => ast::Ty::CheckedPath(ast::PathCheck::ExistingFile),
+        "ExistingDir" => ast::Ty::CheckedPath(ast::PathCheck::ExistingDir),
+        "NewPath" => ast::Ty::CheckedPath(ast::PathCheck::NewPath),
         _ => ast::Ty::FromStr(name),
     };
+    if p.eat_keyword("from") {
+        p.expect_keyword("OsStr")?;
+        match res {
+            ast::Ty::FromStr(name) => return Ok(ast::Ty::FromOsStr(name)),
+            _ => bail!("`from OsStr` is only supported for custom types"),
+        }
+    }
     Ok(res)
 }
 
@@ -264,6 +679,50 @@ fn alias_names(p: &mut Parser) -> Vec<String> {
     aliases
 }
 
+/// Parses `(deprecated: old-name other-name)`.
+fn deprecated_aliases(p: &mut Parser) -> Result<Vec<String>> {
+    if !p.at_delim(Delimiter::Parenthesis) {
+        return Ok(Vec::new());
+    }
+    p.enter_delim(Delimiter::Parenthesis)?;
+    p.expect_keyword("deprecated")?;
+    p.expect_punct(':')?;
+    let res = alias_names(p);
+    if res.is_empty() {
+        bail!("expected deprecated names after `deprecated:`")
+    }
+    p.exit_delim()?;
+    Ok(res)
+}
+
+fn path(p: &mut Parser) -> Result<String> {
+    let mut res = p.expect_ident()?;
+    while p.lookahead_punct(':', 0) && p.lookahead_punct(':', 1) {
+        p.expect_punct(':')?;
+        p.expect_punct(':')?;
+        res.push_str("::");
+        res.push_str(&p.expect_ident()?);
+    }
+    Ok(res)
+}
+
+/// Recovers the command name from the name of the generated struct. Runs of
+/// capitals are kept together, so `HTTPServer` is `http-server`.
+fn kebab(ident: &str) -> String {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut res = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let next_lower = chars.get(i + 1).is_some_and(|it| it.is_lowercase());
+            if !chars[i - 1].is_uppercase() || next_lower {
+                res.push('-');
+            }
+        }
+        res.extend(c.to_lowercase());
+    }
+    res
+}
+
 fn flag_name(p: &mut Parser) -> Result<String> {
     let name = p.expect_name()?;
     if !name.starts_with('-') {
@@ -297,6 +756,15 @@ impl Parser {
         }
         Ok(())
     }
+    fn at_delim(&mut self, delimiter: Delimiter) -> bool {
+        matches!(self.ts.last(), Some(TokenTree::Group(g)) if g.delimiter() == delimiter)
+    }
+    fn expect_group(&mut self, delimiter: Delimiter) -> Result<TokenStream> {
+        match self.ts.pop() {
+            Some(TokenTree::Group(g)) if g.delimiter() == delimiter => Ok(g.stream()),
+            _ => bail!("expected `{{`"),
+        }
+    }
     fn exit_delim(&mut self) -> Result<()> {
         if !self.end() {
             bail!("expected `}}`")
@@ -322,6 +790,26 @@ impl Parser {
             false
         }
     }
+    /// Eats a keyword containing dashes, like `env-prefix`.
+    fn eat_dashed_keyword(&mut self, kw: &str) -> bool {
+        let n = kw.split('-').count() * 2 - 1;
+        if self.ts.len() < n {
+            return false;
+        }
+        let mut buf = String::new();
+        for tt in self.ts[self.ts.len() - n..].iter().rev() {
+            match tt {
+                TokenTree::Ident(ident) => buf.push_str(&ident.to_string()),
+                TokenTree::Punct(p) if p.as_char() == '-' => buf.push('-'),
+                _ => return false,
+            }
+        }
+        if buf != kw {
+            return false;
+        }
+        self.ts.truncate(self.ts.len() - n);
+        true
+    }
     fn at_keyword(&mut self, kw: &str) -> bool {
         match self.ts.last() {
             #[allow(clippy::cmp_owned)]
@@ -360,7 +848,7 @@ impl Parser {
         }
     }
 
-    fn _expect_ident(&mut self) -> Result<String> {
+    fn expect_ident(&mut self) -> Result<String> {
         match self.ts.pop() {
             Some(TokenTree::Ident(ident)) => Ok(ident.to_string()),
             _ => bail!("expected ident"),
@@ -382,6 +870,20 @@ impl Parser {
             _ => false,
         }
     }
+    /// Eats a digit or one of the punctuation characters allowed as
+    /// unconventional short flag names, like `-1` or `-#`.
+    fn eat_short_symbol(&mut self) -> Option<char> {
+        let c = match self.ts.last()? {
+            TokenTree::Punct(p) if "#@%+".contains(p.as_char()) => p.as_char(),
+            TokenTree::Literal(lit) => match lit.to_string().as_bytes() {
+                &[c] if c.is_ascii_digit() => char::from(c),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.ts.pop();
+        Some(c)
+    }
     fn lookahead_punct(&mut self, punct: char, n: usize) -> bool {
         match self.ts.iter().rev().nth(n) {
             Some(TokenTree::Punct(p)) => p.as_char() == punct,
@@ -389,6 +891,10 @@ impl Parser {
         }
     }
 
+    fn at_string(&self) -> bool {
+        matches!(self.ts.last(), Some(TokenTree::Literal(lit)) if lit.to_string().starts_with('"'))
+    }
+
     fn expect_string(&mut self) -> Result<String> {
         match self.ts.pop() {
             Some(TokenTree::Literal(lit)) if lit.to_string().starts_with('"') => {
@@ -405,5 +911,106 @@ impl Parser {
 /// Really needs support in the proc_macro library:
 /// <https://internals.rust-lang.org/t/getting-value-out-of-proc-macro-literal/14140>
 fn str_lit_value(lit: String) -> String {
-    lit.trim_matches('"').replace("\\'", "'")
+    let lit = lit.strip_prefix('"').and_then(|it| it.strip_suffix('"')).unwrap_or(&lit);
+    let mut res = String::new();
+    let mut chars = lit.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => res.push('\n'),
+            Some('r') => res.push('\r'),
+            Some('t') => res.push('\t'),
+            Some('0') => res.push('\0'),
+            Some('x') => {
+                let hex = chars.by_ref().take(2).collect::<String>();
+                res.extend(u8::from_str_radix(&hex, 16).ok().map(char::from));
+            }
+            Some('u') => {
+                let hex = chars.by_ref().skip(1).take_while(|&it| it != '}').collect::<String>();
+                res.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            // A line continuation skips the newline and the leading whitespace.
+            Some('\n') => {
+                let rest = chars.as_str().trim_start();
+                chars = rest.chars();
+            }
+            Some(c) => res.push(c),
+            None => (),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    fn check_error(text: &str, expect: Expect) {
+        let err = super::xflags(text.parse().unwrap()).unwrap_err();
+        expect.assert_eq(&err.to_string());
+    }
+
+    #[test]
+    fn duplicates() {
+        check_error(
+            "cmd app { cmd run r {} cmd release r {} }",
+            expect!["`r` is used by both `run` and `release`"],
+        );
+        check_error(
+            "cmd app { cmd run {} cmd run {} }",
+            expect!["`run` is defined multiple times"],
+        );
+        check_error(
+            "cmd app { optional -r, --recursive optional -r, --release }",
+            expect!["`-r` is used by both `--recursive` and `--release`"],
+        );
+        check_error(
+            "cmd app { optional --jobs optional --jobs }",
+            expect!["`--jobs` is defined multiple times"],
+        );
+        check_error(
+            "cmd app { verbosity optional -v, --version }",
+            expect!["`-v` is used by both `--verbose` and `--version`"],
+        );
+        check_error(
+            "cmd app { optional -h, --hidden }",
+            expect!["`-h` is used by both `--help` and `--hidden`"],
+        );
+    }
+
+    #[test]
+    fn accessor_names() {
+        let check_error = |text: &str, expect: Expect| {
+            let err = super::parse_or_exit(text.parse().unwrap()).unwrap_err();
+            expect.assert_eq(&err.to_string());
+        };
+        check_error(
+            "cmd apply {}",
+            expect!["subcommand `apply` clashes with the generated `apply` method"],
+        );
+        check_error(
+            "cmd run { cmd spec-json {} }",
+            expect!["subcommand `spec-json` clashes with the generated `spec_json` method"],
+        );
+        assert!(super::parse_or_exit("cmd usages {} cmd applied {}".parse().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn mount_names() {
+        let names = |text: &str| {
+            let xflags = super::xflags(text.parse().unwrap()).unwrap();
+            xflags.cmd.subcommands.into_iter().map(|it| it.name).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names("cmd app { mount super::HTTPServer mount IOError mount Http2Server }"),
+            ["http-server", "io-error", "http2-server"]
+        );
+        assert_eq!(
+            names("cmd app { mount ra super::RustAnalyzer mount http-server HTTPServer }"),
+            ["ra", "http-server"]
+        );
+    }
 }
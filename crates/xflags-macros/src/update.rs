@@ -6,8 +6,14 @@ pub(crate) fn in_place(api: &str, path: &Path) {
         Path::new(&dir).join(path)
     };
 
-    let mut text = fs::read_to_string(&path).unwrap_or_else(|_| panic!("failed to read {path:?}"));
+    let text = fs::read_to_string(&path).unwrap_or_else(|_| panic!("failed to read {path:?}"));
+    let text = update(text, api);
 
+    fs::write(&path, text.as_bytes()).unwrap();
+}
+
+/// Replaces the generated code in `text`, or inserts it after the macro call.
+fn update(mut text: String, api: &str) -> String {
     let (insert_to, indent) = locate(&text);
 
     let api: String =
@@ -22,8 +28,7 @@ pub(crate) fn in_place(api: &str, path: &Path) {
             })
             .collect();
     text.replace_range(insert_to, &api);
-
-    fs::write(&path, text.as_bytes()).unwrap();
+    text
 }
 
 pub(crate) fn stdout(api: &str) {
@@ -65,6 +70,14 @@ fn locate_existing(text: &str) -> Option<(Range<usize>, String)> {
     Some((start_idx..end_idx, indent))
 }
 
+/// The first occurrence of `name` outside of a line comment.
+fn find_call(text: &str, name: &str) -> Option<usize> {
+    text.match_indices(name).map(|(idx, _)| idx).find(|&idx| {
+        let line_start = text[..idx].rfind('\n').map_or(0, |it| it + 1);
+        !text[line_start..idx].contains("//")
+    })
+}
+
 fn newline_before(text: &str, start_idx: usize) -> usize {
     text[..start_idx].rfind('\n').map_or(start_idx, |it| it + 1)
 }
@@ -78,7 +91,10 @@ fn indent_at(text: &str, start_idx: usize) -> String {
 }
 
 fn locate_new(text: &str) -> Option<(Range<usize>, String)> {
-    let mut idx = text.find("xflags!")?;
+    let mut idx = ["xflags!", "parse_or_exit!", "parse!"]
+        .iter()
+        .filter_map(|it| find_call(text, it))
+        .min()?;
     let mut lvl = 0i32;
     for c in text[idx..].chars() {
         idx += c.len_utf8();
@@ -90,8 +106,43 @@ fn locate_new(text: &str) -> Option<(Range<usize>, String)> {
         }
     }
     let indent = indent_at(text, newline_before(text, idx));
-    if text[idx..].starts_with('\n') {
-        idx += 1;
+    // Skip the `;` after `let flags = parse_or_exit! { ... };`.
+    let line_end = newline_after(text, idx);
+    if matches!(text[idx..line_end].trim(), "" | ";") {
+        idx = line_end;
     }
     Some((idx..idx, indent))
 }
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    #[test]
+    fn parse_or_exit_round_trip() {
+        let text = "\
+// Unlike `xflags!`, this defines an anonymous struct.
+fn main() {
+    let flags = xflags::parse_or_exit! {
+        optional -v, --verbose
+    };
+}
+";
+        let once = super::update(text.to_string(), "struct Flags;");
+        expect![[r#"
+            // Unlike `xflags!`, this defines an anonymous struct.
+            fn main() {
+                let flags = xflags::parse_or_exit! {
+                    optional -v, --verbose
+                };
+                // generated start
+                // The following code is generated by `xflags` macro.
+                // Run `env UPDATE_XFLAGS=1 cargo build` to regenerate.
+                struct Flags;
+                // generated end
+            }
+        "#]]
+        .assert_eq(&once);
+        assert_eq!(super::update(once.clone(), "struct Flags;"), once);
+    }
+}
@@ -0,0 +1,7 @@
+xflags! {
+    cmd deploy {
+        optional -c, --color value: bool
+        required --dry-run value: bool
+        repeated --confirm value: bool
+    }
+}
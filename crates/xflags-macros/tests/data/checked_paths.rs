@@ -0,0 +1,7 @@
+xflags! {
+    cmd render {
+        optional --config path: ExistingFile
+        optional --templates dir: ExistingDir
+        required --output path: NewPath
+    }
+}
@@ -0,0 +1,10 @@
+xflags! {
+    cmd paint {
+        builtin color
+        optional --dry-run
+
+        cmd wall {
+            required name: String
+        }
+    }
+}
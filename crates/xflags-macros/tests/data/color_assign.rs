@@ -0,0 +1,8 @@
+xflags! {
+    settings { flag-assign }
+
+    cmd paint {
+        builtin color
+        required name: String
+    }
+}
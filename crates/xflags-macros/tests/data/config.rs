@@ -0,0 +1,17 @@
+xflags! {
+    config "tests/config.toml"
+
+    cmd configured {
+        optional -j, --jobs n: u32
+        repeated -v, --verbose
+        optional --color
+
+        cmd build {
+            repeated --feature name: String
+            required --target triple: String
+        }
+        cmd test {
+            optional --target triple: String
+        }
+    }
+}
@@ -0,0 +1,6 @@
+xflags! {
+    cmd counted {
+        repeated -v, --verbose as xflags::LogLevel { 0 => Warn, 1 => Info, 2.. => Debug }
+        repeated -d, --debug as u8
+    }
+}
@@ -0,0 +1,9 @@
+xflags! {
+    cmd tool {
+        /// Checks the code.
+        cmd lint (deprecated: check verify) {
+            optional --fix
+        }
+        cmd build b {}
+    }
+}
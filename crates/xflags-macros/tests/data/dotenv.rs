@@ -0,0 +1,10 @@
+xflags! {
+    env-prefix "XFLAGS_DOTENV_"
+    dotenv "tests/test.env"
+
+    cmd server {
+        optional --host name: String
+        optional --port n: u16
+        repeated -v, --verbose
+    }
+}
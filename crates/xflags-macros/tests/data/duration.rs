@@ -0,0 +1,8 @@
+xflags! {
+    cmd fetch {
+        optional --timeout duration: Duration
+        repeated --retry-after delay: Duration
+        optional --ttl ttl: super::units::Duration
+        optional tick: Duration
+    }
+}
@@ -0,0 +1,13 @@
+xflags! {
+    env-prefix "XFLAGS_TEST_"
+
+    cmd tool {
+        optional -j, --jobs n: u32
+        repeated -v, --verbose
+        optional --log-file path: PathBuf
+
+        cmd build {
+            repeated --feature name: String
+        }
+    }
+}
@@ -0,0 +1,7 @@
+xflags! {
+    env-prefix "MYTOOL_"
+
+    cmd mytool {
+        verbosity
+    }
+}
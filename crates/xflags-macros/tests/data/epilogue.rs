@@ -0,0 +1,7 @@
+xflags! {
+    /// Runs plugins.
+    cmd plug {
+        optional -v, --verbose
+        cmd list {}
+    }
+}
@@ -0,0 +1,7 @@
+xflags! {
+    /// Prints "quoted" text.
+    cmd echo {
+        /// Separator, `\n` by default.
+        optional --sep sep: String
+    }
+}
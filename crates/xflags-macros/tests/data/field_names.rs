@@ -0,0 +1,8 @@
+xflags! {
+    cmd make {
+        optional --foo-jobs n: u32
+        cmd foo {
+            optional --jobs n: u32
+        }
+    }
+}
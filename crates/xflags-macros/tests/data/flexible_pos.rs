@@ -0,0 +1,19 @@
+xflags! {
+    cmd copy {
+        cmd cp {
+            repeated sources: PathBuf
+            required dest: PathBuf
+            optional -r, --recursive
+        }
+        cmd mv {
+            optional source: PathBuf
+            required dest: PathBuf
+            optional -n, --jobs n: u32
+        }
+        cmd ln {
+            optional mode: u32
+            repeated! links: OsString
+            required target: PathBuf
+        }
+    }
+}
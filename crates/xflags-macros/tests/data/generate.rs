@@ -0,0 +1,15 @@
+xflags! {
+    /// Builds things.
+    cmd tool {
+        builtin generate
+        repeated -v, --verbose
+
+        /// Build the project.
+        cmd build b {
+            optional --out dir: PathBuf
+        }
+        cmd test {
+            repeated filter: String
+        }
+    }
+}
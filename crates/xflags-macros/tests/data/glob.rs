@@ -0,0 +1,13 @@
+xflags! {
+    cmd lint {
+        cmd all {
+            repeated files: PathBuf glob
+            repeated -e, --exclude path: PathBuf
+            repeated -i, --include pattern: PathBuf glob
+            repeated --extra pattern: PathBuf glob(lenient)
+        }
+        cmd native {
+            repeated files: PathBuf glob(windows)
+        }
+    }
+}
@@ -0,0 +1,10 @@
+xflags! {
+    cmd app {
+        optional --dry-run
+
+        /// Rust analyzer commands.
+        mount super::subcommands::RustAnalyzer
+        mount smoke super::smoke::RustAnalyzer
+        cmd other {}
+    }
+}
@@ -0,0 +1,9 @@
+xflags! {
+    env-prefix "XFLAGS_MOUNT_"
+
+    cmd app {
+        builtin generate
+
+        mount ra super::subcommands::RustAnalyzer
+    }
+}
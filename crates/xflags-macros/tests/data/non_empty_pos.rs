@@ -0,0 +1,7 @@
+xflags! {
+    cmd cat {
+        /// Files to print.
+        repeated! files: PathBuf
+        optional -n, --number
+    }
+}
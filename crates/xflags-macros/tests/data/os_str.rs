@@ -0,0 +1,6 @@
+xflags! {
+    cmd tag {
+        repeated --label label: super::labels::Label from OsStr
+        optional target: super::labels::Label from OsStr
+    }
+}
@@ -0,0 +1,7 @@
+xflags! {
+    cmd login {
+        required --user name: String prompt
+        required --token t: String prompt(hidden)
+        optional --server url: String
+    }
+}
@@ -0,0 +1,11 @@
+xflags! {
+    settings { gnu-short-flags, flag-assign, strict-ordering }
+
+    cmd archive {
+        repeated -v, --verbose
+        optional -c, --color value: bool
+        optional -j, --jobs n: u32
+        optional -o, --output path: PathBuf
+        repeated files: PathBuf
+    }
+}
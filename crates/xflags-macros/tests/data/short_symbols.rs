@@ -0,0 +1,11 @@
+xflags! {
+    cmd list {
+        optional paths: PathBuf
+        /// One entry per line.
+        optional -1, --one-per-line
+        /// Show extended attributes.
+        optional -@, --extended
+        optional -#, --columns n: u32
+        optional -%, --percent
+    }
+}
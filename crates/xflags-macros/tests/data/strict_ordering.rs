@@ -0,0 +1,10 @@
+xflags! {
+    settings { strict-ordering }
+
+    cmd runner {
+        cmd exec {
+            required program: String
+            repeated args: String
+        }
+    }
+}
@@ -0,0 +1,11 @@
+xflags! {
+    settings { strip-help }
+
+    /// A long description, which is left out of the binary.
+    cmd tiny {
+        builtin generate
+        /// Number of things.
+        optional -n, --count n: u32
+        cmd run {}
+    }
+}
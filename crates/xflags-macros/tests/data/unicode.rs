@@ -0,0 +1,16 @@
+xflags! {
+    /// Übersetzungswerkzeug.
+    cmd werkzeug {
+        /// Ausgabedatei.
+        optional -a, --ausgabe datei: PathBuf
+        /// Größe des Puffers.
+        optional -ß, --größe größe: u32
+        repeated -ü, --übersprungen
+
+        /// Übersetzt eine Datei.
+        cmd übersetzen ü {
+            required eingabe: PathBuf
+        }
+        cmd prüfen {}
+    }
+}
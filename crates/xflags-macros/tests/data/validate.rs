@@ -0,0 +1,8 @@
+xflags! {
+    cmd validated {
+        validate super::no_quiet_verbose
+
+        repeated -v, --verbose
+        optional -q, --quiet
+    }
+}
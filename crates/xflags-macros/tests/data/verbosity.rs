@@ -0,0 +1,9 @@
+xflags! {
+    cmd tool {
+        verbosity
+
+        cmd run {
+            optional --dry-run
+        }
+    }
+}
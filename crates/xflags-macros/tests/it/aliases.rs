@@ -20,6 +20,20 @@ pub struct Sub {
 #[derive(Debug)]
 pub struct This;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum AliasCmdField {
+    Sub_Count,
+}
+
+impl From<AliasCmdField> for usize {
+    fn from(field: AliasCmdField) -> usize {
+        match field {
+            AliasCmdField::Sub_Count => 0,
+        }
+    }
+}
+
 impl AliasCmd {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -35,6 +49,96 @@ impl AliasCmd {
     pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         Self::from_vec_(args)
     }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<AliasCmdField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AliasCmdField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Sub {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &AliasCmd::HELP_SUB__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        AliasCmd::USAGE_SUB__
+    }
+}
+
+impl This {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &AliasCmd::HELP_THIS__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        AliasCmd::USAGE_THIS__
+    }
 }
 
 impl AliasCmd {
@@ -42,76 +146,233 @@ impl AliasCmd {
         Self::from_env_().unwrap_or_else(|err| err.exit())
     }
     fn from_env_() -> xflags::Result<Self> {
-        let mut p = xflags::rt::Parser::new_from_env();
+        let mut p = xflags::rt::Parser::new_from_env()?;
         Self::parse_(&mut p)
     }
     fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         let mut p = xflags::rt::Parser::new(args);
         Self::parse_(&mut p)
     }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<AliasCmdField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AliasCmdField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
 }
 
 impl AliasCmd {
+    const FLAGS_: &'static [&'static str] = &["--count", "--help", "-c", "-h"];
     fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
         #![allow(non_snake_case, unused_mut)]
-        let mut sub__count = Vec::new();
+        #[derive(Default)]
+        struct Locals_ {
+            sub__count: Vec<usize>,
+        }
+        let mut l_ = Locals_::default();
 
-        let mut state_ = 0u8;
         while let Some(arg_) = p_.pop_flag() {
             match arg_ {
-                Ok(flag_) => match (state_, flag_.as_str()) {
-                    (0, "--help" | "-h") => return Err(p_.help(Self::HELP_)),
-                    (1, "--help" | "-h") => return Err(p_.help(Self::HELP_SUB__)),
-                    (1, "--count" | "-c") => {
-                        sub__count.push(p_.next_value_from_str::<usize>(&flag_)?)
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
                     }
-                    (2, "--help" | "-h") => return Err(p_.help(Self::HELP_THIS__)),
-                    _ => return Err(p_.unexpected_flag(&flag_)),
+                    (1, 1 | 3) => return Err(p_.help(&Self::HELP_SUB__)),
+                    (1, 0 | 2) => l_.sub__count.push(p_.next_value_from_str::<usize>(&flag_)?),
+                    (2, 1 | 3) => return Err(p_.help(&Self::HELP_THIS__)),
+                    _ => p_.unexpected_flag(&flag_)?,
                 },
-                Err(arg_) => match (state_, arg_.to_str().unwrap_or("")) {
-                    (0, "sub" | "s") => state_ = 1,
-                    (0, "this" | "one" | "has" | "a" | "lot" | "of" | "aliases") => state_ = 2,
-                    (0, "help") => return Err(p_.help(Self::HELP_)),
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"sub" | b"s") => *state_ = 1,
+                    (0, b"this" | b"one" | b"has" | b"a" | b"lot" | b"of" | b"aliases") => {
+                        *state_ = 2
+                    }
+                    (0, b"help") => p_.defer_help(),
                     (0, _) => {
                         return Err(p_.unexpected_arg(arg_));
                     }
-                    (1, "help") => return Err(p_.help(Self::HELP_SUB__)),
-                    (2, "help") => return Err(p_.help(Self::HELP_THIS__)),
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
                     _ => return Err(p_.unexpected_arg(arg_)),
                 },
             }
         }
+        if !l_.sub__count.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if matches!(*state_, | 1) && l_.sub__count.is_empty() {
+            let values_ = p_.fallback(0, &["sub"], "count");
+            for value_ in values_ {
+                l_.sub__count.push(p_.value_from_str::<usize>("--count", value_)?);
+            }
+        }
+        if matches!(*state_, | 1) {
+            p_.check_optional("--count", l_.sub__count.len())?;
+        }
         Ok(AliasCmd {
-            subcommand: match state_ {
-                1 => AliasCmdCmd::Sub(Sub { count: p_.optional("--count", sub__count)? }),
+            subcommand: match *state_ {
+                1 => AliasCmdCmd::Sub(Sub { count: l_.sub__count.pop() }),
                 2 => AliasCmdCmd::This(This {}),
                 _ => return Err(p_.subcommand_required()),
             },
         })
     }
 }
-impl AliasCmd {
-    const HELP_SUB__: &'static str = "Usage: sub [-c <count>]
 
-And even an aliased subcommand!
+impl AliasCmd {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
 
-Options:
-  -c, --count <count>  Little sanity check to see if this still works as intended
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            sub__count: Vec<usize>,
+        }
+        let mut l_ = Locals_::default();
 
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_THIS__: &'static str = "Usage: this
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_: &'static str = "Usage: alias-cmd [-h] <COMMAND>
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 1 | 3) => return Err(p_.help(&Self::HELP_SUB__)),
+                    (1, 0 | 2) => l_.sub__count.push(p_.next_value_from_str::<usize>(&flag_)?),
+                    (2, 1 | 3) => return Err(p_.help(&Self::HELP_THIS__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"sub" | b"s") => *state_ = 1,
+                    (0, b"this" | b"one" | b"has" | b"a" | b"lot" | b"of" | b"aliases") => {
+                        *state_ = 2
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--count", l_.sub__count.len())?;
+        match &mut self.subcommand {
+            AliasCmdCmd::Sub(cmd_) => {
+                if let Some(it) = l_.sub__count.pop() {
+                    cmd_.count = Some(it);
+                }
+            }
+            AliasCmdCmd::This(_) => {}
+        }
+        Ok(())
+    }
 
-commands with different aliases
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            AliasCmdCmd::Sub(_) => 1,
+            AliasCmdCmd::This(_) => 2,
+        }
+    }
+}
 
-Options:
-  -h, --help           Prints help
+impl AliasCmd {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["alias-cmd"];
+        match &self.subcommand {
+            AliasCmdCmd::Sub(_) => {
+                res_.push("sub");
+            }
+            AliasCmdCmd::This(_) => {
+                res_.push("this");
+            }
+        }
+        res_
+    }
+}
 
-Commands:
-  sub                  And even an aliased subcommand!
-  this                 
-  help                 Print this message or the help of the given subcommand(s)";
+impl xflags::rt::Mount for AliasCmd {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl AliasCmd {
+    const USAGE_SUB__: &'static str = "Usage: sub [-c <count>]";
+    const HELP_SUB__: xflags::Help = xflags::Help::new(
+        Self::USAGE_SUB__,
+        Some("And even an aliased subcommand!"),
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[(
+                    "-c, --count <count>",
+                    "Little sanity check to see if this still works as intended",
+                )],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_THIS__: &'static str = "Usage: this";
+    const HELP_THIS__: xflags::Help = xflags::Help::new(
+        Self::USAGE_THIS__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_: &'static str = "Usage: alias-cmd [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some("commands with different aliases"),
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[
+                    ("sub", "And even an aliased subcommand!"),
+                    ("this", ""),
+                    xflags::rt::HELP_ENTRY,
+                ],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_SUB__,
+            2 => Self::USAGE_THIS__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"alias-cmd\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"commands with different aliases\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"sub\",\"aliases\":[\"s\"],\"deprecated_aliases\":[],\"doc\":\"And even an aliased subcommand!\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"count\",\"short\":\"c\",\"arity\":\"optional\",\"value\":{\"name\":\"count\",\"type\":\"usize\"},\"doc\":\"Little sanity check to see if this still works as intended\"}],\"subcommands\":[]},{\"name\":\"this\",\"aliases\":[\"one\",\"has\",\"a\",\"lot\",\"of\",\"aliases\"],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
 }
@@ -0,0 +1,338 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Deploy {
+    pub color: Option<bool>,
+    pub dry_run: bool,
+    pub confirm: Vec<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeployField {
+    Color,
+    DryRun,
+    Confirm,
+}
+
+impl From<DeployField> for usize {
+    fn from(field: DeployField) -> usize {
+        match field {
+            DeployField::Color => 0,
+            DeployField::DryRun => 1,
+            DeployField::Confirm => 2,
+        }
+    }
+}
+
+impl Deploy {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<DeployField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<DeployField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Deploy {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<DeployField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<DeployField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Deploy {
+    const FLAGS_: &'static [&'static str] =
+        &["--color", "--confirm", "--dry-run", "--help", "-c", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            color: Vec<bool>,
+            dry_run: Vec<bool>,
+            confirm: Vec<bool>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 3 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 0 | 4) => l_.color.push(true),
+                    (0, _) if flag_.starts_with("--color=") => {
+                        let value_: std::ffi::OsString = flag_["--color=".len()..].into();
+                        l_.color.push(p_.value_bool("--color", value_)?);
+                    }
+                    (0, 2) => l_.dry_run.push(true),
+                    (0, _) if flag_.starts_with("--dry-run=") => {
+                        let value_: std::ffi::OsString = flag_["--dry-run=".len()..].into();
+                        l_.dry_run.push(p_.value_bool("--dry-run", value_)?);
+                    }
+                    (0, 1) => l_.confirm.push(true),
+                    (0, _) if flag_.starts_with("--confirm=") => {
+                        let value_: std::ffi::OsString = flag_["--confirm=".len()..].into();
+                        l_.confirm.push(p_.value_bool("--confirm", value_)?);
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.color.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.dry_run.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.confirm.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if l_.color.is_empty() {
+            let values_ = p_.fallback(0, &[], "color");
+            for value_ in values_ {
+                l_.color.push(p_.value_bool("--color", value_)?);
+            }
+        }
+        if l_.dry_run.is_empty() {
+            let values_ = p_.fallback(1, &[], "dry-run");
+            for value_ in values_ {
+                l_.dry_run.push(p_.value_bool("--dry-run", value_)?);
+            }
+        }
+        if l_.confirm.is_empty() {
+            let values_ = p_.fallback(2, &[], "confirm");
+            for value_ in values_ {
+                l_.confirm.push(p_.value_bool("--confirm", value_)?);
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_required("--dry-run", l_.dry_run.len())?;
+        Ok(Deploy {
+            color: l_.color.pop(),
+            dry_run: l_.dry_run.pop().unwrap(),
+            confirm: std::mem::take(&mut l_.confirm),
+        })
+    }
+}
+
+impl Deploy {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            color: Vec<bool>,
+            dry_run: Vec<bool>,
+            confirm: Vec<bool>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 3 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 0 | 4) => l_.color.push(true),
+                    (0, _) if flag_.starts_with("--color=") => {
+                        let value_: std::ffi::OsString = flag_["--color=".len()..].into();
+                        l_.color.push(p_.value_bool("--color", value_)?);
+                    }
+                    (0, 2) => l_.dry_run.push(true),
+                    (0, _) if flag_.starts_with("--dry-run=") => {
+                        let value_: std::ffi::OsString = flag_["--dry-run=".len()..].into();
+                        l_.dry_run.push(p_.value_bool("--dry-run", value_)?);
+                    }
+                    (0, 1) => l_.confirm.push(true),
+                    (0, _) if flag_.starts_with("--confirm=") => {
+                        let value_: std::ffi::OsString = flag_["--confirm=".len()..].into();
+                        l_.confirm.push(p_.value_bool("--confirm", value_)?);
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--dry-run", l_.dry_run.len())?;
+        if let Some(it) = l_.color.pop() {
+            self.color = Some(it);
+        }
+        if let Some(it) = l_.dry_run.pop() {
+            self.dry_run = it;
+        }
+        self.confirm.extend(std::mem::take(&mut l_.confirm));
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl Deploy {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["deploy"]
+    }
+}
+
+impl xflags::rt::Mount for Deploy {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Deploy {
+    const USAGE_: &'static str =
+        "Usage: deploy [--color[=<value>]] --dry-run[=<value>] [--confirm[=<value>]]... [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-c, --color[=<value>]", ""),
+                    ("--dry-run[=<value>]", ""),
+                    ("--confirm[=<value>]", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"deploy\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"color\",\"short\":\"c\",\"arity\":\"optional\",\"value\":{\"name\":\"value\",\"type\":\"bool\"},\"doc\":null},{\"name\":\"dry-run\",\"short\":null,\"arity\":\"required\",\"value\":{\"name\":\"value\",\"type\":\"bool\"},\"doc\":null},{\"name\":\"confirm\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"value\",\"type\":\"bool\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
@@ -0,0 +1,342 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Render {
+    pub config: Option<std::path::PathBuf>,
+    pub templates: Option<std::path::PathBuf>,
+    pub output: std::path::PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderField {
+    Config,
+    Templates,
+    Output,
+}
+
+impl From<RenderField> for usize {
+    fn from(field: RenderField) -> usize {
+        match field {
+            RenderField::Config => 0,
+            RenderField::Templates => 1,
+            RenderField::Output => 2,
+        }
+    }
+}
+
+impl Render {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<RenderField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RenderField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Render {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<RenderField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RenderField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Render {
+    const FLAGS_: &'static [&'static str] =
+        &["--config", "--help", "--output", "--templates", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            config: Vec<std::path::PathBuf>,
+            templates: Vec<std::path::PathBuf>,
+            output: Vec<std::path::PathBuf>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 0) => l_
+                        .config
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::ExistingFile)?),
+                    (0, 3) => l_
+                        .templates
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::ExistingDir)?),
+                    (0, 2) => l_
+                        .output
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::NewPath)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.config.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.templates.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.output.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if l_.config.is_empty() {
+            let values_ = p_.fallback(0, &[], "config");
+            for value_ in values_ {
+                l_.config.push(p_.checked_path(
+                    "--config",
+                    value_,
+                    xflags::rt::PathCheck::ExistingFile,
+                )?);
+            }
+        }
+        if l_.templates.is_empty() {
+            let values_ = p_.fallback(1, &[], "templates");
+            for value_ in values_ {
+                l_.templates.push(p_.checked_path(
+                    "--templates",
+                    value_,
+                    xflags::rt::PathCheck::ExistingDir,
+                )?);
+            }
+        }
+        if l_.output.is_empty() {
+            let values_ = p_.fallback(2, &[], "output");
+            for value_ in values_ {
+                l_.output.push(p_.checked_path(
+                    "--output",
+                    value_,
+                    xflags::rt::PathCheck::NewPath,
+                )?);
+            }
+        }
+        p_.check_optional("--config", l_.config.len())?;
+        p_.check_optional("--templates", l_.templates.len())?;
+        p_.check_required("--output", l_.output.len())?;
+        Ok(Render {
+            config: l_.config.pop(),
+            templates: l_.templates.pop(),
+            output: l_.output.pop().unwrap(),
+        })
+    }
+}
+
+impl Render {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            config: Vec<std::path::PathBuf>,
+            templates: Vec<std::path::PathBuf>,
+            output: Vec<std::path::PathBuf>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 0) => l_
+                        .config
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::ExistingFile)?),
+                    (0, 3) => l_
+                        .templates
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::ExistingDir)?),
+                    (0, 2) => l_
+                        .output
+                        .push(p_.next_checked_path(&flag_, xflags::rt::PathCheck::NewPath)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--config", l_.config.len())?;
+        p_.check_optional("--templates", l_.templates.len())?;
+        p_.check_optional("--output", l_.output.len())?;
+        if let Some(it) = l_.config.pop() {
+            self.config = Some(it);
+        }
+        if let Some(it) = l_.templates.pop() {
+            self.templates = Some(it);
+        }
+        if let Some(it) = l_.output.pop() {
+            self.output = it;
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl Render {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["render"]
+    }
+}
+
+impl xflags::rt::Mount for Render {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Render {
+    const USAGE_: &'static str =
+        "Usage: render [--config <path>] [--templates <dir>] --output <path> [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("--config <path>", ""),
+                    ("--templates <dir>", ""),
+                    ("--output <path>", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"render\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"config\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"path\",\"type\":\"ExistingFile\"},\"doc\":null},{\"name\":\"templates\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"dir\",\"type\":\"ExistingDir\"},\"doc\":null},{\"name\":\"output\",\"short\":null,\"arity\":\"required\",\"value\":{\"name\":\"path\",\"type\":\"NewPath\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
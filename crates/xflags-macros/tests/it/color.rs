@@ -0,0 +1,396 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Paint {
+    pub color: Option<xflags::ColorChoice>,
+    pub dry_run: bool,
+    pub subcommand: PaintCmd,
+}
+
+#[derive(Debug)]
+pub enum PaintCmd {
+    Wall(Wall),
+}
+
+#[derive(Debug)]
+pub struct Wall {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum PaintField {
+    Color,
+    DryRun,
+    Wall_Name,
+}
+
+impl From<PaintField> for usize {
+    fn from(field: PaintField) -> usize {
+        match field {
+            PaintField::Color => 0,
+            PaintField::DryRun => 1,
+            PaintField::Wall_Name => 2,
+        }
+    }
+}
+
+impl Paint {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<PaintField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<PaintField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+
+    #[allow(dead_code)]
+    pub fn color_choice(&self) -> xflags::ColorChoice {
+        self.color.unwrap_or_default()
+    }
+}
+
+impl Wall {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Paint::HELP_WALL__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Paint::USAGE_WALL__
+    }
+}
+
+impl Paint {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<PaintField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<PaintField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Paint {
+    const FLAGS_: &'static [&'static str] = &["--color", "--dry-run", "--help", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        p_.scan_color(false);
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            color: Vec<xflags::ColorChoice>,
+            dry_run: Vec<()>,
+            wall__name: (bool, Vec<String>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0) => {
+                        l_.color.push(p_.next_value_from_str::<xflags::ColorChoice>(&flag_)?)
+                    }
+                    (0..=1, 1) => l_.dry_run.push(()),
+                    (1, 2 | 3) => return Err(p_.help(&Self::HELP_WALL__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"wall") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.wall__name {
+                            buf_.push(p_.value_from_str::<String>("name", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.color.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.dry_run.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.wall__name.1.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if l_.color.is_empty() {
+            let values_ = p_.fallback(0, &[], "color");
+            for value_ in values_ {
+                l_.color.push(p_.value_from_str::<xflags::ColorChoice>("--color", value_)?);
+            }
+        }
+        if l_.dry_run.is_empty() {
+            let values_ = p_.fallback(1, &[], "dry-run");
+            for _ in 0..p_.switch_count("--dry-run", values_, true)? {
+                l_.dry_run.push(());
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--dry-run", l_.dry_run.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_required("name", l_.wall__name.1.len())?;
+        }
+        Ok(Paint {
+            color: l_.color.pop(),
+            dry_run: !l_.dry_run.is_empty(),
+            subcommand: match *state_ {
+                1 => PaintCmd::Wall(Wall { name: l_.wall__name.1.pop().unwrap() }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Paint {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            color: Vec<xflags::ColorChoice>,
+            dry_run: Vec<()>,
+            wall__name: (bool, Vec<String>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0) => {
+                        l_.color.push(p_.next_value_from_str::<xflags::ColorChoice>(&flag_)?)
+                    }
+                    (0..=1, 1) => l_.dry_run.push(()),
+                    (1, 2 | 3) => return Err(p_.help(&Self::HELP_WALL__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"wall") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.wall__name {
+                            buf_.push(p_.value_from_str::<String>("name", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--dry-run", l_.dry_run.len())?;
+        p_.check_optional("name", l_.wall__name.1.len())?;
+        if let Some(it) = l_.color.pop() {
+            self.color = Some(it);
+        }
+        if !l_.dry_run.is_empty() {
+            self.dry_run = true;
+        }
+        match &mut self.subcommand {
+            PaintCmd::Wall(cmd_) => {
+                if let Some(it) = l_.wall__name.1.pop() {
+                    cmd_.name = it;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            PaintCmd::Wall(_) => 1,
+        }
+    }
+}
+
+impl Paint {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["paint"];
+        match &self.subcommand {
+            PaintCmd::Wall(_) => {
+                res_.push("wall");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Paint {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Paint {
+    const USAGE_WALL__: &'static str = "Usage: wall <name>";
+    const HELP_WALL__: xflags::Help = xflags::Help::new(
+        Self::USAGE_WALL__,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("<name>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: paint [--color <when>] [--dry-run] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("--color <when>", "When to use colors: auto, always or never"),
+                    ("--dry-run", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("wall", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_WALL__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"paint\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"color\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"when\",\"type\":\"xflags::ColorChoice\"},\"doc\":\"When to use colors: auto, always or never\"},{\"name\":\"dry-run\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"wall\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"name\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"String\",\"doc\":null}],\"flags\":[],\"subcommands\":[]}]}}";
+}
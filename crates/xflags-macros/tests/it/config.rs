@@ -0,0 +1,487 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Configured {
+    pub jobs: Option<u32>,
+    pub verbose: u32,
+    pub color: bool,
+    pub subcommand: ConfiguredCmd,
+}
+
+#[derive(Debug)]
+pub enum ConfiguredCmd {
+    Build(Build),
+    Test(Test),
+}
+
+#[derive(Debug)]
+pub struct Build {
+    pub feature: Vec<String>,
+    pub target: String,
+}
+
+#[derive(Debug)]
+pub struct Test {
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ConfiguredField {
+    Jobs,
+    Verbose,
+    Color,
+    Build_Feature,
+    Build_Target,
+    Test_Target,
+}
+
+impl From<ConfiguredField> for usize {
+    fn from(field: ConfiguredField) -> usize {
+        match field {
+            ConfiguredField::Jobs => 0,
+            ConfiguredField::Verbose => 1,
+            ConfiguredField::Color => 2,
+            ConfiguredField::Build_Feature => 3,
+            ConfiguredField::Build_Target => 4,
+            ConfiguredField::Test_Target => 5,
+        }
+    }
+}
+
+impl Configured {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ConfiguredField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ConfiguredField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Build {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Configured::HELP_BUILD__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Configured::USAGE_BUILD__
+    }
+}
+
+impl Test {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Configured::HELP_TEST__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Configured::USAGE_TEST__
+    }
+}
+
+impl Configured {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ConfiguredField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ConfiguredField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Configured {
+    const FLAGS_: &'static [&'static str] =
+        &["--color", "--feature", "--help", "--jobs", "--target", "--verbose", "-h", "-j", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        p_.load_config("tests/config.toml")?;
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            jobs: Vec<u32>,
+            verbose: Vec<()>,
+            color: Vec<()>,
+            build__feature: Vec<String>,
+            build__target: Vec<String>,
+            test__target: Vec<String>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 3 | 7) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=2, 5 | 8) => l_.verbose.push(()),
+                    (0..=2, 0) => l_.color.push(()),
+                    (1, 2 | 6) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 1) => l_.build__feature.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (1, 4) => l_.build__target.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (2, 2 | 6) => return Err(p_.help(&Self::HELP_TEST__)),
+                    (2, 4) => l_.test__target.push(p_.next_value_from_str::<String>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build") => *state_ = 1,
+                    (0, b"test") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.jobs.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.color.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.build__feature.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.build__target.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if !l_.test__target.is_empty() {
+            p_.set_source(5, xflags::ValueSource::CommandLine);
+        }
+        if l_.jobs.is_empty() {
+            let values_ = p_.fallback(0, &[], "jobs");
+            for value_ in values_ {
+                l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+            }
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(1, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if l_.color.is_empty() {
+            let values_ = p_.fallback(2, &[], "color");
+            for _ in 0..p_.switch_count("--color", values_, true)? {
+                l_.color.push(());
+            }
+        }
+        if matches!(*state_, | 1) && l_.build__feature.is_empty() {
+            let values_ = p_.fallback(3, &["build"], "feature");
+            for value_ in values_ {
+                l_.build__feature.push(p_.value_from_str::<String>("--feature", value_)?);
+            }
+        }
+        if matches!(*state_, | 1) && l_.build__target.is_empty() {
+            let values_ = p_.fallback(4, &["build"], "target");
+            for value_ in values_ {
+                l_.build__target.push(p_.value_from_str::<String>("--target", value_)?);
+            }
+        }
+        if matches!(*state_, | 2) && l_.test__target.is_empty() {
+            let values_ = p_.fallback(5, &["test"], "target");
+            for value_ in values_ {
+                l_.test__target.push(p_.value_from_str::<String>("--target", value_)?);
+            }
+        }
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--color", l_.color.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_required("--target", l_.build__target.len())?;
+        }
+        if matches!(*state_, | 2) {
+            p_.check_optional("--target", l_.test__target.len())?;
+        }
+        Ok(Configured {
+            jobs: l_.jobs.pop(),
+            verbose: l_.verbose.len() as u32,
+            color: !l_.color.is_empty(),
+            subcommand: match *state_ {
+                1 => ConfiguredCmd::Build(Build {
+                    feature: std::mem::take(&mut l_.build__feature),
+                    target: l_.build__target.pop().unwrap(),
+                }),
+                2 => ConfiguredCmd::Test(Test { target: l_.test__target.pop() }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Configured {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            jobs: Vec<u32>,
+            verbose: Vec<()>,
+            color: Vec<()>,
+            build__feature: Vec<String>,
+            build__target: Vec<String>,
+            test__target: Vec<String>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 3 | 7) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=2, 5 | 8) => l_.verbose.push(()),
+                    (0..=2, 0) => l_.color.push(()),
+                    (1, 2 | 6) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 1) => l_.build__feature.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (1, 4) => l_.build__target.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (2, 2 | 6) => return Err(p_.help(&Self::HELP_TEST__)),
+                    (2, 4) => l_.test__target.push(p_.next_value_from_str::<String>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build") => *state_ = 1,
+                    (0, b"test") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--target", l_.build__target.len())?;
+        p_.check_optional("--target", l_.test__target.len())?;
+        if let Some(it) = l_.jobs.pop() {
+            self.jobs = Some(it);
+        }
+        self.verbose += l_.verbose.len() as u32;
+        if !l_.color.is_empty() {
+            self.color = true;
+        }
+        match &mut self.subcommand {
+            ConfiguredCmd::Build(cmd_) => {
+                cmd_.feature.extend(std::mem::take(&mut l_.build__feature));
+                if let Some(it) = l_.build__target.pop() {
+                    cmd_.target = it;
+                }
+            }
+            ConfiguredCmd::Test(cmd_) => {
+                if let Some(it) = l_.test__target.pop() {
+                    cmd_.target = Some(it);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            ConfiguredCmd::Build(_) => 1,
+            ConfiguredCmd::Test(_) => 2,
+        }
+    }
+}
+
+impl Configured {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["configured"];
+        match &self.subcommand {
+            ConfiguredCmd::Build(_) => {
+                res_.push("build");
+            }
+            ConfiguredCmd::Test(_) => {
+                res_.push("test");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Configured {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Configured {
+    const USAGE_BUILD__: &'static str = "Usage: build [--feature <name>]... --target <triple>";
+    const HELP_BUILD__: xflags::Help = xflags::Help::new(
+        Self::USAGE_BUILD__,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("--feature <name>", ""), ("--target <triple>", "")],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_TEST__: &'static str = "Usage: test [--target <triple>]";
+    const HELP_TEST__: xflags::Help = xflags::Help::new(
+        Self::USAGE_TEST__,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--target <triple>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: configured [-j <n>] [-v]... [--color] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-j, --jobs <n>", ""),
+                    ("-v, --verbose", ""),
+                    ("--color", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("build", ""), ("test", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_BUILD__,
+            2 => Self::USAGE_TEST__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"configured\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"jobs\",\"short\":\"j\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"color\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"build\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"feature\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"name\",\"type\":\"String\"},\"doc\":null},{\"name\":\"target\",\"short\":null,\"arity\":\"required\",\"value\":{\"name\":\"triple\",\"type\":\"String\"},\"doc\":null}],\"subcommands\":[]},{\"name\":\"test\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"target\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"triple\",\"type\":\"String\"},\"doc\":null}],\"subcommands\":[]}]}}";
+}
@@ -0,0 +1,380 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Tool {
+    pub subcommand: ToolCmd,
+}
+
+#[derive(Debug)]
+pub enum ToolCmd {
+    Lint(Lint),
+    Build(Build),
+}
+
+#[derive(Debug)]
+pub struct Lint {
+    pub fix: bool,
+}
+
+#[derive(Debug)]
+pub struct Build;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ToolField {
+    Lint_Fix,
+}
+
+impl From<ToolField> for usize {
+    fn from(field: ToolField) -> usize {
+        match field {
+            ToolField::Lint_Fix => 0,
+        }
+    }
+}
+
+impl Tool {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Lint {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_LINT__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_LINT__
+    }
+}
+
+impl Build {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_BUILD__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_BUILD__
+    }
+}
+
+impl Tool {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Tool {
+    const FLAGS_: &'static [&'static str] = &["--fix", "--help", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            lint__fix: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 1 | 2) => return Err(p_.help(&Self::HELP_LINT__)),
+                    (1, 0) => l_.lint__fix.push(()),
+                    (2, 1 | 2) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"lint") => *state_ = 1,
+                    (0, b"check") => {
+                        p_.deprecated("check", "lint");
+                        *state_ = 1;
+                    }
+                    (0, b"verify") => {
+                        p_.deprecated("verify", "lint");
+                        *state_ = 1;
+                    }
+                    (0, b"build" | b"b") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.lint__fix.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if matches!(*state_, | 1) && l_.lint__fix.is_empty() {
+            let values_ = p_.fallback(0, &["lint"], "fix");
+            for _ in 0..p_.switch_count("--fix", values_, true)? {
+                l_.lint__fix.push(());
+            }
+        }
+        if matches!(*state_, | 1) {
+            p_.check_optional("--fix", l_.lint__fix.len())?;
+        }
+        Ok(Tool {
+            subcommand: match *state_ {
+                1 => ToolCmd::Lint(Lint { fix: !l_.lint__fix.is_empty() }),
+                2 => ToolCmd::Build(Build {}),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Tool {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            lint__fix: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 1 | 2) => return Err(p_.help(&Self::HELP_LINT__)),
+                    (1, 0) => l_.lint__fix.push(()),
+                    (2, 1 | 2) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"lint") => *state_ = 1,
+                    (0, b"check") => {
+                        p_.deprecated("check", "lint");
+                        *state_ = 1;
+                    }
+                    (0, b"verify") => {
+                        p_.deprecated("verify", "lint");
+                        *state_ = 1;
+                    }
+                    (0, b"build" | b"b") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--fix", l_.lint__fix.len())?;
+        match &mut self.subcommand {
+            ToolCmd::Lint(cmd_) => {
+                if !l_.lint__fix.is_empty() {
+                    cmd_.fix = true;
+                }
+            }
+            ToolCmd::Build(_) => {}
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            ToolCmd::Lint(_) => 1,
+            ToolCmd::Build(_) => 2,
+        }
+    }
+}
+
+impl Tool {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["tool"];
+        match &self.subcommand {
+            ToolCmd::Lint(_) => {
+                res_.push("lint");
+            }
+            ToolCmd::Build(_) => {
+                res_.push("build");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Tool {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Tool {
+    const USAGE_LINT__: &'static str = "Usage: lint [--fix]";
+    const HELP_LINT__: xflags::Help = xflags::Help::new(
+        Self::USAGE_LINT__,
+        Some("Checks the code."),
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--fix", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_BUILD__: &'static str = "Usage: build";
+    const HELP_BUILD__: xflags::Help = xflags::Help::new(
+        Self::USAGE_BUILD__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_: &'static str = "Usage: tool [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("lint", "Checks the code."), ("build", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_LINT__,
+            2 => Self::USAGE_BUILD__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"tool\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"lint\",\"aliases\":[],\"deprecated_aliases\":[\"check\",\"verify\"],\"doc\":\"Checks the code.\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"fix\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[]},{\"name\":\"build\",\"aliases\":[\"b\"],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+}
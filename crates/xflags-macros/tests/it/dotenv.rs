@@ -0,0 +1,311 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Server {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub verbose: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerField {
+    Host,
+    Port,
+    Verbose,
+}
+
+impl From<ServerField> for usize {
+    fn from(field: ServerField) -> usize {
+        match field {
+            ServerField::Host => 0,
+            ServerField::Port => 1,
+            ServerField::Verbose => 2,
+        }
+    }
+}
+
+impl Server {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ServerField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ServerField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Server {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ServerField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ServerField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Server {
+    const FLAGS_: &'static [&'static str] =
+        &["--help", "--host", "--port", "--verbose", "-h", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        p_.load_env("XFLAGS_DOTENV_");
+        p_.load_dotenv("XFLAGS_DOTENV_", "tests/test.env")?;
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            host: Vec<String>,
+            port: Vec<u16>,
+            verbose: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 1) => l_.host.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (0, 2) => l_.port.push(p_.next_value_from_str::<u16>(&flag_)?),
+                    (0, 3 | 5) => l_.verbose.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.host.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.port.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if l_.host.is_empty() {
+            let values_ = p_.fallback(0, &[], "host");
+            for value_ in values_ {
+                l_.host.push(p_.value_from_str::<String>("--host", value_)?);
+            }
+        }
+        if l_.port.is_empty() {
+            let values_ = p_.fallback(1, &[], "port");
+            for value_ in values_ {
+                l_.port.push(p_.value_from_str::<u16>("--port", value_)?);
+            }
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(2, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        p_.check_optional("--host", l_.host.len())?;
+        p_.check_optional("--port", l_.port.len())?;
+        Ok(Server { host: l_.host.pop(), port: l_.port.pop(), verbose: l_.verbose.len() as u32 })
+    }
+}
+
+impl Server {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            host: Vec<String>,
+            port: Vec<u16>,
+            verbose: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 1) => l_.host.push(p_.next_value_from_str::<String>(&flag_)?),
+                    (0, 2) => l_.port.push(p_.next_value_from_str::<u16>(&flag_)?),
+                    (0, 3 | 5) => l_.verbose.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--host", l_.host.len())?;
+        p_.check_optional("--port", l_.port.len())?;
+        if let Some(it) = l_.host.pop() {
+            self.host = Some(it);
+        }
+        if let Some(it) = l_.port.pop() {
+            self.port = Some(it);
+        }
+        self.verbose += l_.verbose.len() as u32;
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl Server {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["server"]
+    }
+}
+
+impl xflags::rt::Mount for Server {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Server {
+    const USAGE_: &'static str = "Usage: server [--host <name>] [--port <n>] [-v]... [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("--host <name>", ""),
+                    ("--port <n>", ""),
+                    ("-v, --verbose", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"server\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"host\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"name\",\"type\":\"String\"},\"doc\":null},{\"name\":\"port\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u16\"},\"doc\":null},{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
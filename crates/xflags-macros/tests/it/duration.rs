@@ -0,0 +1,348 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Fetch {
+    pub tick: Option<std::time::Duration>,
+
+    pub timeout: Option<std::time::Duration>,
+    pub retry_after: Vec<std::time::Duration>,
+    pub ttl: Option<super::units::Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchField {
+    Timeout,
+    RetryAfter,
+    Ttl,
+    Tick,
+}
+
+impl From<FetchField> for usize {
+    fn from(field: FetchField) -> usize {
+        match field {
+            FetchField::Timeout => 0,
+            FetchField::RetryAfter => 1,
+            FetchField::Ttl => 2,
+            FetchField::Tick => 3,
+        }
+    }
+}
+
+impl Fetch {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<FetchField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<FetchField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Fetch {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<FetchField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<FetchField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Fetch {
+    const FLAGS_: &'static [&'static str] =
+        &["--help", "--retry-after", "--timeout", "--ttl", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            timeout: Vec<std::time::Duration>,
+            retry_after: Vec<std::time::Duration>,
+            ttl: Vec<super::units::Duration>,
+            tick: (bool, Vec<std::time::Duration>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 2) => l_.timeout.push(p_.next_value_duration(&flag_)?),
+                    (0, 1) => l_.retry_after.push(p_.next_value_duration(&flag_)?),
+                    (0, 3) => {
+                        l_.ttl.push(p_.next_value_from_str::<super::units::Duration>(&flag_)?)
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.tick {
+                            buf_.push(p_.value_duration("tick", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.timeout.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.retry_after.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.ttl.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.tick.1.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if l_.timeout.is_empty() {
+            let values_ = p_.fallback(0, &[], "timeout");
+            for value_ in values_ {
+                l_.timeout.push(p_.value_duration("--timeout", value_)?);
+            }
+        }
+        if l_.retry_after.is_empty() {
+            let values_ = p_.fallback(1, &[], "retry-after");
+            for value_ in values_ {
+                l_.retry_after.push(p_.value_duration("--retry-after", value_)?);
+            }
+        }
+        if l_.ttl.is_empty() {
+            let values_ = p_.fallback(2, &[], "ttl");
+            for value_ in values_ {
+                l_.ttl.push(p_.value_from_str::<super::units::Duration>("--ttl", value_)?);
+            }
+        }
+        p_.check_optional("--timeout", l_.timeout.len())?;
+        p_.check_optional("--ttl", l_.ttl.len())?;
+        p_.check_optional("tick", l_.tick.1.len())?;
+        Ok(Fetch {
+            timeout: l_.timeout.pop(),
+            retry_after: std::mem::take(&mut l_.retry_after),
+            ttl: l_.ttl.pop(),
+            tick: l_.tick.1.pop(),
+        })
+    }
+}
+
+impl Fetch {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            timeout: Vec<std::time::Duration>,
+            retry_after: Vec<std::time::Duration>,
+            ttl: Vec<super::units::Duration>,
+            tick: (bool, Vec<std::time::Duration>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 2) => l_.timeout.push(p_.next_value_duration(&flag_)?),
+                    (0, 1) => l_.retry_after.push(p_.next_value_duration(&flag_)?),
+                    (0, 3) => {
+                        l_.ttl.push(p_.next_value_from_str::<super::units::Duration>(&flag_)?)
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.tick {
+                            buf_.push(p_.value_duration("tick", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--timeout", l_.timeout.len())?;
+        p_.check_optional("--ttl", l_.ttl.len())?;
+        p_.check_optional("tick", l_.tick.1.len())?;
+        if let Some(it) = l_.timeout.pop() {
+            self.timeout = Some(it);
+        }
+        self.retry_after.extend(std::mem::take(&mut l_.retry_after));
+        if let Some(it) = l_.ttl.pop() {
+            self.ttl = Some(it);
+        }
+        if let Some(it) = l_.tick.1.pop() {
+            self.tick = Some(it);
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl Fetch {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["fetch"]
+    }
+}
+
+impl xflags::rt::Mount for Fetch {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Fetch {
+    const USAGE_: &'static str =
+        "Usage: fetch [tick] [--timeout <duration>] [--retry-after <delay>]... [--ttl <ttl>] [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[tick]", "")] },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("--timeout <duration>", ""),
+                    ("--retry-after <delay>", ""),
+                    ("--ttl <ttl>", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"fetch\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"tick\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"Duration\",\"doc\":null}],\"flags\":[{\"name\":\"timeout\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"duration\",\"type\":\"Duration\"},\"doc\":null},{\"name\":\"retry-after\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"delay\",\"type\":\"Duration\"},\"doc\":null},{\"name\":\"ttl\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"ttl\",\"type\":\"super::units::Duration\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
@@ -0,0 +1,395 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Tool {
+    pub jobs: Option<u32>,
+    pub verbose: u32,
+    pub log_file: Option<PathBuf>,
+    pub subcommand: ToolCmd,
+}
+
+#[derive(Debug)]
+pub enum ToolCmd {
+    Build(Build),
+}
+
+#[derive(Debug)]
+pub struct Build {
+    pub feature: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ToolField {
+    Jobs,
+    Verbose,
+    LogFile,
+    Build_Feature,
+}
+
+impl From<ToolField> for usize {
+    fn from(field: ToolField) -> usize {
+        match field {
+            ToolField::Jobs => 0,
+            ToolField::Verbose => 1,
+            ToolField::LogFile => 2,
+            ToolField::Build_Feature => 3,
+        }
+    }
+}
+
+impl Tool {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Build {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_BUILD__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_BUILD__
+    }
+}
+
+impl Tool {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Tool {
+    const FLAGS_: &'static [&'static str] =
+        &["--feature", "--help", "--jobs", "--log-file", "--verbose", "-h", "-j", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        p_.load_env("XFLAGS_TEST_");
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            jobs: Vec<u32>,
+            verbose: Vec<()>,
+            log_file: Vec<PathBuf>,
+            build__feature: Vec<String>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 2 | 6) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=1, 4 | 7) => l_.verbose.push(()),
+                    (0..=1, 3) => l_.log_file.push(p_.next_value(&flag_)?.into()),
+                    (1, 1 | 5) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 0) => l_.build__feature.push(p_.next_value_from_str::<String>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.jobs.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.log_file.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.build__feature.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if l_.jobs.is_empty() {
+            let values_ = p_.fallback(0, &[], "jobs");
+            for value_ in values_ {
+                l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+            }
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(1, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if l_.log_file.is_empty() {
+            let values_ = p_.fallback(2, &[], "log-file");
+            for value_ in values_ {
+                l_.log_file.push(value_.into());
+            }
+        }
+        if matches!(*state_, | 1) && l_.build__feature.is_empty() {
+            let values_ = p_.fallback(3, &["build"], "feature");
+            for value_ in values_ {
+                l_.build__feature.push(p_.value_from_str::<String>("--feature", value_)?);
+            }
+        }
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--log-file", l_.log_file.len())?;
+        Ok(Tool {
+            jobs: l_.jobs.pop(),
+            verbose: l_.verbose.len() as u32,
+            log_file: l_.log_file.pop(),
+            subcommand: match *state_ {
+                1 => ToolCmd::Build(Build { feature: std::mem::take(&mut l_.build__feature) }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Tool {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            jobs: Vec<u32>,
+            verbose: Vec<()>,
+            log_file: Vec<PathBuf>,
+            build__feature: Vec<String>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 2 | 6) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=1, 4 | 7) => l_.verbose.push(()),
+                    (0..=1, 3) => l_.log_file.push(p_.next_value(&flag_)?.into()),
+                    (1, 1 | 5) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 0) => l_.build__feature.push(p_.next_value_from_str::<String>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--log-file", l_.log_file.len())?;
+        if let Some(it) = l_.jobs.pop() {
+            self.jobs = Some(it);
+        }
+        self.verbose += l_.verbose.len() as u32;
+        if let Some(it) = l_.log_file.pop() {
+            self.log_file = Some(it);
+        }
+        match &mut self.subcommand {
+            ToolCmd::Build(cmd_) => {
+                cmd_.feature.extend(std::mem::take(&mut l_.build__feature));
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            ToolCmd::Build(_) => 1,
+        }
+    }
+}
+
+impl Tool {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["tool"];
+        match &self.subcommand {
+            ToolCmd::Build(_) => {
+                res_.push("build");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Tool {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Tool {
+    const USAGE_BUILD__: &'static str = "Usage: build [--feature <name>]...";
+    const HELP_BUILD__: xflags::Help = xflags::Help::new(
+        Self::USAGE_BUILD__,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--feature <name>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: tool [-j <n>] [-v]... [--log-file <path>] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-j, --jobs <n>", ""),
+                    ("-v, --verbose", ""),
+                    ("--log-file <path>", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("build", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_BUILD__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"tool\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"jobs\",\"short\":\"j\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"log-file\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"path\",\"type\":\"PathBuf\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"build\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"feature\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"name\",\"type\":\"String\"},\"doc\":null}],\"subcommands\":[]}]}}";
+}
@@ -0,0 +1,325 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Plug {
+    pub verbose: bool,
+    pub subcommand: PlugCmd,
+}
+
+#[derive(Debug)]
+pub enum PlugCmd {
+    List(List),
+}
+
+#[derive(Debug)]
+pub struct List;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlugField {
+    Verbose,
+}
+
+impl From<PlugField> for usize {
+    fn from(field: PlugField) -> usize {
+        match field {
+            PlugField::Verbose => 0,
+        }
+    }
+}
+
+impl Plug {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<PlugField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<PlugField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl List {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Plug::HELP_LIST__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Plug::USAGE_LIST__
+    }
+}
+
+impl Plug {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<PlugField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<PlugField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Plug {
+    const FLAGS_: &'static [&'static str] = &["--help", "--verbose", "-h", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 1 | 3) => l_.verbose.push(()),
+                    (1, 0 | 2) => return Err(p_.help(&Self::HELP_LIST__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"list") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(0, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, true)? {
+                l_.verbose.push(());
+            }
+        }
+        p_.check_optional("--verbose", l_.verbose.len())?;
+        Ok(Plug {
+            verbose: !l_.verbose.is_empty(),
+            subcommand: match *state_ {
+                1 => PlugCmd::List(List {}),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Plug {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 1 | 3) => l_.verbose.push(()),
+                    (1, 0 | 2) => return Err(p_.help(&Self::HELP_LIST__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"list") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--verbose", l_.verbose.len())?;
+        if !l_.verbose.is_empty() {
+            self.verbose = true;
+        }
+        match &mut self.subcommand {
+            PlugCmd::List(_) => {}
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            PlugCmd::List(_) => 1,
+        }
+    }
+}
+
+impl Plug {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["plug"];
+        match &self.subcommand {
+            PlugCmd::List(_) => {
+                res_.push("list");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Plug {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Plug {
+    const USAGE_LIST__: &'static str = "Usage: list";
+    const HELP_LIST__: xflags::Help = xflags::Help::new(
+        Self::USAGE_LIST__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_: &'static str = "Usage: plug [-v] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some("Runs plugins."),
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("-v, --verbose", ""), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("list", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_LIST__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"plug\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"Runs plugins.\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"list\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+}
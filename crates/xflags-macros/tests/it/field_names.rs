@@ -0,0 +1,354 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Make {
+    pub foo_jobs: Option<u32>,
+    pub subcommand: MakeCmd,
+}
+
+#[derive(Debug)]
+pub enum MakeCmd {
+    Foo(Foo),
+}
+
+#[derive(Debug)]
+pub struct Foo {
+    pub jobs: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum MakeField {
+    FooJobs,
+    Foo_Jobs,
+}
+
+impl From<MakeField> for usize {
+    fn from(field: MakeField) -> usize {
+        match field {
+            MakeField::FooJobs => 0,
+            MakeField::Foo_Jobs => 1,
+        }
+    }
+}
+
+impl Make {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<MakeField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<MakeField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Foo {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Make::HELP_FOO__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Make::USAGE_FOO__
+    }
+}
+
+impl Make {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<MakeField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<MakeField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Make {
+    const FLAGS_: &'static [&'static str] = &["--foo-jobs", "--help", "--jobs", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            foo_jobs: Vec<u32>,
+            foo__jobs: Vec<u32>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0) => l_.foo_jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (1, 1 | 3) => return Err(p_.help(&Self::HELP_FOO__)),
+                    (1, 2) => l_.foo__jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"foo") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.foo_jobs.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.foo__jobs.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if l_.foo_jobs.is_empty() {
+            let values_ = p_.fallback(0, &[], "foo-jobs");
+            for value_ in values_ {
+                l_.foo_jobs.push(p_.value_from_str::<u32>("--foo-jobs", value_)?);
+            }
+        }
+        if matches!(*state_, | 1) && l_.foo__jobs.is_empty() {
+            let values_ = p_.fallback(1, &["foo"], "jobs");
+            for value_ in values_ {
+                l_.foo__jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+            }
+        }
+        p_.check_optional("--foo-jobs", l_.foo_jobs.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_optional("--jobs", l_.foo__jobs.len())?;
+        }
+        Ok(Make {
+            foo_jobs: l_.foo_jobs.pop(),
+            subcommand: match *state_ {
+                1 => MakeCmd::Foo(Foo { jobs: l_.foo__jobs.pop() }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Make {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            foo_jobs: Vec<u32>,
+            foo__jobs: Vec<u32>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0) => l_.foo_jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (1, 1 | 3) => return Err(p_.help(&Self::HELP_FOO__)),
+                    (1, 2) => l_.foo__jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"foo") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--foo-jobs", l_.foo_jobs.len())?;
+        p_.check_optional("--jobs", l_.foo__jobs.len())?;
+        if let Some(it) = l_.foo_jobs.pop() {
+            self.foo_jobs = Some(it);
+        }
+        match &mut self.subcommand {
+            MakeCmd::Foo(cmd_) => {
+                if let Some(it) = l_.foo__jobs.pop() {
+                    cmd_.jobs = Some(it);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            MakeCmd::Foo(_) => 1,
+        }
+    }
+}
+
+impl Make {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["make"];
+        match &self.subcommand {
+            MakeCmd::Foo(_) => {
+                res_.push("foo");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Make {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Make {
+    const USAGE_FOO__: &'static str = "Usage: foo [--jobs <n>]";
+    const HELP_FOO__: xflags::Help = xflags::Help::new(
+        Self::USAGE_FOO__,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--jobs <n>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: make [--foo-jobs <n>] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("--foo-jobs <n>", ""), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("foo", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_FOO__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"make\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"foo-jobs\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"foo\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"jobs\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null}],\"subcommands\":[]}]}}";
+}
@@ -0,0 +1,651 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Copy {
+    pub subcommand: CopyCmd,
+}
+
+#[derive(Debug)]
+pub enum CopyCmd {
+    Cp(Cp),
+    Mv(Mv),
+    Ln(Ln),
+}
+
+#[derive(Debug)]
+pub struct Cp {
+    pub sources: Vec<PathBuf>,
+    pub dest: PathBuf,
+
+    pub recursive: bool,
+}
+
+#[derive(Debug)]
+pub struct Mv {
+    pub source: Option<PathBuf>,
+    pub dest: PathBuf,
+
+    pub jobs: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct Ln {
+    pub mode: Option<u32>,
+    pub links: Vec<OsString>,
+    pub target: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum CopyField {
+    Cp_Recursive,
+    Cp_Sources,
+    Cp_Dest,
+    Mv_Jobs,
+    Mv_Source,
+    Mv_Dest,
+    Ln_Mode,
+    Ln_Links,
+    Ln_Target,
+}
+
+impl From<CopyField> for usize {
+    fn from(field: CopyField) -> usize {
+        match field {
+            CopyField::Cp_Recursive => 0,
+            CopyField::Cp_Sources => 1,
+            CopyField::Cp_Dest => 2,
+            CopyField::Mv_Jobs => 3,
+            CopyField::Mv_Source => 4,
+            CopyField::Mv_Dest => 5,
+            CopyField::Ln_Mode => 6,
+            CopyField::Ln_Links => 7,
+            CopyField::Ln_Target => 8,
+        }
+    }
+}
+
+impl Copy {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<CopyField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<CopyField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Cp {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Copy::HELP_CP__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Copy::USAGE_CP__
+    }
+}
+
+impl Mv {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Copy::HELP_MV__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Copy::USAGE_MV__
+    }
+}
+
+impl Ln {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Copy::HELP_LN__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Copy::USAGE_LN__
+    }
+}
+
+impl Copy {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<CopyField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<CopyField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Copy {
+    const FLAGS_: &'static [&'static str] = &["--help", "--jobs", "--recursive", "-h", "-n", "-r"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            cp__recursive: Vec<()>,
+            cp__sources: (bool, Vec<PathBuf>),
+            cp__dest: (bool, Vec<PathBuf>),
+            cp__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+            mv__jobs: Vec<u32>,
+            mv__source: (bool, Vec<PathBuf>),
+            mv__dest: (bool, Vec<PathBuf>),
+            mv__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+            ln__mode: (bool, Vec<u32>),
+            ln__links: (bool, Vec<OsString>),
+            ln__target: (bool, Vec<PathBuf>),
+            ln__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 0 | 3) => return Err(p_.help(&Self::HELP_CP__)),
+                    (1, 2 | 5) => l_.cp__recursive.push(()),
+                    (2, 0 | 3) => return Err(p_.help(&Self::HELP_MV__)),
+                    (2, 1 | 4) => l_.mv__jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (3, 0 | 3) => return Err(p_.help(&Self::HELP_LN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"cp") => *state_ = 1,
+                    (0, b"mv") => *state_ = 2,
+                    (0, b"ln") => *state_ = 3,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        l_.cp__pending_.push(p_.defer_arg(arg_));
+                    }
+                    (2, _) => {
+                        if l_.mv__pending_.len() < 2 {
+                            l_.mv__pending_.push(p_.defer_arg(arg_));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (3, _) => {
+                        l_.ln__pending_.push(p_.defer_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.cp__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(1);
+            for arg_ in pending_.by_ref().take(extra_) {
+                let arg_ = p_.resume_arg(arg_);
+                l_.cp__sources.1.push(arg_.into());
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.cp__dest.1.push(arg_.into());
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.mv__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(1);
+            if let Some(arg_) = (extra_ > 0).then(|| pending_.next()).flatten() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.mv__source.1.push(arg_.into());
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.mv__dest.1.push(arg_.into());
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.ln__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(2);
+            if let Some(arg_) = (extra_ > 0).then(|| pending_.next()).flatten() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__mode.1.push(p_.value_from_str::<u32>("mode", arg_)?);
+            }
+            extra_ = extra_.saturating_sub(1);
+            for arg_ in pending_.by_ref().take(extra_ + 1) {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__links.1.push(arg_);
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__target.1.push(arg_.into());
+            }
+        }
+        if !l_.cp__recursive.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.cp__sources.1.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.cp__dest.1.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.mv__jobs.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.mv__source.1.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if !l_.mv__dest.1.is_empty() {
+            p_.set_source(5, xflags::ValueSource::CommandLine);
+        }
+        if !l_.ln__mode.1.is_empty() {
+            p_.set_source(6, xflags::ValueSource::CommandLine);
+        }
+        if !l_.ln__links.1.is_empty() {
+            p_.set_source(7, xflags::ValueSource::CommandLine);
+        }
+        if !l_.ln__target.1.is_empty() {
+            p_.set_source(8, xflags::ValueSource::CommandLine);
+        }
+        if matches!(*state_, | 1) && l_.cp__recursive.is_empty() {
+            let values_ = p_.fallback(0, &["cp"], "recursive");
+            for _ in 0..p_.switch_count("--recursive", values_, true)? {
+                l_.cp__recursive.push(());
+            }
+        }
+        if matches!(*state_, | 2) && l_.mv__jobs.is_empty() {
+            let values_ = p_.fallback(3, &["mv"], "jobs");
+            for value_ in values_ {
+                l_.mv__jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+            }
+        }
+        if matches!(*state_, | 1) {
+            p_.check_optional("--recursive", l_.cp__recursive.len())?;
+            p_.check_required("dest", l_.cp__dest.1.len())?;
+        }
+        if matches!(*state_, | 2) {
+            p_.check_optional("--jobs", l_.mv__jobs.len())?;
+            p_.check_optional("source", l_.mv__source.1.len())?;
+            p_.check_required("dest", l_.mv__dest.1.len())?;
+        }
+        if matches!(*state_, | 3) {
+            p_.check_optional("mode", l_.ln__mode.1.len())?;
+            p_.check_non_empty("links", l_.ln__links.1.len())?;
+            p_.check_required("target", l_.ln__target.1.len())?;
+        }
+        Ok(Copy {
+            subcommand: match *state_ {
+                1 => CopyCmd::Cp(Cp {
+                    recursive: !l_.cp__recursive.is_empty(),
+                    sources: std::mem::take(&mut l_.cp__sources.1),
+                    dest: l_.cp__dest.1.pop().unwrap(),
+                }),
+                2 => CopyCmd::Mv(Mv {
+                    jobs: l_.mv__jobs.pop(),
+                    source: l_.mv__source.1.pop(),
+                    dest: l_.mv__dest.1.pop().unwrap(),
+                }),
+                3 => CopyCmd::Ln(Ln {
+                    mode: l_.ln__mode.1.pop(),
+                    links: std::mem::take(&mut l_.ln__links.1),
+                    target: l_.ln__target.1.pop().unwrap(),
+                }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Copy {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            cp__recursive: Vec<()>,
+            cp__sources: (bool, Vec<PathBuf>),
+            cp__dest: (bool, Vec<PathBuf>),
+            cp__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+            mv__jobs: Vec<u32>,
+            mv__source: (bool, Vec<PathBuf>),
+            mv__dest: (bool, Vec<PathBuf>),
+            mv__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+            ln__mode: (bool, Vec<u32>),
+            ln__links: (bool, Vec<OsString>),
+            ln__target: (bool, Vec<PathBuf>),
+            ln__pending_: Vec<(Option<usize>, std::ffi::OsString)>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 0 | 3) => return Err(p_.help(&Self::HELP_CP__)),
+                    (1, 2 | 5) => l_.cp__recursive.push(()),
+                    (2, 0 | 3) => return Err(p_.help(&Self::HELP_MV__)),
+                    (2, 1 | 4) => l_.mv__jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (3, 0 | 3) => return Err(p_.help(&Self::HELP_LN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"cp") => *state_ = 1,
+                    (0, b"mv") => *state_ = 2,
+                    (0, b"ln") => *state_ = 3,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        l_.cp__pending_.push(p_.defer_arg(arg_));
+                    }
+                    (2, _) => {
+                        if l_.mv__pending_.len() < 2 {
+                            l_.mv__pending_.push(p_.defer_arg(arg_));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (3, _) => {
+                        l_.ln__pending_.push(p_.defer_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.cp__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(1);
+            for arg_ in pending_.by_ref().take(extra_) {
+                let arg_ = p_.resume_arg(arg_);
+                l_.cp__sources.1.push(arg_.into());
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.cp__dest.1.push(arg_.into());
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.mv__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(1);
+            if let Some(arg_) = (extra_ > 0).then(|| pending_.next()).flatten() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.mv__source.1.push(arg_.into());
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.mv__dest.1.push(arg_.into());
+            }
+        }
+        {
+            let mut pending_ = std::mem::take(&mut l_.ln__pending_).into_iter();
+            let mut extra_ = pending_.len().saturating_sub(2);
+            if let Some(arg_) = (extra_ > 0).then(|| pending_.next()).flatten() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__mode.1.push(p_.value_from_str::<u32>("mode", arg_)?);
+            }
+            extra_ = extra_.saturating_sub(1);
+            for arg_ in pending_.by_ref().take(extra_ + 1) {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__links.1.push(arg_);
+            }
+            if let Some(arg_) = pending_.next() {
+                let arg_ = p_.resume_arg(arg_);
+                l_.ln__target.1.push(arg_.into());
+            }
+        }
+        p_.check_optional("--recursive", l_.cp__recursive.len())?;
+        p_.check_optional("dest", l_.cp__dest.1.len())?;
+        p_.check_optional("--jobs", l_.mv__jobs.len())?;
+        p_.check_optional("source", l_.mv__source.1.len())?;
+        p_.check_optional("dest", l_.mv__dest.1.len())?;
+        p_.check_optional("mode", l_.ln__mode.1.len())?;
+        p_.check_optional("target", l_.ln__target.1.len())?;
+        match &mut self.subcommand {
+            CopyCmd::Cp(cmd_) => {
+                if !l_.cp__recursive.is_empty() {
+                    cmd_.recursive = true;
+                }
+                cmd_.sources.extend(std::mem::take(&mut l_.cp__sources.1));
+                if let Some(it) = l_.cp__dest.1.pop() {
+                    cmd_.dest = it;
+                }
+            }
+            CopyCmd::Mv(cmd_) => {
+                if let Some(it) = l_.mv__jobs.pop() {
+                    cmd_.jobs = Some(it);
+                }
+                if let Some(it) = l_.mv__source.1.pop() {
+                    cmd_.source = Some(it);
+                }
+                if let Some(it) = l_.mv__dest.1.pop() {
+                    cmd_.dest = it;
+                }
+            }
+            CopyCmd::Ln(cmd_) => {
+                if let Some(it) = l_.ln__mode.1.pop() {
+                    cmd_.mode = Some(it);
+                }
+                cmd_.links.extend(std::mem::take(&mut l_.ln__links.1));
+                if let Some(it) = l_.ln__target.1.pop() {
+                    cmd_.target = it;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            CopyCmd::Cp(_) => 1,
+            CopyCmd::Mv(_) => 2,
+            CopyCmd::Ln(_) => 3,
+        }
+    }
+}
+
+impl Copy {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["copy"];
+        match &self.subcommand {
+            CopyCmd::Cp(_) => {
+                res_.push("cp");
+            }
+            CopyCmd::Mv(_) => {
+                res_.push("mv");
+            }
+            CopyCmd::Ln(_) => {
+                res_.push("ln");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Copy {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Copy {
+    const USAGE_CP__: &'static str = "Usage: cp [sources]... <dest> [-r]";
+    const HELP_CP__: xflags::Help = xflags::Help::new(
+        Self::USAGE_CP__,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[("[sources]...", ""), ("<dest>", "")],
+            },
+            xflags::HelpSection { title: "Options", entries: &[("-r, --recursive", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_MV__: &'static str = "Usage: mv [source] <dest> [-n <n>]";
+    const HELP_MV__: xflags::Help = xflags::Help::new(
+        Self::USAGE_MV__,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[("[source]", ""), ("<dest>", "")],
+            },
+            xflags::HelpSection { title: "Options", entries: &[("-n, --jobs <n>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_LN__: &'static str = "Usage: ln [mode] <links>... <target>";
+    const HELP_LN__: xflags::Help = xflags::Help::new(
+        Self::USAGE_LN__,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[("[mode]", ""), ("<links>...", ""), ("<target>", "")],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: copy [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("cp", ""), ("mv", ""), ("ln", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_CP__,
+            2 => Self::USAGE_MV__,
+            3 => Self::USAGE_LN__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"copy\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"cp\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"sources\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null},{\"name\":\"dest\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"recursive\",\"short\":\"r\",\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[]},{\"name\":\"mv\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"source\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null},{\"name\":\"dest\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"jobs\",\"short\":\"n\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null}],\"subcommands\":[]},{\"name\":\"ln\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"mode\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"u32\",\"doc\":null},{\"name\":\"links\",\"arity\":\"repeated\",\"non_empty\":true,\"type\":\"OsString\",\"doc\":null},{\"name\":\"target\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[],\"subcommands\":[]}]}}";
+}
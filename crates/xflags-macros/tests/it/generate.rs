@@ -0,0 +1,430 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Tool {
+    pub verbose: u32,
+    pub subcommand: ToolCmd,
+}
+
+#[derive(Debug)]
+pub enum ToolCmd {
+    Build(Build),
+    Test(Test),
+}
+
+#[derive(Debug)]
+pub struct Build {
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct Test {
+    pub filter: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ToolField {
+    Verbose,
+    Build_Out,
+    Test_Filter,
+}
+
+impl From<ToolField> for usize {
+    fn from(field: ToolField) -> usize {
+        match field {
+            ToolField::Verbose => 0,
+            ToolField::Build_Out => 1,
+            ToolField::Test_Filter => 2,
+        }
+    }
+}
+
+impl Tool {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Build {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_BUILD__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_BUILD__
+    }
+}
+
+impl Test {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_TEST__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_TEST__
+    }
+}
+
+impl Tool {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Tool {
+    const FLAGS_: &'static [&'static str] = &["--help", "--out", "--verbose", "-h", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            build__out: Vec<PathBuf>,
+            test__filter: (bool, Vec<String>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 2 | 4) => l_.verbose.push(()),
+                    (1, 0 | 3) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 1) => l_.build__out.push(p_.next_value(&flag_)?.into()),
+                    (2, 0 | 3) => return Err(p_.help(&Self::HELP_TEST__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build" | b"b") => *state_ = 1,
+                    (0, b"test") => *state_ = 2,
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, _) => {
+                        if let (false, buf_) = &mut l_.test__filter {
+                            buf_.push(p_.value_from_str::<String>("filter", arg_)?);
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.build__out.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.test__filter.1.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(0, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if matches!(*state_, | 1) && l_.build__out.is_empty() {
+            let values_ = p_.fallback(1, &["build"], "out");
+            for value_ in values_ {
+                l_.build__out.push(value_.into());
+            }
+        }
+        if matches!(*state_, | 1) {
+            p_.check_optional("--out", l_.build__out.len())?;
+        }
+        Ok(Tool {
+            verbose: l_.verbose.len() as u32,
+            subcommand: match *state_ {
+                1 => ToolCmd::Build(Build { out: l_.build__out.pop() }),
+                2 => ToolCmd::Test(Test { filter: std::mem::take(&mut l_.test__filter.1) }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Tool {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            build__out: Vec<PathBuf>,
+            test__filter: (bool, Vec<String>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 3) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 2 | 4) => l_.verbose.push(()),
+                    (1, 0 | 3) => return Err(p_.help(&Self::HELP_BUILD__)),
+                    (1, 1) => l_.build__out.push(p_.next_value(&flag_)?.into()),
+                    (2, 0 | 3) => return Err(p_.help(&Self::HELP_TEST__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"build" | b"b") => *state_ = 1,
+                    (0, b"test") => *state_ = 2,
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    (2, _) => {
+                        if let (false, buf_) = &mut l_.test__filter {
+                            buf_.push(p_.value_from_str::<String>("filter", arg_)?);
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--out", l_.build__out.len())?;
+        self.verbose += l_.verbose.len() as u32;
+        match &mut self.subcommand {
+            ToolCmd::Build(cmd_) => {
+                if let Some(it) = l_.build__out.pop() {
+                    cmd_.out = Some(it);
+                }
+            }
+            ToolCmd::Test(cmd_) => {
+                cmd_.filter.extend(std::mem::take(&mut l_.test__filter.1));
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            ToolCmd::Build(_) => 1,
+            ToolCmd::Test(_) => 2,
+        }
+    }
+}
+
+impl Tool {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["tool"];
+        match &self.subcommand {
+            ToolCmd::Build(_) => {
+                res_.push("build");
+            }
+            ToolCmd::Test(_) => {
+                res_.push("test");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Tool {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Tool {
+    const USAGE_BUILD__: &'static str = "Usage: build [--out <dir>]";
+    const HELP_BUILD__: xflags::Help = xflags::Help::new(
+        Self::USAGE_BUILD__,
+        Some("Build the project."),
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--out <dir>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_TEST__: &'static str = "Usage: test [filter]...";
+    const HELP_TEST__: xflags::Help = xflags::Help::new(
+        Self::USAGE_TEST__,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[filter]...", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: tool [-v]... [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some("Builds things."),
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("-v, --verbose", ""), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[
+                    ("build", "Build the project."),
+                    ("test", ""),
+                    xflags::rt::GENERATE_ENTRY,
+                    xflags::rt::HELP_ENTRY,
+                ],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_BUILD__,
+            2 => Self::USAGE_TEST__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"tool\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"Builds things.\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"build\",\"aliases\":[\"b\"],\"deprecated_aliases\":[],\"doc\":\"Build the project.\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"out\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"dir\",\"type\":\"PathBuf\"},\"doc\":null}],\"subcommands\":[]},{\"name\":\"test\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"filter\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"String\",\"doc\":null}],\"flags\":[],\"subcommands\":[]}]}}";
+    const GENERATED_: &'static [(&'static str, &'static str)] = &[
+("bash", "_tool() {\n    local cur=\"${COMP_WORDS[COMP_CWORD]}\" state=\"tool\" i\n    for ((i = 1; i < COMP_CWORD; i++)); do\n        case \"$state,${COMP_WORDS[i]}\" in\n            \"tool,build\") state=\"tool__build\" ;;\n            \"tool,b\") state=\"tool__build\" ;;\n            \"tool,test\") state=\"tool__test\" ;;\n            \"tool,generate\") state=\"tool__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"tool\") COMPREPLY=($(compgen -W \"-v --verbose -h --help build b test help generate\" -- \"$cur\")) ;;\n        \"tool__build\") COMPREPLY=($(compgen -W \"--out help\" -- \"$cur\")) ;;\n        \"tool__test\") COMPREPLY=($(compgen -W \"help\" -- \"$cur\")) ;;\n        \"tool__generate_\") COMPREPLY=($(compgen -W \"bash zsh fish man\" -- \"$cur\")) ;;\n    esac\n}\ncomplete -o default -F _tool tool\n"),
+("zsh", "#compdef tool\n\n_tool() {\n    local state=\"tool\" i\n    for ((i = 2; i < CURRENT; i++)); do\n        case \"$state,${words[i]}\" in\n            \"tool,build\") state=\"tool__build\" ;;\n            \"tool,b\") state=\"tool__build\" ;;\n            \"tool,test\") state=\"tool__test\" ;;\n            \"tool,generate\") state=\"tool__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"tool\") compadd -- -v --verbose -h --help build b test help generate ;;\n        \"tool__build\") compadd -- --out help ;;\n        \"tool__test\") compadd -- help ;;\n        \"tool__generate_\") compadd -- bash zsh fish man; return ;;\n    esac\n    _files\n}\n\n_tool \"$@\"\n"),
+("fish", "complete -c tool -s v -l verbose\ncomplete -c tool -s h -l help -d 'Prints help'\ncomplete -c tool -f -n '__fish_use_subcommand' -a build -d 'Build the project.'\ncomplete -c tool -f -n '__fish_use_subcommand' -a b -d 'Build the project.'\ncomplete -c tool -f -n '__fish_use_subcommand' -a test\ncomplete -c tool -n '__fish_seen_subcommand_from build b' -l out -r\ncomplete -c tool -f -n '__fish_use_subcommand' -a generate -d 'Print shell completions or a man page'\ncomplete -c tool -f -n '__fish_seen_subcommand_from generate' -a 'bash zsh fish man'\n"),
+("man", ".TH TOOL 1\n.SH NAME\ntool \\- Builds things.\n.SH SYNOPSIS\n\\fBtool\\fR <COMMAND>\n.SH DESCRIPTION\nBuilds things.\n.SH OPTIONS\n.TP\n\\fB\\-v\\fR, \\fB\\-\\-verbose\\fR\n.TP\n\\fB\\-h\\fR, \\fB\\-\\-help\\fR\nPrints help\n.SH COMMANDS\n.TP\n\\fBbuild\\fR\nBuild the project.\n.TP\n\\fBtest\\fR\n.SH TOOL BUILD\n\\fBtool build\\fR\n.SS DESCRIPTION\nBuild the project.\n.SS OPTIONS\n.TP\n\\fB\\-\\-out\\fR \\fIdir\\fR\n.SH TOOL TEST\n\\fBtool test\\fR [filter]...\n.SS ARGUMENTS\n.TP\n\\fIfilter\\fR\n"),
+];
+}
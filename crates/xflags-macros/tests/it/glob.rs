@@ -0,0 +1,455 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Lint {
+    pub subcommand: LintCmd,
+}
+
+#[derive(Debug)]
+pub enum LintCmd {
+    All(All),
+    Native(Native),
+}
+
+#[derive(Debug)]
+pub struct All {
+    pub files: Vec<PathBuf>,
+
+    pub exclude: Vec<PathBuf>,
+    pub include: Vec<PathBuf>,
+    pub extra: Vec<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct Native {
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum LintField {
+    All_Exclude,
+    All_Include,
+    All_Extra,
+    All_Files,
+    Native_Files,
+}
+
+impl From<LintField> for usize {
+    fn from(field: LintField) -> usize {
+        match field {
+            LintField::All_Exclude => 0,
+            LintField::All_Include => 1,
+            LintField::All_Extra => 2,
+            LintField::All_Files => 3,
+            LintField::Native_Files => 4,
+        }
+    }
+}
+
+impl Lint {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<LintField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<LintField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl All {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Lint::HELP_ALL__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Lint::USAGE_ALL__
+    }
+}
+
+impl Native {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Lint::HELP_NATIVE__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Lint::USAGE_NATIVE__
+    }
+}
+
+impl Lint {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<LintField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<LintField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Lint {
+    const FLAGS_: &'static [&'static str] =
+        &["--exclude", "--extra", "--help", "--include", "-e", "-h", "-i"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            all__exclude: Vec<PathBuf>,
+            all__include: Vec<PathBuf>,
+            all__extra: Vec<PathBuf>,
+            all__files: (bool, Vec<PathBuf>),
+            native__files: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 2 | 5) => return Err(p_.help(&Self::HELP_ALL__)),
+                    (1, 0 | 4) => l_.all__exclude.push(p_.next_value(&flag_)?.into()),
+                    (1, 3 | 6) => l_.all__include.extend(p_.next_glob(&flag_, false, false)?),
+                    (1, 1) => l_.all__extra.extend(p_.next_glob(&flag_, false, true)?),
+                    (2, 2 | 5) => return Err(p_.help(&Self::HELP_NATIVE__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"all") => *state_ = 1,
+                    (0, b"native") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (false, buf_) = &mut l_.all__files {
+                            buf_.extend(p_.glob(arg_, false));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (2, _) => {
+                        if let (false, buf_) = &mut l_.native__files {
+                            buf_.extend(p_.glob(arg_, true));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.all__exclude.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.all__include.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.all__extra.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.all__files.1.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.native__files.1.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if matches!(*state_, | 1) && l_.all__exclude.is_empty() {
+            let values_ = p_.fallback(0, &["all"], "exclude");
+            for value_ in values_ {
+                l_.all__exclude.push(value_.into());
+            }
+        }
+        if matches!(*state_, | 1) && l_.all__include.is_empty() {
+            let values_ = p_.fallback(1, &["all"], "include");
+            for value_ in values_ {
+                l_.all__include.extend(p_.glob_value("--include", value_, false, false)?);
+            }
+        }
+        if matches!(*state_, | 1) && l_.all__extra.is_empty() {
+            let values_ = p_.fallback(2, &["all"], "extra");
+            for value_ in values_ {
+                l_.all__extra.extend(p_.glob_value("--extra", value_, false, true)?);
+            }
+        }
+        Ok(Lint {
+            subcommand: match *state_ {
+                1 => LintCmd::All(All {
+                    exclude: std::mem::take(&mut l_.all__exclude),
+                    include: std::mem::take(&mut l_.all__include),
+                    extra: std::mem::take(&mut l_.all__extra),
+                    files: std::mem::take(&mut l_.all__files.1),
+                }),
+                2 => LintCmd::Native(Native { files: std::mem::take(&mut l_.native__files.1) }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Lint {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            all__exclude: Vec<PathBuf>,
+            all__include: Vec<PathBuf>,
+            all__extra: Vec<PathBuf>,
+            all__files: (bool, Vec<PathBuf>),
+            native__files: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (1, 2 | 5) => return Err(p_.help(&Self::HELP_ALL__)),
+                    (1, 0 | 4) => l_.all__exclude.push(p_.next_value(&flag_)?.into()),
+                    (1, 3 | 6) => l_.all__include.extend(p_.next_glob(&flag_, false, false)?),
+                    (1, 1) => l_.all__extra.extend(p_.next_glob(&flag_, false, true)?),
+                    (2, 2 | 5) => return Err(p_.help(&Self::HELP_NATIVE__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"all") => *state_ = 1,
+                    (0, b"native") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (false, buf_) = &mut l_.all__files {
+                            buf_.extend(p_.glob(arg_, false));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (2, _) => {
+                        if let (false, buf_) = &mut l_.native__files {
+                            buf_.extend(p_.glob(arg_, true));
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        match &mut self.subcommand {
+            LintCmd::All(cmd_) => {
+                cmd_.exclude.extend(std::mem::take(&mut l_.all__exclude));
+                cmd_.include.extend(std::mem::take(&mut l_.all__include));
+                cmd_.extra.extend(std::mem::take(&mut l_.all__extra));
+                cmd_.files.extend(std::mem::take(&mut l_.all__files.1));
+            }
+            LintCmd::Native(cmd_) => {
+                cmd_.files.extend(std::mem::take(&mut l_.native__files.1));
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            LintCmd::All(_) => 1,
+            LintCmd::Native(_) => 2,
+        }
+    }
+}
+
+impl Lint {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["lint"];
+        match &self.subcommand {
+            LintCmd::All(_) => {
+                res_.push("all");
+            }
+            LintCmd::Native(_) => {
+                res_.push("native");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Lint {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Lint {
+    const USAGE_ALL__: &'static str =
+        "Usage: all [files]... [-e <path>]... [-i <pattern>]... [--extra <pattern>]...";
+    const HELP_ALL__: xflags::Help = xflags::Help::new(
+        Self::USAGE_ALL__,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[files]...", "")] },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-e, --exclude <path>", ""),
+                    ("-i, --include <pattern>", ""),
+                    ("--extra <pattern>", ""),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_NATIVE__: &'static str = "Usage: native [files]...";
+    const HELP_NATIVE__: xflags::Help = xflags::Help::new(
+        Self::USAGE_NATIVE__,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[files]...", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: lint [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("all", ""), ("native", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_ALL__,
+            2 => Self::USAGE_NATIVE__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"lint\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"all\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"files\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"exclude\",\"short\":\"e\",\"arity\":\"repeated\",\"value\":{\"name\":\"path\",\"type\":\"PathBuf\"},\"doc\":null},{\"name\":\"include\",\"short\":\"i\",\"arity\":\"repeated\",\"value\":{\"name\":\"pattern\",\"type\":\"PathBuf\"},\"doc\":null},{\"name\":\"extra\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"pattern\",\"type\":\"PathBuf\"},\"doc\":null}],\"subcommands\":[]},{\"name\":\"native\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"files\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[],\"subcommands\":[]}]}}";
+}
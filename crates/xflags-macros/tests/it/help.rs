@@ -20,6 +20,26 @@ pub struct Sub {
     pub flag: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum HelpfulField {
+    Switch,
+    Src,
+    Extra,
+    Sub_Flag,
+}
+
+impl From<HelpfulField> for usize {
+    fn from(field: HelpfulField) -> usize {
+        match field {
+            HelpfulField::Switch => 0,
+            HelpfulField::Src => 1,
+            HelpfulField::Extra => 2,
+            HelpfulField::Sub_Flag => 3,
+        }
+    }
+}
+
 impl Helpful {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -35,6 +55,84 @@ impl Helpful {
     pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         Self::from_vec_(args)
     }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<HelpfulField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<HelpfulField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Sub {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Helpful::HELP_SUB__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Helpful::USAGE_SUB__
+    }
 }
 
 impl Helpful {
@@ -42,94 +140,286 @@ impl Helpful {
         Self::from_env_().unwrap_or_else(|err| err.exit())
     }
     fn from_env_() -> xflags::Result<Self> {
-        let mut p = xflags::rt::Parser::new_from_env();
+        let mut p = xflags::rt::Parser::new_from_env()?;
         Self::parse_(&mut p)
     }
     fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         let mut p = xflags::rt::Parser::new(args);
         Self::parse_(&mut p)
     }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<HelpfulField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<HelpfulField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
 }
 
 impl Helpful {
+    const FLAGS_: &'static [&'static str] = &["--flag", "--help", "--switch", "-f", "-h", "-s"];
     fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
         #![allow(non_snake_case, unused_mut)]
-        let mut switch = Vec::new();
-        let mut src = (false, Vec::new());
-        let mut extra = (false, Vec::new());
-        let mut sub__flag = Vec::new();
+        #[derive(Default)]
+        struct Locals_ {
+            switch: Vec<()>,
+            src: (bool, Vec<PathBuf>),
+            extra: (bool, Vec<String>),
+            sub__flag: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
 
-        let mut state_ = 0u8;
         while let Some(arg_) = p_.pop_flag() {
             match arg_ {
-                Ok(flag_) => match (state_, flag_.as_str()) {
-                    (0, "--help" | "-h") => return Err(p_.help(Self::HELP_)),
-                    (0 | 1, "--switch" | "-s") => switch.push(()),
-                    (1, "--help" | "-h") => return Err(p_.help(Self::HELP_SUB__)),
-                    (1, "--flag" | "-f") => sub__flag.push(()),
-                    _ => return Err(p_.unexpected_flag(&flag_)),
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 2 | 5) => l_.switch.push(()),
+                    (1, 1 | 4) => return Err(p_.help(&Self::HELP_SUB__)),
+                    (1, 0 | 3) => l_.sub__flag.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
                 },
-                Err(arg_) => match (state_, arg_.to_str().unwrap_or("")) {
-                    (0, "sub") => state_ = 1,
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"sub") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
                     (0, _) => {
-                        if let (done_ @ false, buf_) = &mut src {
+                        if let (done_ @ false, buf_) = &mut l_.src {
                             buf_.push(arg_.into());
                             *done_ = true;
                             continue;
                         }
-                        if let (done_ @ false, buf_) = &mut extra {
+                        if let (done_ @ false, buf_) = &mut l_.extra {
                             buf_.push(p_.value_from_str::<String>("extra", arg_)?);
                             *done_ = true;
                             continue;
                         }
                         return Err(p_.unexpected_arg(arg_));
                     }
-                    (1, "help") => return Err(p_.help(Self::HELP_SUB__)),
+                    (1, b"help") => p_.defer_help(),
                     _ => return Err(p_.unexpected_arg(arg_)),
                 },
             }
         }
+        if !l_.switch.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.src.1.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.extra.1.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.sub__flag.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if l_.switch.is_empty() {
+            let values_ = p_.fallback(0, &[], "switch");
+            for _ in 0..p_.switch_count("--switch", values_, true)? {
+                l_.switch.push(());
+            }
+        }
+        if matches!(*state_, | 1) && l_.sub__flag.is_empty() {
+            let values_ = p_.fallback(3, &["sub"], "flag");
+            for _ in 0..p_.switch_count("--flag", values_, true)? {
+                l_.sub__flag.push(());
+            }
+        }
+        p_.check_required("--switch", l_.switch.len())?;
+        p_.check_optional("src", l_.src.1.len())?;
+        p_.check_optional("extra", l_.extra.1.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_optional("--flag", l_.sub__flag.len())?;
+        }
         Ok(Helpful {
-            switch: p_.required("--switch", switch)?,
-            src: p_.optional("src", src.1)?,
-            extra: p_.optional("extra", extra.1)?,
-            subcommand: match state_ {
-                1 => HelpfulCmd::Sub(Sub { flag: p_.optional("--flag", sub__flag)?.is_some() }),
+            switch: (),
+            src: l_.src.1.pop(),
+            extra: l_.extra.1.pop(),
+            subcommand: match *state_ {
+                1 => HelpfulCmd::Sub(Sub { flag: !l_.sub__flag.is_empty() }),
                 _ => return Err(p_.subcommand_required()),
             },
         })
     }
 }
+
 impl Helpful {
-    const HELP_SUB__: &'static str = "Usage: sub [-f]
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
 
-And even a subcommand!
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            switch: Vec<()>,
+            src: (bool, Vec<PathBuf>),
+            extra: (bool, Vec<String>),
+            sub__flag: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
 
-Options:
-  -f, --flag           With an optional flag. This has a really long
-description which spans multiple lines.
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 2 | 5) => l_.switch.push(()),
+                    (1, 1 | 4) => return Err(p_.help(&Self::HELP_SUB__)),
+                    (1, 0 | 3) => l_.sub__flag.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"sub") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.src {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        if let (done_ @ false, buf_) = &mut l_.extra {
+                            buf_.push(p_.value_from_str::<String>("extra", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--switch", l_.switch.len())?;
+        p_.check_optional("src", l_.src.1.len())?;
+        p_.check_optional("extra", l_.extra.1.len())?;
+        p_.check_optional("--flag", l_.sub__flag.len())?;
+        if let Some(it) = l_.src.1.pop() {
+            self.src = Some(it);
+        }
+        if let Some(it) = l_.extra.1.pop() {
+            self.extra = Some(it);
+        }
+        match &mut self.subcommand {
+            HelpfulCmd::Sub(cmd_) => {
+                if !l_.sub__flag.is_empty() {
+                    cmd_.flag = true;
+                }
+            }
+        }
+        Ok(())
+    }
 
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_: &'static str = "Usage: helpful [src] [extra] -s [-h] <COMMAND>
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            HelpfulCmd::Sub(_) => 1,
+        }
+    }
+}
 
-Does stuff
+impl Helpful {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["helpful"];
+        match &self.subcommand {
+            HelpfulCmd::Sub(_) => {
+                res_.push("sub");
+            }
+        }
+        res_
+    }
+}
 
-Helpful stuff.
+impl xflags::rt::Mount for Helpful {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Helpful {
+    const USAGE_SUB__: &'static str = "Usage: sub [-f]";
+    const HELP_SUB__: xflags::Help = xflags::Help::new(
+        Self::USAGE_SUB__,
+        Some("And even a subcommand!"),
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[(
+                    "-f, --flag",
+                    "With an optional flag. This has a really long
+description which spans multiple lines.",
+                )],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: helpful [src] [extra] -s [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some(
+            "Does stuff
 
-Arguments:
-  [src]                With an arg.
-  [extra]              Another arg.
+Helpful stuff.",
+        ),
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[
+                    ("[src]", "With an arg."),
+                    (
+                        "[extra]",
+                        "Another arg.
 
 This time, we provide some extra info about the
 arg. Maybe some caveats, or what kinds of
-values are accepted.
-
-Options:
-  -s, --switch         And a switch.
-  -h, --help           Prints help
-
-Commands:
-  sub                  And even a subcommand!
-  help                 Print this message or the help of the given subcommand(s)";
+values are accepted.",
+                    ),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("-s, --switch", "And a switch."), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("sub", "And even a subcommand!"), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_SUB__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"helpful\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"Does stuff\\n\\nHelpful stuff.\",\"default\":false,\"args\":[{\"name\":\"src\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":\"With an arg.\"},{\"name\":\"extra\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"String\",\"doc\":\"Another arg.\\n\\nThis time, we provide some extra info about the\\narg. Maybe some caveats, or what kinds of\\nvalues are accepted.\"}],\"flags\":[{\"name\":\"switch\",\"short\":\"s\",\"arity\":\"required\",\"value\":null,\"doc\":\"And a switch.\"},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"sub\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"And even a subcommand!\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"flag\",\"short\":\"f\",\"arity\":\"optional\",\"value\":null,\"doc\":\"With an optional flag. This has a really long\\ndescription which spans multiple lines.\"}],\"subcommands\":[]}]}}";
 }
@@ -1,8 +1,39 @@
+#![allow(dead_code)]
+
 mod empty;
 mod smoke;
 mod repeated_pos;
 mod subcommands;
 mod help;
+mod validate;
+mod config;
+mod env;
+mod verbosity;
+mod generate;
+mod mount;
+mod prompt;
+mod glob;
+mod dotenv;
+mod duration;
+mod checked_paths;
+mod bool_switch;
+mod counted;
+mod settings;
+mod strip_help;
+mod unicode;
+mod short_symbols;
+mod non_empty_pos;
+mod flexible_pos;
+mod deprecated;
+mod epilogue;
+mod color;
+mod escapes;
+mod field_names;
+mod strict_ordering;
+mod env_verbosity;
+mod mount_env;
+mod os_str;
+mod color_assign;
 
 use std::{ffi::OsString, fmt};
 
@@ -74,42 +105,22 @@ fn smoke() {
         "#]],
     );
 
-    check(
-        smoke::RustAnalyzer::from_vec,
-        "-n 92 --werbose",
-        expect!["Unknown flag: `--werbose`. Use `help` for more information"],
-    );
-    check(
-        smoke::RustAnalyzer::from_vec,
-        "",
-        expect!["Flag is required: `--number`. Use `help` for more information"],
-    );
-    check(
-        smoke::RustAnalyzer::from_vec,
-        ".",
-        expect!["Flag is required: `--number`. Use `help` for more information"],
-    );
+    check(smoke::RustAnalyzer::from_vec, "-n 92 --werbose", expect!["Unknown flag: `--werbose`"]);
+    check(smoke::RustAnalyzer::from_vec, "", expect!["Flag is required: `--number`"]);
+    check(smoke::RustAnalyzer::from_vec, ".", expect!["Flag is required: `--number`"]);
     check(smoke::RustAnalyzer::from_vec, "-n", expect![[r#"expected a value for `-n`"#]]);
-    check(
-        smoke::RustAnalyzer::from_vec,
-        "-n 92",
-        expect!["Flag is required: `workspace`. Use `help` for more information"],
-    );
+    check(smoke::RustAnalyzer::from_vec, "-n 92", expect!["Flag is required: `workspace`"]);
     check(
         smoke::RustAnalyzer::from_vec,
         "-n lol",
-        expect!["Can't parse `-n`, invalid digit found in string"],
+        expect!["Invalid value `lol` for `-n`: invalid digit found in string"],
     );
     check(
         smoke::RustAnalyzer::from_vec,
         "-n 1 -n 2 .",
         expect!["Flag specified more than once: `--number`"],
     );
-    check(
-        smoke::RustAnalyzer::from_vec,
-        "-n 1 . 92 lol",
-        expect!["Unknown command: `lol`. Use `help` for more information"],
-    );
+    check(smoke::RustAnalyzer::from_vec, "-n 1 . 92 lol", expect!["Unknown command: `lol`"]);
     check(
         smoke::RustAnalyzer::from_vec,
         "-n 1 . --emoji --emoji",
@@ -219,11 +230,7 @@ fn subcommands() {
         "#]],
     );
 
-    check(
-        subcommands::RustAnalyzer::from_vec,
-        "",
-        expect!["A subcommand is required. Use `help` for more information"],
-    );
+    check(subcommands::RustAnalyzer::from_vec, "", expect!["A subcommand is required"]);
 }
 
 #[test]
@@ -250,13 +257,9 @@ fn subcommand_flag_inheritance() {
     check(
         subcommands::RustAnalyzer::from_vec,
         "analysis-stats --verbose --dir .",
-        expect!["Unknown flag: `--dir`. Use `help` for more information"],
-    );
-    check(
-        subcommands::RustAnalyzer::from_vec,
-        "--dir . server",
-        expect!["Unknown flag: `--dir`. Use `help` for more information"],
+        expect!["Unknown flag: `--dir`"],
     );
+    check(subcommands::RustAnalyzer::from_vec, "--dir . server", expect!["Unknown flag: `--dir`"]);
 }
 
 #[test]
@@ -303,16 +306,8 @@ fn edge_cases() {
             }
         "#]],
     );
-    check(
-        subcommands::RustAnalyzer::from_vec,
-        "-- -v server",
-        expect!["Unknown command: `-v`. Use `help` for more information"],
-    );
-    check(
-        repeated_pos::RepeatedPos::from_vec,
-        "pos 1 prog -j",
-        expect!["Unknown flag: `-j`. Use `help` for more information"],
-    );
+    check(subcommands::RustAnalyzer::from_vec, "-- -v server", expect!["Unknown command: `-v`"]);
+    check(repeated_pos::RepeatedPos::from_vec, "pos 1 prog -j", expect!["Unknown flag: `-j`"]);
     check(
         repeated_pos::RepeatedPos::from_vec,
         "pos 1 -- prog -j",
@@ -332,3 +327,1691 @@ fn edge_cases() {
         "#]],
     );
 }
+
+#[test]
+fn usage_in_errors() {
+    let usage = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+        let err = subcommands::RustAnalyzer::from_vec(args).unwrap_err();
+        err.usage().unwrap_or_default()
+    };
+
+    expect!["Usage: rust-analyzer [-v]... [-h] <COMMAND>"].assert_eq(usage(""));
+    expect!["Usage: launch [--log]"].assert_eq(usage("server --bogus"));
+    expect!["Usage: analysis-stats <path> [--parallel]"].assert_eq(usage("analysis-stats"));
+    expect![""].assert_eq(usage("--help"));
+}
+
+fn no_quiet_verbose(flags: &validate::Validated) -> xflags::Result<()> {
+    if flags.quiet && flags.verbose > 0 {
+        return Err(xflags::Error::new("`-q` and `-v` can't be specified at the same time"));
+    }
+    Ok(())
+}
+
+#[test]
+fn validation() {
+    check(
+        validate::Validated::from_vec,
+        "-v -v",
+        expect![[r#"
+            Validated {
+                verbose: 2,
+                quiet: false,
+            }
+        "#]],
+    );
+    check(
+        validate::Validated::from_vec,
+        "-q -v",
+        expect!["`-q` and `-v` can't be specified at the same time"],
+    );
+
+    let mut flags = validate::Validated::from_vec(vec!["-v".into()]).unwrap();
+    let err = flags.apply(vec!["-v".into(), "-q".into()]).unwrap_err();
+    expect!["`-q` and `-v` can't be specified at the same time"].assert_eq(&err.to_string());
+    expect![[r#"
+        Validated {
+            verbose: 1,
+            quiet: false,
+        }
+    "#]]
+    .assert_debug_eq(&flags);
+}
+
+#[test]
+fn help_outcome() {
+    let outcome = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+        match smoke::RustAnalyzer::from_vec_outcome(args) {
+            Ok(xflags::ParseOutcome::Flags(flags)) => format!("flags: {}", flags.number),
+            Ok(xflags::ParseOutcome::Help(help)) => help.lines().next().unwrap().to_string(),
+            Ok(_) => unreachable!(),
+            Err(err) => format!("error: {err}"),
+        }
+    };
+
+    expect!["flags: 92"].assert_eq(&outcome("-n 92 ."));
+    expect![
+        "Usage: rust-analyzer <workspace> [jobs] [--log-file <path>] [-v]... -n <n> [--data <value>]... [--emoji] [-h]"
+    ]
+    .assert_eq(&outcome("-n 92 --help"));
+    expect!["error: Flag is required: `--number`"].assert_eq(&outcome("."));
+}
+
+#[test]
+fn help_accessors() {
+    expect!["Usage: rust-analyzer [-v]... [-h] <COMMAND>"]
+        .assert_eq(subcommands::RustAnalyzer::usage());
+    expect!["Usage: server [--dir <path>] [--log] <COMMAND>"]
+        .assert_eq(subcommands::Server::usage());
+    expect![[r#"
+        Usage: launch [--log]
+        Options:
+          --log                
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]]
+    .assert_eq(&subcommands::Launch::help_message().to_string());
+}
+
+#[test]
+fn value_error_source() {
+    use std::error::Error;
+
+    let args = ["-n", "lol", "."].map(OsString::from).to_vec();
+    let err = smoke::RustAnalyzer::from_vec(args).unwrap_err();
+    let source = err.source().unwrap();
+    assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+    expect!["invalid digit found in string"].assert_eq(&source.to_string());
+}
+
+#[test]
+fn error_arg() {
+    let arg = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+        let err = smoke::RustAnalyzer::from_vec(args).unwrap_err();
+        format!("{:?} {:?}", err.arg_index(), err.arg())
+    };
+
+    expect![[r#"Some(2) Some("--werbose")"#]].assert_eq(&arg("-n 92 --werbose"));
+    expect![[r#"Some(1) Some("lol")"#]].assert_eq(&arg("-n lol"));
+    expect![[r#"Some(4) Some("lol")"#]].assert_eq(&arg("-n 1 . 92 lol"));
+    expect![[r#"Some(0) Some("-n")"#]].assert_eq(&arg("-n"));
+    expect![[r#"Some(2) Some("lol")"#]].assert_eq(&arg("help -n lol"));
+    expect!["None None"].assert_eq(&arg("."));
+}
+
+#[test]
+#[cfg(unix)]
+fn non_utf8_values() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let value = std::ffi::OsStr::from_bytes(b"caf\xe9").to_os_string();
+    let args = vec!["-n".into(), "1".into(), "--log-file".into(), value.clone(), value.clone()];
+    let flags = smoke::RustAnalyzer::from_vec(args).unwrap();
+    assert_eq!(flags.workspace.as_os_str(), value);
+    assert_eq!(flags.log_file.unwrap().as_os_str(), value);
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_errors() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let args = |args: &[&[u8]]| {
+        args.iter().map(|it| std::ffi::OsStr::from_bytes(it).to_os_string()).collect::<Vec<_>>()
+    };
+    let err = |args| subcommands::RustAnalyzer::from_vec(args).unwrap_err().to_string();
+
+    expect!["Unknown command: `caf\u{FFFD}` (not valid UTF-8)"]
+        .assert_eq(&err(args(&[b"caf\xe9"])));
+    expect!["Unknown flag: `--caf\u{FFFD}` (not valid UTF-8)"]
+        .assert_eq(&err(args(&[b"--caf\xe9"])));
+    let err = smoke::RustAnalyzer::from_vec(args(&[b".", b"-n", b"caf\xe9"])).unwrap_err();
+    expect!["Invalid value `caf\u{FFFD}` for `-n`: not valid UTF-8"].assert_eq(&err.to_string());
+}
+
+#[test]
+fn value_sources() {
+    use subcommands::RustAnalyzerField as Field;
+
+    let args = ["server", "--dir", "."].map(OsString::from).to_vec();
+    let (_, sources) = subcommands::RustAnalyzer::from_vec_with_sources(args).unwrap();
+    assert_eq!(sources.get(Field::Server_Dir), xflags::ValueSource::CommandLine);
+    assert_eq!(sources.get(Field::Server_Launch_Log), xflags::ValueSource::Default);
+    assert!(!sources.is_explicit(Field::Verbose));
+}
+
+#[test]
+fn subcommand_path() {
+    let path = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+        subcommands::RustAnalyzer::from_vec(args).unwrap().subcommand_path().join(" ")
+    };
+
+    expect!["rust-analyzer server launch"].assert_eq(&path("server"));
+    expect!["rust-analyzer server watch"].assert_eq(&path("server watch"));
+    expect!["rust-analyzer analysis-stats"].assert_eq(&path("analysis-stats ."));
+    expect!["empty"]
+        .assert_eq(&empty::Empty::from_vec(Vec::new()).unwrap().subcommand_path().join(" "));
+}
+
+#[test]
+fn from_slice() {
+    let flags = smoke::RustAnalyzer::from_slice(&["-n", "92", "--emoji", "."]).unwrap();
+    expect![[r#"
+        RustAnalyzer {
+            workspace: ".",
+            jobs: None,
+            log_file: None,
+            verbose: 0,
+            number: 92,
+            data: [],
+            emoji: true,
+        }
+    "#]]
+    .assert_debug_eq(&flags);
+}
+
+#[test]
+fn apply() {
+    let args = |args: &str| args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+
+    let mut flags = subcommands::RustAnalyzer::from_vec(args("server --dir a")).unwrap();
+    flags.apply(args("--log -v")).unwrap();
+    flags.apply(args("--dir b -v")).unwrap();
+    expect![[r#"
+        RustAnalyzer {
+            verbose: 2,
+            subcommand: Server(
+                Server {
+                    dir: Some(
+                        "b",
+                    ),
+                    subcommand: Launch(
+                        Launch {
+                            log: true,
+                        },
+                    ),
+                },
+            ),
+        }
+    "#]]
+    .assert_debug_eq(&flags);
+
+    let mut flags = smoke::RustAnalyzer::from_vec(args("-n 1 --data a .")).unwrap();
+    flags.apply(args("-n 2 --data b src")).unwrap();
+    expect![[r#"
+        RustAnalyzer {
+            workspace: "src",
+            jobs: None,
+            log_file: None,
+            verbose: 0,
+            number: 2,
+            data: [
+                "a",
+                "b",
+            ],
+            emoji: false,
+        }
+    "#]]
+    .assert_debug_eq(&flags);
+
+    let err = flags.apply(args("-n 3 -n 4")).unwrap_err();
+    expect!["Flag specified more than once: `--number`"].assert_eq(&err.to_string());
+    assert_eq!(flags.number, 2);
+}
+
+#[test]
+fn config_file() {
+    check(
+        config::Configured::from_vec,
+        "build --feature c",
+        expect![[r#"
+            Configured {
+                jobs: Some(
+                    4,
+                ),
+                verbose: 2,
+                color: false,
+                subcommand: Build(
+                    Build {
+                        feature: [
+                            "c",
+                        ],
+                        target: "x86_64-unknown-linux-gnu",
+                    },
+                ),
+            }
+        "#]],
+    );
+    check(
+        config::Configured::from_vec,
+        "-j 8 test",
+        expect![[r#"
+            Configured {
+                jobs: Some(
+                    8,
+                ),
+                verbose: 2,
+                color: false,
+                subcommand: Test(
+                    Test {
+                        target: None,
+                    },
+                ),
+            }
+        "#]],
+    );
+
+    let args = ["build", "-j", "1"].map(OsString::from).to_vec();
+    let (_, sources) = config::Configured::from_vec_with_sources(args).unwrap();
+    assert_eq!(sources.get(config::ConfiguredField::Jobs), xflags::ValueSource::CommandLine);
+    assert_eq!(sources.get(config::ConfiguredField::Verbose), xflags::ValueSource::Config);
+    assert_eq!(sources.get(config::ConfiguredField::Color), xflags::ValueSource::Default);
+}
+
+#[test]
+fn env_prefix() {
+    let vars = &[
+        ("XFLAGS_TEST_JOBS", "8"),
+        ("XFLAGS_TEST_VERBOSE", "1"),
+        ("XFLAGS_TEST_LOG_FILE", ""),
+        ("XFLAGS_TEST_BUILD_FEATURE", "a"),
+    ];
+    xflags::testing::with_env(vars, || {
+        check(
+            env::Tool::from_vec,
+            "build",
+            expect![[r#"
+                Tool {
+                    jobs: Some(
+                        8,
+                    ),
+                    verbose: 1,
+                    log_file: None,
+                    subcommand: Build(
+                        Build {
+                            feature: [
+                                "a",
+                            ],
+                        },
+                    ),
+                }
+            "#]],
+        );
+        check(
+            env::Tool::from_vec,
+            "-j 2 -v -v build --feature b",
+            expect![[r#"
+                Tool {
+                    jobs: Some(
+                        2,
+                    ),
+                    verbose: 2,
+                    log_file: None,
+                    subcommand: Build(
+                        Build {
+                            feature: [
+                                "b",
+                            ],
+                        },
+                    ),
+                }
+            "#]],
+        );
+
+        let (_, sources) = env::Tool::from_vec_with_sources(vec!["build".into()]).unwrap();
+        assert_eq!(sources.get(env::ToolField::Jobs), xflags::ValueSource::Env);
+        assert_eq!(sources.get(env::ToolField::LogFile), xflags::ValueSource::Default);
+    });
+}
+
+#[test]
+fn env_switches() {
+    xflags::testing::with_env(&[("MYTOOL_QUIET", "true")], || {
+        check(
+            env_verbosity::Mytool::from_vec,
+            "",
+            expect![[r#"
+                Mytool {
+                    verbosity: -1,
+                }
+            "#]],
+        );
+        check(
+            env_verbosity::Mytool::from_vec,
+            "-v",
+            expect![[r#"
+                Mytool {
+                    verbosity: 1,
+                }
+            "#]],
+        );
+    });
+    xflags::testing::with_env(&[("MYTOOL_QUIET", "2")], || {
+        check(
+            env_verbosity::Mytool::from_vec,
+            "",
+            expect![
+                "Invalid value `2` for `--quiet` from `MYTOOL_QUIET`: can be specified only once"
+            ],
+        );
+    });
+    xflags::testing::with_env(&[("MYTOOL_VERBOSE", "maybe")], || {
+        check(
+            env_verbosity::Mytool::from_vec,
+            "",
+            expect![[
+                r#"Invalid value `maybe` for `--verbose` from `MYTOOL_VERBOSE`: expected `true`, `false`, or a count"#
+            ]],
+        );
+    });
+}
+
+#[test]
+fn dotenv_file() {
+    let vars = &[("XFLAGS_DOTENV_PORT", "9000")];
+    xflags::testing::with_env(vars, || {
+        check(
+            dotenv::Server::from_vec,
+            "",
+            expect![[r#"
+                Server {
+                    host: Some(
+                        "local host",
+                    ),
+                    port: Some(
+                        9000,
+                    ),
+                    verbose: 2,
+                }
+            "#]],
+        );
+        check(
+            dotenv::Server::from_vec,
+            "--host example.com",
+            expect![[r#"
+                Server {
+                    host: Some(
+                        "example.com",
+                    ),
+                    port: Some(
+                        9000,
+                    ),
+                    verbose: 2,
+                }
+            "#]],
+        );
+
+        let (_, sources) = dotenv::Server::from_vec_with_sources(vec![]).unwrap();
+        assert_eq!(sources.get(dotenv::ServerField::Host), xflags::ValueSource::Env);
+    });
+}
+
+#[test]
+fn spec_json() {
+    expect![[r#"{"version":1,"command":{"name":"rust-analyzer","aliases":[],"deprecated_aliases":[],"doc":null,"default":false,"args":[],"flags":[{"name":"verbose","short":"v","arity":"repeated","value":null,"doc":null},{"name":"help","short":"h","arity":"optional","value":null,"doc":"Prints help"}],"subcommands":[{"name":"server","aliases":[],"deprecated_aliases":[],"doc":null,"default":false,"args":[],"flags":[{"name":"dir","short":null,"arity":"optional","value":{"name":"path","type":"PathBuf"},"doc":null}],"subcommands":[{"name":"launch","aliases":[],"deprecated_aliases":[],"doc":null,"default":true,"args":[],"flags":[{"name":"log","short":null,"arity":"optional","value":null,"doc":null}],"subcommands":[]},{"name":"watch","aliases":[],"deprecated_aliases":[],"doc":null,"default":false,"args":[],"flags":[],"subcommands":[]}]},{"name":"analysis-stats","aliases":[],"deprecated_aliases":[],"doc":null,"default":false,"args":[{"name":"path","arity":"required","non_empty":false,"type":"PathBuf","doc":null}],"flags":[{"name":"parallel","short":null,"arity":"optional","value":null,"doc":null}],"subcommands":[]}]}}"#]].assert_eq(subcommands::RustAnalyzer::spec_json());
+}
+
+#[test]
+fn verbosity() {
+    check(
+        verbosity::Tool::from_vec,
+        "-v -v run",
+        expect![[r#"
+            Tool {
+                verbosity: 2,
+                subcommand: Run(
+                    Run {
+                        dry_run: false,
+                    },
+                ),
+            }
+        "#]],
+    );
+
+    let log_level = |args| verbosity::Tool::from_slice(args).unwrap().log_level();
+    assert_eq!(log_level(&["run"]), xflags::LogLevel::Warn);
+    assert_eq!(log_level(&["-v", "-v", "run"]), xflags::LogLevel::Debug);
+    assert_eq!(log_level(&["run", "-q"]), xflags::LogLevel::Off);
+    let verbosity = |args| verbosity::Tool::from_slice(args).unwrap().verbosity;
+    assert_eq!(verbosity(&["run"]), 0);
+    assert_eq!(verbosity(&["-v", "-v", "run"]), 2);
+    assert_eq!(verbosity(&["run", "-q"]), -1);
+    let mut flags = verbosity::Tool::from_slice(&["-q", "run"]).unwrap();
+    let err = flags.apply(vec!["-v".into()]).unwrap_err();
+    expect!["`--verbose` can't be used together with `--quiet`"].assert_eq(&err.to_string());
+    assert_eq!(flags.verbosity, -1);
+    flags.apply(vec!["-q".into()]).unwrap();
+    assert_eq!(flags.verbosity, -1);
+    let mut flags = verbosity::Tool::from_slice(&["-v", "run"]).unwrap();
+    flags.apply(vec!["-v".into()]).unwrap();
+    assert_eq!(flags.verbosity, 2);
+    assert!(flags.apply(vec!["-q".into()]).is_err());
+    assert_eq!(flags.verbosity, 2);
+    check(
+        verbosity::Tool::from_vec,
+        "-v run -q",
+        expect!["`--verbose` can't be used together with `--quiet`"],
+    );
+    expect!["Usage: tool [-v]... [-q] [-h] <COMMAND>"].assert_eq(verbosity::Tool::usage());
+}
+
+#[test]
+fn generate() {
+    check(
+        generate::Tool::from_vec,
+        "generate bash",
+        expect![[r#"
+        _tool() {
+            local cur="${COMP_WORDS[COMP_CWORD]}" state="tool" i
+            for ((i = 1; i < COMP_CWORD; i++)); do
+                case "$state,${COMP_WORDS[i]}" in
+                    "tool,build") state="tool__build" ;;
+                    "tool,b") state="tool__build" ;;
+                    "tool,test") state="tool__test" ;;
+                    "tool,generate") state="tool__generate_" ;;
+                esac
+            done
+            case "$state" in
+                "tool") COMPREPLY=($(compgen -W "-v --verbose -h --help build b test help generate" -- "$cur")) ;;
+                "tool__build") COMPREPLY=($(compgen -W "--out help" -- "$cur")) ;;
+                "tool__test") COMPREPLY=($(compgen -W "help" -- "$cur")) ;;
+                "tool__generate_") COMPREPLY=($(compgen -W "bash zsh fish man" -- "$cur")) ;;
+            esac
+        }
+        complete -o default -F _tool tool
+    "#]],
+    );
+    check(
+        generate::Tool::from_vec,
+        "generate fish",
+        expect![[r#"
+        complete -c tool -s v -l verbose
+        complete -c tool -s h -l help -d 'Prints help'
+        complete -c tool -f -n '__fish_use_subcommand' -a build -d 'Build the project.'
+        complete -c tool -f -n '__fish_use_subcommand' -a b -d 'Build the project.'
+        complete -c tool -f -n '__fish_use_subcommand' -a test
+        complete -c tool -n '__fish_seen_subcommand_from build b' -l out -r
+        complete -c tool -f -n '__fish_use_subcommand' -a generate -d 'Print shell completions or a man page'
+        complete -c tool -f -n '__fish_seen_subcommand_from generate' -a 'bash zsh fish man'
+    "#]],
+    );
+    check(
+        generate::Tool::from_vec,
+        "generate man",
+        expect![[r#"
+        .TH TOOL 1
+        .SH NAME
+        tool \- Builds things.
+        .SH SYNOPSIS
+        \fBtool\fR <COMMAND>
+        .SH DESCRIPTION
+        Builds things.
+        .SH OPTIONS
+        .TP
+        \fB\-v\fR, \fB\-\-verbose\fR
+        .TP
+        \fB\-h\fR, \fB\-\-help\fR
+        Prints help
+        .SH COMMANDS
+        .TP
+        \fBbuild\fR
+        Build the project.
+        .TP
+        \fBtest\fR
+        .SH TOOL BUILD
+        \fBtool build\fR
+        .SS DESCRIPTION
+        Build the project.
+        .SS OPTIONS
+        .TP
+        \fB\-\-out\fR \fIdir\fR
+        .SH TOOL TEST
+        \fBtool test\fR [filter]...
+        .SS ARGUMENTS
+        .TP
+        \fIfilter\fR
+    "#]],
+    );
+    check(
+        generate::Tool::from_vec,
+        "generate elvish",
+        expect!["Unknown generator `elvish`, expected one of: bash, zsh, fish, man"],
+    );
+
+    let err = generate::Tool::from_vec(vec!["generate".into(), "zsh".into()]).unwrap_err();
+    assert!(err.is_help());
+    assert!(err.to_string().starts_with("#compdef tool\n"));
+}
+
+#[test]
+fn mount() {
+    check(
+        mount::App::from_vec,
+        "--dry-run rust-analyzer -v analysis-stats . --parallel",
+        expect![[r#"
+            App {
+                dry_run: true,
+                subcommand: RustAnalyzer(
+                    RustAnalyzer {
+                        verbose: 1,
+                        subcommand: AnalysisStats(
+                            AnalysisStats {
+                                path: ".",
+                                parallel: true,
+                            },
+                        ),
+                    },
+                ),
+            }
+        "#]],
+    );
+    check(mount::App::from_vec, "rust-analyzer --dry-run", expect!["Unknown flag: `--dry-run`"]);
+    check(
+        mount::App::from_vec,
+        "smoke -n 1 .",
+        expect![[r#"
+        App {
+            dry_run: false,
+            subcommand: Smoke(
+                RustAnalyzer {
+                    workspace: ".",
+                    jobs: None,
+                    log_file: None,
+                    verbose: 0,
+                    number: 1,
+                    data: [],
+                    emoji: false,
+                },
+            ),
+        }
+    "#]],
+    );
+    check(
+        mount::App::from_vec,
+        "rust-analyzer --help",
+        expect![[r#"
+        Usage: rust-analyzer [-v]... [-h] <COMMAND>
+        Options:
+          -v, --verbose        
+          -h, --help           Prints help
+
+        Commands:
+          server               
+          analysis-stats       
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+
+    let flags = mount::App::from_slice(&["rust-analyzer", "server", "watch"]).unwrap();
+    assert_eq!(flags.subcommand_path(), ["app", "rust-analyzer", "server", "watch"]);
+
+    let vars =
+        &[("XFLAGS_MOUNT_RA_VERBOSE", "2"), ("XFLAGS_MOUNT_RA_ANALYSIS_STATS_PARALLEL", "true")];
+    xflags::testing::with_env(vars, || {
+        check(
+            mount_env::App::from_vec,
+            "ra analysis-stats .",
+            expect![[r#"
+                App {
+                    subcommand: Ra(
+                        RustAnalyzer {
+                            verbose: 2,
+                            subcommand: AnalysisStats(
+                                AnalysisStats {
+                                    path: ".",
+                                    parallel: true,
+                                },
+                            ),
+                        },
+                    ),
+                }
+            "#]],
+        );
+        check(
+            mount_env::App::from_vec,
+            "ra -v analysis-stats .",
+            expect![[r#"
+                App {
+                    subcommand: Ra(
+                        RustAnalyzer {
+                            verbose: 1,
+                            subcommand: AnalysisStats(
+                                AnalysisStats {
+                                    path: ".",
+                                    parallel: true,
+                                },
+                            ),
+                        },
+                    ),
+                }
+            "#]],
+        );
+    });
+
+    // Mounted commands are generated on their own, so only their names are known here.
+    let spec = mount_env::App::spec_json();
+    assert!(spec.contains(r#"{"name":"ra","aliases":[],"deprecated_aliases":[],"doc":null,"default":false,"args":[],"flags":[],"subcommands":[]}"#), "{spec}");
+    check(
+        mount_env::App::from_vec,
+        "generate bash",
+        expect![[r#"
+            _app() {
+                local cur="${COMP_WORDS[COMP_CWORD]}" state="app" i
+                for ((i = 1; i < COMP_CWORD; i++)); do
+                    case "$state,${COMP_WORDS[i]}" in
+                        "app,ra") state="app__ra" ;;
+                        "app,generate") state="app__generate_" ;;
+                    esac
+                done
+                case "$state" in
+                    "app") COMPREPLY=($(compgen -W "-h --help ra help generate" -- "$cur")) ;;
+                    "app__ra") COMPREPLY=($(compgen -W "help" -- "$cur")) ;;
+                    "app__generate_") COMPREPLY=($(compgen -W "bash zsh fish man" -- "$cur")) ;;
+                esac
+            }
+            complete -o default -F _app app
+        "#]],
+    );
+}
+
+#[test]
+fn prompt() {
+    check(
+        prompt::Login::from_vec,
+        "--user me --token secret",
+        expect![[r#"
+            Login {
+                user: "me",
+                token: "secret",
+                server: None,
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn glob() {
+    check(
+        glob::Lint::from_vec,
+        "all tests/data/sm*.rs tests/data/[a-f]mpty.rs tests/*/smoke.?s missing/*.rs -e tests/*",
+        expect![[r#"
+            Lint {
+                subcommand: All(
+                    All {
+                        files: [
+                            "tests/data/smoke.rs",
+                            "tests/data/empty.rs",
+                            "tests/data/smoke.rs",
+                            "tests/it/smoke.rs",
+                            "missing/*.rs",
+                        ],
+                        exclude: [
+                            "tests/*",
+                        ],
+                        include: [],
+                        extra: [],
+                    },
+                ),
+            }
+        "#]],
+    );
+    if cfg!(not(windows)) {
+        check(
+            glob::Lint::from_vec,
+            "native tests/data/s*.rs",
+            expect![[r#"
+                Lint {
+                    subcommand: Native(
+                        Native {
+                            files: [
+                                "tests/data/s*.rs",
+                            ],
+                        },
+                    ),
+                }
+            "#]],
+        );
+    }
+}
+
+#[test]
+fn glob_flags() {
+    check(
+        glob::Lint::from_vec,
+        "all -i tests/**/sm*.rs -i Cargo.toml --extra missing/*.rs",
+        expect![[r#"
+            Lint {
+                subcommand: All(
+                    All {
+                        files: [],
+                        exclude: [],
+                        include: [
+                            "tests/data/smoke.rs",
+                            "tests/it/smoke.rs",
+                            "Cargo.toml",
+                        ],
+                        extra: [],
+                    },
+                ),
+            }
+        "#]],
+    );
+    check(
+        glob::Lint::from_vec,
+        "all -i missing/*.rs",
+        expect!["Invalid value `missing/*.rs` for `-i`: no files match the pattern"],
+    );
+}
+
+/// A type of its own named like the builtin `Duration`.
+mod units {
+    #[derive(Debug)]
+    pub struct Duration(pub u64);
+
+    impl std::str::FromStr for Duration {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Duration)
+        }
+    }
+}
+
+#[test]
+fn duration() {
+    check(
+        duration::Fetch::from_vec,
+        "--timeout 1h30m --retry-after 250ms --retry-after 1.5s 2d",
+        expect![[r#"
+            Fetch {
+                tick: Some(
+                    172800s,
+                ),
+                timeout: Some(
+                    5400s,
+                ),
+                retry_after: [
+                    250ms,
+                    1.5s,
+                ],
+                ttl: None,
+            }
+        "#]],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--timeout 30",
+        expect![
+            "Invalid value `30` for `--timeout`: missing unit after `30`, expected one of: ns, us, µs, ms, s, sec, m, min, h, d"
+        ],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--timeout 10years",
+        expect![
+            "Invalid value `10years` for `--timeout`: unknown unit `years`, expected one of: ns, us, µs, ms, s, sec, m, min, h, d"
+        ],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--ttl 3",
+        expect![[r#"
+            Fetch {
+                tick: None,
+                timeout: None,
+                retry_after: [],
+                ttl: Some(
+                    Duration(
+                        3,
+                    ),
+                ),
+            }
+        "#]],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--timeout s",
+        expect!["Invalid value `s` for `--timeout`: expected a number, got `s`"],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--timeout µs",
+        expect!["Invalid value `µs` for `--timeout`: expected a number, got `µ`"],
+    );
+    check(
+        duration::Fetch::from_vec,
+        "--timeout é",
+        expect!["Invalid value `é` for `--timeout`: expected a number, got `é`"],
+    );
+}
+
+/// A type which keeps the raw argument, rejecting empty ones.
+mod labels {
+    use std::ffi::{OsStr, OsString};
+
+    #[derive(Debug)]
+    pub struct Label(pub OsString);
+
+    impl TryFrom<&OsStr> for Label {
+        type Error = &'static str;
+
+        fn try_from(value: &OsStr) -> Result<Self, Self::Error> {
+            if value.is_empty() {
+                return Err("label is empty");
+            }
+            Ok(Label(value.to_os_string()))
+        }
+    }
+}
+
+#[test]
+fn from_os_str() {
+    check(
+        os_str::Tag::from_vec,
+        "--label a --label b c",
+        expect![[r#"
+            Tag {
+                target: Some(
+                    Label(
+                        "c",
+                    ),
+                ),
+                label: [
+                    Label(
+                        "a",
+                    ),
+                    Label(
+                        "b",
+                    ),
+                ],
+            }
+        "#]],
+    );
+    check(os_str::Tag::from_vec, "--label", expect!["expected a value for `--label`"]);
+
+    let err = os_str::Tag::from_vec(vec!["--label".into(), "".into()]).unwrap_err();
+    expect!["Invalid value `` for `--label`: label is empty"].assert_eq(&err.to_string());
+    let err = os_str::Tag::from_vec(vec!["".into()]).unwrap_err();
+    expect!["Invalid value `` for `target`: label is empty"].assert_eq(&err.to_string());
+}
+
+#[cfg(unix)]
+#[test]
+fn from_os_str_non_utf8() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let value = std::ffi::OsStr::from_bytes(b"caf\xe9").to_os_string();
+    let args = vec!["--label".into(), value.clone(), value.clone()];
+    let flags = os_str::Tag::from_vec(args).unwrap();
+    assert_eq!(flags.label[0].0, value);
+    assert_eq!(flags.target.unwrap().0, value);
+}
+
+#[test]
+fn byte_size() {
+    let parse = |text: &str| match text.parse::<xflags::ByteSize>() {
+        Ok(it) => it.as_u64().to_string(),
+        Err(err) => err.to_string(),
+    };
+    let sizes = ["512", "64k", "2MiB", "1.5G", "10 kB", "1TB", "3XB", "k", "64KiBs"];
+    expect![[r#"
+        [
+            "512",
+            "65536",
+            "2097152",
+            "1610612736",
+            "10000",
+            "1000000000000",
+            "unknown size suffix `XB`, expected one like `k`, `MiB` or `GB`",
+            "expected a size, like `512`, `64k` or `2MiB`",
+            "unknown size suffix `KiBs`, expected one like `k`, `MiB` or `GB`",
+        ]
+    "#]]
+    .assert_debug_eq(&sizes.map(parse));
+    expect!["3MiB"].assert_eq(&xflags::ByteSize(3 * 1024 * 1024).to_string());
+}
+
+#[test]
+fn checked_paths() {
+    check(
+        checked_paths::Render::from_vec,
+        "--config Cargo.toml --templates tests --output out.txt",
+        expect![[r#"
+            Render {
+                config: Some(
+                    "Cargo.toml",
+                ),
+                templates: Some(
+                    "tests",
+                ),
+                output: "out.txt",
+            }
+        "#]],
+    );
+    check(
+        checked_paths::Render::from_vec,
+        "--config missing.toml --output out.txt",
+        expect!["Invalid value `missing.toml` for `--config`: file does not exist"],
+    );
+    check(
+        checked_paths::Render::from_vec,
+        "--config tests --output out.txt",
+        expect!["Invalid value `tests` for `--config`: expected a file, found a directory"],
+    );
+    check(
+        checked_paths::Render::from_vec,
+        "--templates Cargo.toml --output out.txt",
+        expect!["Invalid value `Cargo.toml` for `--templates`: expected a directory, found a file"],
+    );
+    check(
+        checked_paths::Render::from_vec,
+        "--output Cargo.toml",
+        expect!["Invalid value `Cargo.toml` for `--output`: path already exists"],
+    );
+    check(
+        checked_paths::Render::from_vec,
+        "--output missing/out.txt",
+        expect!["Invalid value `missing/out.txt` for `--output`: parent directory does not exist"],
+    );
+}
+
+#[test]
+fn bool_switch() {
+    check(
+        bool_switch::Deploy::from_vec,
+        "--dry-run",
+        expect![[r#"
+        Deploy {
+            color: None,
+            dry_run: true,
+            confirm: [],
+        }
+    "#]],
+    );
+    check(
+        bool_switch::Deploy::from_vec,
+        "--color=off --dry-run=FALSE",
+        expect![[r#"
+        Deploy {
+            color: Some(
+                false,
+            ),
+            dry_run: false,
+            confirm: [],
+        }
+    "#]],
+    );
+    check(
+        bool_switch::Deploy::from_vec,
+        "-c --dry-run=yes --confirm=1 --confirm",
+        expect![[r#"
+        Deploy {
+            color: Some(
+                true,
+            ),
+            dry_run: true,
+            confirm: [
+                true,
+                true,
+            ],
+        }
+    "#]],
+    );
+    check(
+        bool_switch::Deploy::from_vec,
+        "--dry-run=maybe",
+        expect![
+            "Invalid value `maybe` for `--dry-run`: expected one of: true, false, yes, no, on, off, 1, 0"
+        ],
+    );
+    check(bool_switch::Deploy::from_vec, "--dry-run true", expect!["Unknown command: `true`"]);
+    check(
+        bool_switch::Deploy::from_vec,
+        "help",
+        expect![[r#"
+        Usage: deploy [--color[=<value>]] --dry-run[=<value>] [--confirm[=<value>]]... [-h]
+        Options:
+          -c, --color[=<value>] 
+          --dry-run[=<value>]  
+          --confirm[=<value>]  
+          -h, --help           Prints help
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn counted() {
+    check(
+        counted::Counted::from_vec,
+        "",
+        expect![[r#"
+        Counted {
+            verbose: Warn,
+            debug: 0,
+        }
+    "#]],
+    );
+    check(
+        counted::Counted::from_vec,
+        "-v -d -v -v -d",
+        expect![[r#"
+        Counted {
+            verbose: Debug,
+            debug: 2,
+        }
+    "#]],
+    );
+
+    let mut flags = counted::Counted::from_vec(vec!["-d".into()]).unwrap();
+    flags.apply(vec!["-v".into(), "-d".into()]).unwrap();
+    assert_eq!((flags.verbose, flags.debug), (xflags::LogLevel::Info, 2));
+}
+
+#[test]
+fn local_to_fn() {
+    xflags::xflags! {
+        cmd tool {
+            optional -j, --jobs n: u32
+            cmd run {}
+        }
+    }
+
+    check(
+        Tool::from_vec,
+        "-j 2 run",
+        expect![[r#"
+        Tool {
+            jobs: Some(
+                2,
+            ),
+            subcommand: Run(
+                Run,
+            ),
+        }
+    "#]],
+    );
+}
+
+#[test]
+fn settings() {
+    check(
+        settings::Archive::from_vec,
+        "-vvcj4 --output=out.tar a -v b",
+        expect![[r#"
+            Archive {
+                files: [
+                    "a",
+                    "-v",
+                    "b",
+                ],
+                verbose: 2,
+                color: Some(
+                    true,
+                ),
+                jobs: Some(
+                    4,
+                ),
+                output: Some(
+                    "out.tar",
+                ),
+            }
+        "#]],
+    );
+    check(
+        settings::Archive::from_vec,
+        "-j=4",
+        expect!["Invalid value `=4` for `--jobs`: invalid digit found in string"],
+    );
+    check(settings::Archive::from_vec, "-vx", expect!["Unknown flag: `-x`"]);
+    check(
+        settings::Archive::from_vec,
+        "a --help",
+        expect![[r#"
+            Archive {
+                files: [
+                    "a",
+                    "--help",
+                ],
+                verbose: 0,
+                color: None,
+                jobs: None,
+                output: None,
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn strict_ordering() {
+    check(
+        strict_ordering::Runner::from_vec,
+        "exec ls -l --help",
+        expect![[r#"
+            Runner {
+                subcommand: Exec(
+                    Exec {
+                        program: "ls",
+                        args: [
+                            "-l",
+                            "--help",
+                        ],
+                    },
+                ),
+            }
+        "#]],
+    );
+    check(
+        strict_ordering::Runner::from_vec,
+        "help exec",
+        expect![[r#"
+        Usage: exec <program> [args]...
+        Arguments:
+          <program>            
+          [args]...            
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+    check(
+        strict_ordering::Runner::from_vec,
+        "exec --help",
+        expect![[r#"
+        Usage: exec <program> [args]...
+        Arguments:
+          <program>            
+          [args]...            
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn strip_help() {
+    check(strip_help::Tiny::from_vec, "--help", expect!["Usage: tiny [-n <n>] [-h] <COMMAND>"]);
+    check(strip_help::Tiny::from_vec, "run --help", expect!["Usage: run"]);
+
+    for shell in ["bash", "zsh", "fish", "man"] {
+        let text = strip_help::Tiny::from_slice(&["generate", shell]).unwrap_err().to_string();
+        assert!(!text.contains("things") && !text.contains("description"), "{text}");
+    }
+    assert!(!strip_help::Tiny::spec_json().contains("things"));
+}
+
+#[test]
+fn unicode() {
+    check(
+        unicode::Werkzeug::from_vec,
+        "-ß 4 --ausgabe aus -ü -ü ü in",
+        expect![[r#"
+        Werkzeug {
+            ausgabe: Some(
+                "aus",
+            ),
+            größe: Some(
+                4,
+            ),
+            übersprungen: 2,
+            subcommand: Übersetzen(
+                Übersetzen {
+                    eingabe: "in",
+                },
+            ),
+        }
+    "#]],
+    );
+    check(
+        unicode::Werkzeug::from_vec,
+        "--größe 4 prüfen",
+        expect![[r#"
+        Werkzeug {
+            ausgabe: None,
+            größe: Some(
+                4,
+            ),
+            übersprungen: 0,
+            subcommand: Prüfen(
+                Prüfen,
+            ),
+        }
+    "#]],
+    );
+    check(
+        unicode::Werkzeug::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: werkzeug [-a <datei>] [-ß <größe>] [-ü]... [-h] <COMMAND>
+
+        Übersetzungswerkzeug.
+
+        Options:
+          -a, --ausgabe <datei> Ausgabedatei.
+          -ß, --größe <größe>  Größe des Puffers.
+          -ü, --übersprungen   
+          -h, --help           Prints help
+
+        Commands:
+          übersetzen           Übersetzt eine Datei.
+          prüfen               
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn short_symbols() {
+    check(
+        short_symbols::List::from_vec,
+        "-1 -@ -# 3 -% .",
+        expect![[r#"
+        List {
+            paths: Some(
+                ".",
+            ),
+            one_per_line: true,
+            extended: true,
+            columns: Some(
+                3,
+            ),
+            percent: true,
+        }
+    "#]],
+    );
+    check(short_symbols::List::from_vec, "-2", expect!["Unknown flag: `-2`"]);
+    check(
+        short_symbols::List::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: list [paths] [-1] [-@] [-# <n>] [-%] [-h]
+        Arguments:
+          [paths]              
+
+        Options:
+          -1, --one-per-line   One entry per line.
+          -@, --extended       Show extended attributes.
+          -#, --columns <n>    
+          -%, --percent        
+          -h, --help           Prints help
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn lenient() {
+    let args = ". -n 1 --frobnicate -n 2".split_ascii_whitespace().map(OsString::from).collect();
+    let (flags, warnings) = smoke::RustAnalyzer::from_vec_lenient(args).unwrap();
+    assert_eq!(flags.number, 2);
+    let warnings = warnings.iter().map(|it| format!("{:?}: {it}\n", it.arg_index()));
+    expect![[r#"
+        Some(3): Unknown flag: `--frobnicate`
+        None: Flag specified more than once: `--number`
+    "#]]
+    .assert_eq(&warnings.collect::<String>());
+
+    let args = vec!["--frobnicate".into()];
+    expect!["Flag is required: `--number`"]
+        .assert_eq(&smoke::RustAnalyzer::from_vec_lenient(args).unwrap_err().to_string());
+}
+
+#[test]
+fn non_empty_pos() {
+    check(
+        non_empty_pos::Cat::from_vec,
+        "a b -n",
+        expect![[r#"
+        Cat {
+            files: [
+                "a",
+                "b",
+            ],
+            number: true,
+        }
+    "#]],
+    );
+    check(non_empty_pos::Cat::from_vec, "-n", expect!["expected at least one <files>"]);
+    check(
+        non_empty_pos::Cat::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: cat <files>... [-n] [-h]
+        Arguments:
+          <files>...           Files to print.
+
+        Options:
+          -n, --number         
+          -h, --help           Prints help
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn flexible_pos() {
+    let parse = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect::<Vec<_>>();
+        match flexible_pos::Copy::from_vec(args) {
+            Ok(flags) => format!("{:?}", flags.subcommand),
+            Err(err) => format!("{err} {:?}", err.arg_index()),
+        }
+    };
+
+    expect![[r#"Cp(Cp { sources: ["a", "b"], dest: "c", recursive: true })"#]]
+        .assert_eq(&parse("cp a b -r c"));
+    expect![[r#"Cp(Cp { sources: [], dest: "a", recursive: false })"#]].assert_eq(&parse("cp a"));
+    expect!["Flag is required: `dest` None"].assert_eq(&parse("cp"));
+    expect![[r#"Mv(Mv { source: None, dest: "a", jobs: None })"#]].assert_eq(&parse("mv a"));
+    expect![[r#"Mv(Mv { source: Some("a"), dest: "b", jobs: None })"#]].assert_eq(&parse("mv a b"));
+    expect!["Unknown command: `c` Some(3)"].assert_eq(&parse("mv a b c"));
+    expect![[r#"Ln(Ln { mode: None, links: ["a"], target: "t" })"#]].assert_eq(&parse("ln a t"));
+    expect![[r#"Ln(Ln { mode: Some(7), links: ["a", "b"], target: "t" })"#]]
+        .assert_eq(&parse("ln 7 a b t"));
+    expect![[r#"Ln(Ln { mode: None, links: ["x"], target: "t" })"#]].assert_eq(&parse("ln x t"));
+    expect!["Flag is required: `target` None"].assert_eq(&parse("ln t"));
+    expect!["Invalid value `x` for `mode`: invalid digit found in string Some(1)"]
+        .assert_eq(&parse("ln x a t"));
+}
+
+#[test]
+fn deep_help() {
+    fn help<T>(f: fn(Vec<OsString>) -> xflags::Result<T>, args: &str) -> String {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect();
+        let err = f(args).err().unwrap();
+        assert!(err.is_help(), "{err}");
+        err.to_string()
+    }
+    fn check_same<T>(f: fn(Vec<OsString>) -> xflags::Result<T>, deep: &str, flag: &str) {
+        assert_eq!(help(f, deep), help(f, flag));
+    }
+
+    check_same(subcommands::RustAnalyzer::from_vec, "help server watch", "server watch --help");
+    check_same(subcommands::RustAnalyzer::from_vec, "server help watch", "server watch --help");
+    check_same(help::Helpful::from_vec, "help sub", "sub --help");
+    check_same(help::Helpful::from_vec, "src help sub", "src sub --help");
+    check_same(mount::App::from_vec, "help rust-analyzer server", "rust-analyzer server --help");
+    expect![[r#"
+        Usage: watch
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]]
+    .assert_eq(&help(subcommands::RustAnalyzer::from_vec, "help server watch"));
+
+    // Where a value is expected, `help` is just a value.
+    let flags = subcommands::RustAnalyzer::from_vec(vec!["analysis-stats".into(), "help".into()]);
+    assert_eq!(flags.unwrap().subcommand_path(), ["rust-analyzer", "analysis-stats"]);
+}
+
+#[test]
+fn deprecated_aliases() {
+    check(
+        deprecated::Tool::from_vec,
+        "check --fix",
+        expect![[r#"
+        Tool {
+            subcommand: Lint(
+                Lint {
+                    fix: true,
+                },
+            ),
+        }
+    "#]],
+    );
+    check(
+        deprecated::Tool::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: tool [-h] <COMMAND>
+        Options:
+          -h, --help           Prints help
+
+        Commands:
+          lint                 Checks the code.
+          build                
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+
+    let args = vec!["verify".into()];
+    let (flags, warnings) = deprecated::Tool::from_vec_lenient(args).unwrap();
+    assert_eq!(flags.subcommand_path(), ["tool", "lint"]);
+    expect!["`verify` is deprecated, use `lint` instead"].assert_eq(&warnings[0].to_string());
+}
+
+#[test]
+fn help_epilogue() {
+    epilogue::Plug::set_help_epilogue("Plugins:\n  fmt                  Formats code\n");
+    check(
+        epilogue::Plug::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: plug [-v] [-h] <COMMAND>
+
+        Runs plugins.
+
+        Options:
+          -v, --verbose        
+          -h, --help           Prints help
+
+        Commands:
+          list                 
+          help                 Print this message or the help of the given subcommand(s)
+
+        Plugins:
+          fmt                  Formats code"#]],
+    );
+    check(
+        epilogue::Plug::from_vec,
+        "list --help",
+        expect![[r#"
+        Usage: list
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+}
+
+#[test]
+fn color() {
+    check(
+        color::Paint::from_vec,
+        "wall north",
+        expect![[r#"
+        Paint {
+            color: None,
+            dry_run: false,
+            subcommand: Wall(
+                Wall {
+                    name: "north",
+                },
+            ),
+        }
+    "#]],
+    );
+    check(
+        color::Paint::from_vec,
+        "wall north --color never",
+        expect![[r#"
+        Paint {
+            color: Some(
+                Never,
+            ),
+            dry_run: false,
+            subcommand: Wall(
+                Wall {
+                    name: "north",
+                },
+            ),
+        }
+    "#]],
+    );
+    check(
+        color::Paint::from_vec,
+        "--color sometimes wall north",
+        expect!["Invalid value `sometimes` for `--color`: expected `auto`, `always` or `never`"],
+    );
+    check(
+        color::Paint::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: paint [--color <when>] [--dry-run] [-h] <COMMAND>
+        Options:
+          --color <when>       When to use colors: auto, always or never
+          --dry-run            
+          -h, --help           Prints help
+
+        Commands:
+          wall                 
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+
+    let flags = color::Paint::from_slice(&["--color", "always", "wall", "north"]).unwrap();
+    assert_eq!(flags.color_choice(), xflags::ColorChoice::Always);
+    let flags = color::Paint::from_slice(&["wall", "north"]).unwrap();
+    assert_eq!(flags.color_choice(), xflags::ColorChoice::Auto);
+
+    check(
+        color_assign::Paint::from_vec,
+        "north --color=never",
+        expect![[r#"
+            Paint {
+                name: "north",
+                color: Some(
+                    Never,
+                ),
+            }
+        "#]],
+    );
+    // The errors about the arguments before `--color` are colored as selected too.
+    let err = color::Paint::from_slice(&["--bogus", "--color", "never"]).unwrap_err();
+    assert!(format!("{err:?}").contains("color: Some(Never)"), "{err:?}");
+    let err = color_assign::Paint::from_slice(&["--bogus", "--color=never"]).unwrap_err();
+    assert!(format!("{err:?}").contains("color: Some(Never)"), "{err:?}");
+    let err = color::Paint::from_slice(&["--bogus", "--color=never"]).unwrap_err();
+    assert!(format!("{err:?}").contains("color: Some(Auto)"), "{err:?}");
+}
+
+#[test]
+fn testing_helpers() {
+    use subcommands::{AnalysisStats, RustAnalyzer, RustAnalyzerCmd};
+    use xflags::testing::{assert_snapshot, help};
+
+    xflags::assert_parses!("-v server", RustAnalyzer { verbose: 1, .. });
+    xflags::assert_parses!(
+        "analysis-stats . --parallel",
+        RustAnalyzer {
+            subcommand: RustAnalyzerCmd::AnalysisStats(AnalysisStats { parallel: true, .. }),
+            ..
+        }
+    );
+    xflags::assert_parses!("-v -v server", RustAnalyzer { verbose, .. } if verbose == 2);
+    xflags::assert_error_contains!(RustAnalyzer, "analysis-stats", "required");
+
+    let path = std::env::temp_dir().join(format!("xflags-help-{}.txt", std::process::id()));
+    std::fs::write(&path, help::<RustAnalyzer>("server") + "\n").unwrap();
+    assert_snapshot(&path, &help::<RustAnalyzer>("server"));
+    let res = std::panic::catch_unwind(|| assert_snapshot(&path, &help::<RustAnalyzer>("")));
+    std::fs::remove_file(&path).unwrap();
+    assert!(res.is_err());
+}
+
+#[test]
+fn escapes() {
+    check(
+        escapes::Echo::from_vec,
+        "--help",
+        expect![[r#"
+        Usage: echo [--sep <sep>] [-h]
+
+        Prints "quoted" text.
+
+        Options:
+          --sep <sep>          Separator, `\n` by default.
+          -h, --help           Prints help
+
+        Commands:
+          help                 Print this message or the help of the given subcommand(s)"#]],
+    );
+    let spec = escapes::Echo::spec_json();
+    assert!(spec.contains(r#""doc":"Prints \"quoted\" text.""#), "{spec}");
+    assert!(spec.contains(r#""doc":"Separator, `\\n` by default.""#), "{spec}");
+}
+
+#[test]
+fn field_names() {
+    use field_names::MakeField as Field;
+
+    let args = ["foo", "--jobs", "4"].map(Into::into).to_vec();
+    let (_flags, sources) = field_names::Make::from_vec_with_sources(args).unwrap();
+    assert_eq!(sources.get(Field::Foo_Jobs), xflags::ValueSource::CommandLine);
+    assert_eq!(sources.get(Field::FooJobs), xflags::ValueSource::Default);
+}
+
+#[test]
+fn run_dispatch() {
+    let run = |args: &str| {
+        xflags::run! {
+            args = args.split_ascii_whitespace().map(Into::into).collect();
+            cmd app {
+                repeated -v, --verbose
+                cmd build {
+                    optional --release
+                }
+                cmd test {
+                    repeated filter: String
+                }
+            }
+            match app {
+                AppCmd::Build(build) => format!("build, release: {}", build.release),
+                AppCmd::Test(test) => format!("test {:?}, verbose: {}", test.filter, app.verbose),
+            }
+        }
+    };
+
+    expect!["build, release: true"].assert_eq(&run("build --release"));
+    expect![[r#"test ["a", "b"], verbose: 2"#]].assert_eq(&run("-v test a b -v"));
+}
+
+#[test]
+fn anon_subcommands() {
+    // `parse!` generates the same code as `parse_or_exit!`, except for
+    // parsing the given arguments instead of the process ones.
+    let parse = |args: &str| {
+        let flags = xflags::parse! {
+            args = args.split_ascii_whitespace().map(Into::into).collect();
+            repeated -v, --verbose
+            cmd build {
+                optional --release
+            }
+            cmd clean {}
+        };
+        match flags {
+            Ok(flags) => match (flags.build(), flags.clean()) {
+                (Some(build), None) => format!("build, release: {}", build.release),
+                (None, Some(_)) => format!("clean, verbose: {}", flags.verbose),
+                _ => unreachable!(),
+            },
+            Err(err) => err.to_string(),
+        }
+    };
+
+    expect!["build, release: true"].assert_eq(&parse("build --release"));
+    expect!["clean, verbose: 1"].assert_eq(&parse("-v clean"));
+    expect!["Unknown flag: `--release`"].assert_eq(&parse("clean --release"));
+}
+
+#[test]
+fn exit_codes() {
+    use std::process::{ExitCode, Termination};
+
+    let report = |args: &str| {
+        let args = args.split_ascii_whitespace().map(OsString::from).collect();
+        xflags::Exit::from(smoke::RustAnalyzer::from_vec(args).map(drop)).report()
+    };
+    assert_eq!(report("-n 1 ."), ExitCode::SUCCESS);
+    assert_eq!(report("--help"), ExitCode::SUCCESS);
+    assert_eq!(report("-n many ."), ExitCode::from(2));
+    assert_eq!(xflags::Exit::<ExitCode>(Ok(ExitCode::from(3))).report(), ExitCode::from(3));
+}
@@ -0,0 +1,378 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct App {
+    pub dry_run: bool,
+    pub subcommand: AppCmd,
+}
+
+#[derive(Debug)]
+pub enum AppCmd {
+    RustAnalyzer(super::subcommands::RustAnalyzer),
+    Smoke(super::smoke::RustAnalyzer),
+    Other(Other),
+}
+
+#[derive(Debug)]
+pub struct Other;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppField {
+    DryRun,
+}
+
+impl From<AppField> for usize {
+    fn from(field: AppField) -> usize {
+        match field {
+            AppField::DryRun => 0,
+        }
+    }
+}
+
+impl App {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Other {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &App::HELP_OTHER__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        App::USAGE_OTHER__
+    }
+}
+
+impl App {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl App {
+    const FLAGS_: &'static [&'static str] = &["--dry-run", "--help", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            dry_run: Vec<()>,
+            rust_analyzer__mounted_: Option<super::subcommands::RustAnalyzer>,
+            smoke__mounted_: Option<super::smoke::RustAnalyzer>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=3, 0) => l_.dry_run.push(()),
+                    (3, 1 | 2) => return Err(p_.help(&Self::HELP_OTHER__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"rust-analyzer") => {
+                        *state_ = 1;
+                        l_.rust_analyzer__mounted_ =
+                            Some(p_.mount::<super::subcommands::RustAnalyzer>(&["rust-analyzer"])?);
+                    }
+                    (0, b"smoke") => {
+                        *state_ = 2;
+                        l_.smoke__mounted_ =
+                            Some(p_.mount::<super::smoke::RustAnalyzer>(&["smoke"])?);
+                    }
+                    (0, b"other") => *state_ = 3,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (3, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.dry_run.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if l_.dry_run.is_empty() {
+            let values_ = p_.fallback(0, &[], "dry-run");
+            for _ in 0..p_.switch_count("--dry-run", values_, true)? {
+                l_.dry_run.push(());
+            }
+        }
+        p_.check_optional("--dry-run", l_.dry_run.len())?;
+        Ok(App {
+            dry_run: !l_.dry_run.is_empty(),
+            subcommand: match *state_ {
+                1 => AppCmd::RustAnalyzer(l_.rust_analyzer__mounted_.take().unwrap()),
+                2 => AppCmd::Smoke(l_.smoke__mounted_.take().unwrap()),
+                3 => AppCmd::Other(Other {}),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl App {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            dry_run: Vec<()>,
+            rust_analyzer__mounted_: Option<super::subcommands::RustAnalyzer>,
+            smoke__mounted_: Option<super::smoke::RustAnalyzer>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=3, 0) => l_.dry_run.push(()),
+                    (3, 1 | 2) => return Err(p_.help(&Self::HELP_OTHER__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"rust-analyzer") => {
+                        *state_ = 1;
+                        l_.rust_analyzer__mounted_ =
+                            Some(p_.mount::<super::subcommands::RustAnalyzer>(&["rust-analyzer"])?);
+                    }
+                    (0, b"smoke") => {
+                        *state_ = 2;
+                        l_.smoke__mounted_ =
+                            Some(p_.mount::<super::smoke::RustAnalyzer>(&["smoke"])?);
+                    }
+                    (0, b"other") => *state_ = 3,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (3, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--dry-run", l_.dry_run.len())?;
+        if !l_.dry_run.is_empty() {
+            self.dry_run = true;
+        }
+        match &mut self.subcommand {
+            AppCmd::RustAnalyzer(cmd_) => {
+                if let Some(it) = l_.rust_analyzer__mounted_.take() {
+                    *cmd_ = it;
+                }
+            }
+            AppCmd::Smoke(cmd_) => {
+                if let Some(it) = l_.smoke__mounted_.take() {
+                    *cmd_ = it;
+                }
+            }
+            AppCmd::Other(_) => {}
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            AppCmd::RustAnalyzer(_) => 1,
+            AppCmd::Smoke(_) => 2,
+            AppCmd::Other(_) => 3,
+        }
+    }
+}
+
+impl App {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["app"];
+        match &self.subcommand {
+            AppCmd::RustAnalyzer(cmd_) => {
+                res_.push("rust-analyzer");
+                res_.extend(cmd_.subcommand_path().into_iter().skip(1));
+            }
+            AppCmd::Smoke(cmd_) => {
+                res_.push("smoke");
+                res_.extend(cmd_.subcommand_path().into_iter().skip(1));
+            }
+            AppCmd::Other(_) => {
+                res_.push("other");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for App {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl App {
+    const USAGE_OTHER__: &'static str = "Usage: other";
+    const HELP_OTHER__: xflags::Help = xflags::Help::new(
+        Self::USAGE_OTHER__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_: &'static str = "Usage: app [--dry-run] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("--dry-run", ""), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[
+                    ("rust-analyzer", "Rust analyzer commands."),
+                    ("smoke", ""),
+                    ("other", ""),
+                    xflags::rt::HELP_ENTRY,
+                ],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            3 => Self::USAGE_OTHER__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"app\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"dry-run\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"rust-analyzer\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"Rust analyzer commands.\",\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]},{\"name\":\"smoke\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]},{\"name\":\"other\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+}
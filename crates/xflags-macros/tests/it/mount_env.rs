@@ -0,0 +1,302 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct App {
+    pub subcommand: AppCmd,
+}
+
+#[derive(Debug)]
+pub enum AppCmd {
+    Ra(super::subcommands::RustAnalyzer),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppField {}
+
+impl From<AppField> for usize {
+    fn from(field: AppField) -> usize {
+        match field {}
+    }
+}
+
+impl App {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl App {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<AppField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl App {
+    const FLAGS_: &'static [&'static str] = &["--help", "-h"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        p_.load_env("XFLAGS_MOUNT_");
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            ra__mounted_: Option<super::subcommands::RustAnalyzer>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 1) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"ra") => {
+                        *state_ = 1;
+                        l_.ra__mounted_ =
+                            Some(p_.mount::<super::subcommands::RustAnalyzer>(&["ra"])?);
+                    }
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        Ok(App {
+            subcommand: match *state_ {
+                1 => AppCmd::Ra(l_.ra__mounted_.take().unwrap()),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl App {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            ra__mounted_: Option<super::subcommands::RustAnalyzer>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 1) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"ra") => {
+                        *state_ = 1;
+                        l_.ra__mounted_ =
+                            Some(p_.mount::<super::subcommands::RustAnalyzer>(&["ra"])?);
+                    }
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        match &mut self.subcommand {
+            AppCmd::Ra(cmd_) => {
+                if let Some(it) = l_.ra__mounted_.take() {
+                    *cmd_ = it;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            AppCmd::Ra(_) => 1,
+        }
+    }
+}
+
+impl App {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["app"];
+        match &self.subcommand {
+            AppCmd::Ra(cmd_) => {
+                res_.push("ra");
+                res_.extend(cmd_.subcommand_path().into_iter().skip(1));
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for App {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl App {
+    const USAGE_: &'static str = "Usage: app [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("ra", ""), xflags::rt::GENERATE_ENTRY, xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"app\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"ra\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+    const GENERATED_: &'static [(&'static str, &'static str)] = &[
+("bash", "_app() {\n    local cur=\"${COMP_WORDS[COMP_CWORD]}\" state=\"app\" i\n    for ((i = 1; i < COMP_CWORD; i++)); do\n        case \"$state,${COMP_WORDS[i]}\" in\n            \"app,ra\") state=\"app__ra\" ;;\n            \"app,generate\") state=\"app__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"app\") COMPREPLY=($(compgen -W \"-h --help ra help generate\" -- \"$cur\")) ;;\n        \"app__ra\") COMPREPLY=($(compgen -W \"help\" -- \"$cur\")) ;;\n        \"app__generate_\") COMPREPLY=($(compgen -W \"bash zsh fish man\" -- \"$cur\")) ;;\n    esac\n}\ncomplete -o default -F _app app\n"),
+("zsh", "#compdef app\n\n_app() {\n    local state=\"app\" i\n    for ((i = 2; i < CURRENT; i++)); do\n        case \"$state,${words[i]}\" in\n            \"app,ra\") state=\"app__ra\" ;;\n            \"app,generate\") state=\"app__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"app\") compadd -- -h --help ra help generate ;;\n        \"app__ra\") compadd -- help ;;\n        \"app__generate_\") compadd -- bash zsh fish man; return ;;\n    esac\n    _files\n}\n\n_app \"$@\"\n"),
+("fish", "complete -c app -s h -l help -d 'Prints help'\ncomplete -c app -f -n '__fish_use_subcommand' -a ra\ncomplete -c app -f -n '__fish_use_subcommand' -a generate -d 'Print shell completions or a man page'\ncomplete -c app -f -n '__fish_seen_subcommand_from generate' -a 'bash zsh fish man'\n"),
+("man", ".TH APP 1\n.SH NAME\napp\n.SH SYNOPSIS\n\\fBapp\\fR <COMMAND>\n.SH OPTIONS\n.TP\n\\fB\\-h\\fR, \\fB\\-\\-help\\fR\nPrints help\n.SH COMMANDS\n.TP\n\\fBra\\fR\n.SH APP RA\n\\fBapp ra\\fR\n"),
+];
+}
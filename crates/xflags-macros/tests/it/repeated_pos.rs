@@ -9,6 +9,25 @@ pub struct RepeatedPos {
     pub rest: Vec<OsString>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatedPosField {
+    A,
+    B,
+    C,
+    Rest,
+}
+
+impl From<RepeatedPosField> for usize {
+    fn from(field: RepeatedPosField) -> usize {
+        match field {
+            RepeatedPosField::A => 0,
+            RepeatedPosField::B => 1,
+            RepeatedPosField::C => 2,
+            RepeatedPosField::Rest => 3,
+        }
+    }
+}
+
 impl RepeatedPos {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -24,6 +43,72 @@ impl RepeatedPos {
     pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         Self::from_vec_(args)
     }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<RepeatedPosField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RepeatedPosField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
 }
 
 impl RepeatedPos {
@@ -31,49 +116,88 @@ impl RepeatedPos {
         Self::from_env_().unwrap_or_else(|err| err.exit())
     }
     fn from_env_() -> xflags::Result<Self> {
-        let mut p = xflags::rt::Parser::new_from_env();
+        let mut p = xflags::rt::Parser::new_from_env()?;
         Self::parse_(&mut p)
     }
     fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         let mut p = xflags::rt::Parser::new(args);
         Self::parse_(&mut p)
     }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<RepeatedPosField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RepeatedPosField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
 }
 
 impl RepeatedPos {
+    const FLAGS_: &'static [&'static str] = &["--help", "-h"];
     fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
         #![allow(non_snake_case, unused_mut)]
-        let mut a = (false, Vec::new());
-        let mut b = (false, Vec::new());
-        let mut c = (false, Vec::new());
-        let mut rest = (false, Vec::new());
+        #[derive(Default)]
+        struct Locals_ {
+            a: (bool, Vec<PathBuf>),
+            b: (bool, Vec<u32>),
+            c: (bool, Vec<OsString>),
+            rest: (bool, Vec<OsString>),
+        }
+        let mut l_ = Locals_::default();
 
-        let mut state_ = 0u8;
         while let Some(arg_) = p_.pop_flag() {
             match arg_ {
-                Ok(flag_) => match (state_, flag_.as_str()) {
-                    (0, "--help" | "-h") => return Err(p_.help(Self::HELP_)),
-                    _ => return Err(p_.unexpected_flag(&flag_)),
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 1) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
                 },
-                Err(arg_) => match (state_, arg_.to_str().unwrap_or("")) {
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
                     (0, _) => {
-                        if let (done_ @ false, buf_) = &mut a {
+                        if let (done_ @ false, buf_) = &mut l_.a {
                             buf_.push(arg_.into());
                             *done_ = true;
                             continue;
                         }
-                        if let (done_ @ false, buf_) = &mut b {
+                        if let (done_ @ false, buf_) = &mut l_.b {
                             buf_.push(p_.value_from_str::<u32>("b", arg_)?);
                             *done_ = true;
                             continue;
                         }
-                        if let (done_ @ false, buf_) = &mut c {
-                            buf_.push(arg_.into());
+                        if let (done_ @ false, buf_) = &mut l_.c {
+                            buf_.push(arg_);
                             *done_ = true;
                             continue;
                         }
-                        if let (false, buf_) = &mut rest {
-                            buf_.push(arg_.into());
+                        if let (false, buf_) = &mut l_.rest {
+                            buf_.push(arg_);
                             continue;
                         }
                         return Err(p_.unexpected_arg(arg_));
@@ -82,25 +206,135 @@ impl RepeatedPos {
                 },
             }
         }
+        if !l_.a.1.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.b.1.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.c.1.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.rest.1.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        p_.check_required("a", l_.a.1.len())?;
+        p_.check_optional("b", l_.b.1.len())?;
+        p_.check_optional("c", l_.c.1.len())?;
         Ok(RepeatedPos {
-            a: p_.required("a", a.1)?,
-            b: p_.optional("b", b.1)?,
-            c: p_.optional("c", c.1)?,
-            rest: rest.1,
+            a: l_.a.1.pop().unwrap(),
+            b: l_.b.1.pop(),
+            c: l_.c.1.pop(),
+            rest: std::mem::take(&mut l_.rest.1),
         })
     }
 }
+
+impl RepeatedPos {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            a: (bool, Vec<PathBuf>),
+            b: (bool, Vec<u32>),
+            c: (bool, Vec<OsString>),
+            rest: (bool, Vec<OsString>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 0 | 1) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.a {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        if let (done_ @ false, buf_) = &mut l_.b {
+                            buf_.push(p_.value_from_str::<u32>("b", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        if let (done_ @ false, buf_) = &mut l_.c {
+                            buf_.push(arg_);
+                            *done_ = true;
+                            continue;
+                        }
+                        if let (false, buf_) = &mut l_.rest {
+                            buf_.push(arg_);
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("a", l_.a.1.len())?;
+        p_.check_optional("b", l_.b.1.len())?;
+        p_.check_optional("c", l_.c.1.len())?;
+        if let Some(it) = l_.a.1.pop() {
+            self.a = it;
+        }
+        if let Some(it) = l_.b.1.pop() {
+            self.b = Some(it);
+        }
+        if let Some(it) = l_.c.1.pop() {
+            self.c = Some(it);
+        }
+        self.rest.extend(std::mem::take(&mut l_.rest.1));
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
 impl RepeatedPos {
-    const HELP_: &'static str = "Usage: RepeatedPos <a> [b] [c] [rest]... [-h]
-Arguments:
-  <a>                  
-  [b]                  
-  [c]                  
-  [rest]...            
-
-Options:
-  -h, --help           Prints help
-
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["RepeatedPos"]
+    }
+}
+
+impl xflags::rt::Mount for RepeatedPos {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl RepeatedPos {
+    const USAGE_: &'static str = "Usage: RepeatedPos <a> [b] [c] [rest]... [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[("<a>", ""), ("[b]", ""), ("[c]", ""), ("[rest]...", "")],
+            },
+            xflags::HelpSection { title: "Options", entries: &[("-h, --help", "Prints help")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"RepeatedPos\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"a\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null},{\"name\":\"b\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"u32\",\"doc\":null},{\"name\":\"c\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"OsString\",\"doc\":null},{\"name\":\"rest\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"OsString\",\"doc\":null}],\"flags\":[{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
 }
@@ -0,0 +1,425 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Archive {
+    pub files: Vec<PathBuf>,
+
+    pub verbose: u32,
+    pub color: Option<bool>,
+    pub jobs: Option<u32>,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveField {
+    Verbose,
+    Color,
+    Jobs,
+    Output,
+    Files,
+}
+
+impl From<ArchiveField> for usize {
+    fn from(field: ArchiveField) -> usize {
+        match field {
+            ArchiveField::Verbose => 0,
+            ArchiveField::Color => 1,
+            ArchiveField::Jobs => 2,
+            ArchiveField::Output => 3,
+            ArchiveField::Files => 4,
+        }
+    }
+}
+
+impl Archive {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ArchiveField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ArchiveField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Archive {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ArchiveField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ArchiveField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Archive {
+    const FLAGS_: &'static [&'static str] =
+        &["--color", "--help", "--jobs", "--output", "--verbose", "-c", "-h", "-j", "-o", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            color: Vec<bool>,
+            jobs: Vec<u32>,
+            output: Vec<PathBuf>,
+            files: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, _) if flag_.starts_with("-h") => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 4 | 9) => l_.verbose.push(()),
+                    (0, _) if flag_.starts_with("-v") => {
+                        l_.verbose.push(());
+                        p_.push_back(Ok(format!("-{}", &flag_["-v".len()..])));
+                    }
+                    (0, 0 | 5) => l_.color.push(true),
+                    (0, _) if flag_.starts_with("--color=") => {
+                        let value_: std::ffi::OsString = flag_["--color=".len()..].into();
+                        l_.color.push(p_.value_bool("--color", value_)?);
+                    }
+                    (0, _) if flag_.starts_with("-c") => {
+                        l_.color.push(true);
+                        p_.push_back(Ok(format!("-{}", &flag_["-c".len()..])));
+                    }
+                    (0, 2 | 7) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, _) if flag_.starts_with("--jobs=") => {
+                        let value_: std::ffi::OsString = flag_["--jobs=".len()..].into();
+                        l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+                    }
+                    (0, _) if flag_.starts_with("-j") => {
+                        let value_: std::ffi::OsString = flag_["-j".len()..].into();
+                        l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+                    }
+                    (0, 3 | 8) => l_.output.push(p_.next_value(&flag_)?.into()),
+                    (0, _) if flag_.starts_with("--output=") => {
+                        let value_: std::ffi::OsString = flag_["--output=".len()..].into();
+                        l_.output.push(value_.into());
+                    }
+                    (0, _) if flag_.starts_with("-o") => {
+                        let value_: std::ffi::OsString = flag_["-o".len()..].into();
+                        l_.output.push(value_.into());
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (false, buf_) = &mut l_.files {
+                            buf_.push(arg_.into());
+                            p_.end_of_flags();
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.color.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.jobs.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.output.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.files.1.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(0, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if l_.color.is_empty() {
+            let values_ = p_.fallback(1, &[], "color");
+            for value_ in values_ {
+                l_.color.push(p_.value_bool("--color", value_)?);
+            }
+        }
+        if l_.jobs.is_empty() {
+            let values_ = p_.fallback(2, &[], "jobs");
+            for value_ in values_ {
+                l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+            }
+        }
+        if l_.output.is_empty() {
+            let values_ = p_.fallback(3, &[], "output");
+            for value_ in values_ {
+                l_.output.push(value_.into());
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--output", l_.output.len())?;
+        Ok(Archive {
+            verbose: l_.verbose.len() as u32,
+            color: l_.color.pop(),
+            jobs: l_.jobs.pop(),
+            output: l_.output.pop(),
+            files: std::mem::take(&mut l_.files.1),
+        })
+    }
+}
+
+impl Archive {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            color: Vec<bool>,
+            jobs: Vec<u32>,
+            output: Vec<PathBuf>,
+            files: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, _) if flag_.starts_with("-h") => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 4 | 9) => l_.verbose.push(()),
+                    (0, _) if flag_.starts_with("-v") => {
+                        l_.verbose.push(());
+                        p_.push_back(Ok(format!("-{}", &flag_["-v".len()..])));
+                    }
+                    (0, 0 | 5) => l_.color.push(true),
+                    (0, _) if flag_.starts_with("--color=") => {
+                        let value_: std::ffi::OsString = flag_["--color=".len()..].into();
+                        l_.color.push(p_.value_bool("--color", value_)?);
+                    }
+                    (0, _) if flag_.starts_with("-c") => {
+                        l_.color.push(true);
+                        p_.push_back(Ok(format!("-{}", &flag_["-c".len()..])));
+                    }
+                    (0, 2 | 7) => l_.jobs.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, _) if flag_.starts_with("--jobs=") => {
+                        let value_: std::ffi::OsString = flag_["--jobs=".len()..].into();
+                        l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+                    }
+                    (0, _) if flag_.starts_with("-j") => {
+                        let value_: std::ffi::OsString = flag_["-j".len()..].into();
+                        l_.jobs.push(p_.value_from_str::<u32>("--jobs", value_)?);
+                    }
+                    (0, 3 | 8) => l_.output.push(p_.next_value(&flag_)?.into()),
+                    (0, _) if flag_.starts_with("--output=") => {
+                        let value_: std::ffi::OsString = flag_["--output=".len()..].into();
+                        l_.output.push(value_.into());
+                    }
+                    (0, _) if flag_.starts_with("-o") => {
+                        let value_: std::ffi::OsString = flag_["-o".len()..].into();
+                        l_.output.push(value_.into());
+                    }
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (false, buf_) = &mut l_.files {
+                            buf_.push(arg_.into());
+                            p_.end_of_flags();
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--color", l_.color.len())?;
+        p_.check_optional("--jobs", l_.jobs.len())?;
+        p_.check_optional("--output", l_.output.len())?;
+        self.verbose += l_.verbose.len() as u32;
+        if let Some(it) = l_.color.pop() {
+            self.color = Some(it);
+        }
+        if let Some(it) = l_.jobs.pop() {
+            self.jobs = Some(it);
+        }
+        if let Some(it) = l_.output.pop() {
+            self.output = Some(it);
+        }
+        self.files.extend(std::mem::take(&mut l_.files.1));
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl Archive {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["archive"]
+    }
+}
+
+impl xflags::rt::Mount for Archive {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Archive {
+    const USAGE_: &'static str =
+        "Usage: archive [files]... [-v]... [--color[=<value>]] [-j <n>] [-o <path>] [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[files]...", "")] },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-v, --verbose", ""),
+                    ("-c, --color[=<value>]", ""),
+                    ("-j, --jobs <n>", ""),
+                    ("-o, --output <path>", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"archive\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"files\",\"arity\":\"repeated\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"color\",\"short\":\"c\",\"arity\":\"optional\",\"value\":{\"name\":\"value\",\"type\":\"bool\"},\"doc\":null},{\"name\":\"jobs\",\"short\":\"j\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"output\",\"short\":\"o\",\"arity\":\"optional\",\"value\":{\"name\":\"path\",\"type\":\"PathBuf\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
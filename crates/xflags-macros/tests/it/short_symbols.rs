@@ -0,0 +1,380 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct List {
+    pub paths: Option<PathBuf>,
+
+    pub one_per_line: bool,
+    pub extended: bool,
+    pub columns: Option<u32>,
+    pub percent: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListField {
+    OnePerLine,
+    Extended,
+    Columns,
+    Percent,
+    Paths,
+}
+
+impl From<ListField> for usize {
+    fn from(field: ListField) -> usize {
+        match field {
+            ListField::OnePerLine => 0,
+            ListField::Extended => 1,
+            ListField::Columns => 2,
+            ListField::Percent => 3,
+            ListField::Paths => 4,
+        }
+    }
+}
+
+impl List {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ListField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ListField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl List {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ListField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ListField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl List {
+    const FLAGS_: &'static [&'static str] = &[
+        "-#",
+        "-%",
+        "--columns",
+        "--extended",
+        "--help",
+        "--one-per-line",
+        "--percent",
+        "-1",
+        "-@",
+        "-h",
+    ];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            one_per_line: Vec<()>,
+            extended: Vec<()>,
+            columns: Vec<u32>,
+            percent: Vec<()>,
+            paths: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 4 | 9) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 5 | 7) => l_.one_per_line.push(()),
+                    (0, 3 | 8) => l_.extended.push(()),
+                    (0, 2 | 0) => l_.columns.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, 6 | 1) => l_.percent.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.paths {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.one_per_line.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.extended.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.columns.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.percent.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.paths.1.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if l_.one_per_line.is_empty() {
+            let values_ = p_.fallback(0, &[], "one-per-line");
+            for _ in 0..p_.switch_count("--one-per-line", values_, true)? {
+                l_.one_per_line.push(());
+            }
+        }
+        if l_.extended.is_empty() {
+            let values_ = p_.fallback(1, &[], "extended");
+            for _ in 0..p_.switch_count("--extended", values_, true)? {
+                l_.extended.push(());
+            }
+        }
+        if l_.columns.is_empty() {
+            let values_ = p_.fallback(2, &[], "columns");
+            for value_ in values_ {
+                l_.columns.push(p_.value_from_str::<u32>("--columns", value_)?);
+            }
+        }
+        if l_.percent.is_empty() {
+            let values_ = p_.fallback(3, &[], "percent");
+            for _ in 0..p_.switch_count("--percent", values_, true)? {
+                l_.percent.push(());
+            }
+        }
+        p_.check_optional("--one-per-line", l_.one_per_line.len())?;
+        p_.check_optional("--extended", l_.extended.len())?;
+        p_.check_optional("--columns", l_.columns.len())?;
+        p_.check_optional("--percent", l_.percent.len())?;
+        p_.check_optional("paths", l_.paths.1.len())?;
+        Ok(List {
+            one_per_line: !l_.one_per_line.is_empty(),
+            extended: !l_.extended.is_empty(),
+            columns: l_.columns.pop(),
+            percent: !l_.percent.is_empty(),
+            paths: l_.paths.1.pop(),
+        })
+    }
+}
+
+impl List {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            one_per_line: Vec<()>,
+            extended: Vec<()>,
+            columns: Vec<u32>,
+            percent: Vec<()>,
+            paths: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 4 | 9) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 5 | 7) => l_.one_per_line.push(()),
+                    (0, 3 | 8) => l_.extended.push(()),
+                    (0, 2 | 0) => l_.columns.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, 6 | 1) => l_.percent.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.paths {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--one-per-line", l_.one_per_line.len())?;
+        p_.check_optional("--extended", l_.extended.len())?;
+        p_.check_optional("--columns", l_.columns.len())?;
+        p_.check_optional("--percent", l_.percent.len())?;
+        p_.check_optional("paths", l_.paths.1.len())?;
+        if !l_.one_per_line.is_empty() {
+            self.one_per_line = true;
+        }
+        if !l_.extended.is_empty() {
+            self.extended = true;
+        }
+        if let Some(it) = l_.columns.pop() {
+            self.columns = Some(it);
+        }
+        if !l_.percent.is_empty() {
+            self.percent = true;
+        }
+        if let Some(it) = l_.paths.1.pop() {
+            self.paths = Some(it);
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        0
+    }
+}
+
+impl List {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["list"]
+    }
+}
+
+impl xflags::rt::Mount for List {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl List {
+    const USAGE_: &'static str = "Usage: list [paths] [-1] [-@] [-# <n>] [-%] [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("[paths]", "")] },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-1, --one-per-line", "One entry per line."),
+                    ("-@, --extended", "Show extended attributes."),
+                    ("-#, --columns <n>", ""),
+                    ("-%, --percent", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"list\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"paths\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"one-per-line\",\"short\":\"1\",\"arity\":\"optional\",\"value\":null,\"doc\":\"One entry per line.\"},{\"name\":\"extended\",\"short\":\"@\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Show extended attributes.\"},{\"name\":\"columns\",\"short\":\"#\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"percent\",\"short\":\"%\",\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
+}
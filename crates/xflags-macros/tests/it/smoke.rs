@@ -13,6 +13,31 @@ pub struct RustAnalyzer {
     pub emoji: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RustAnalyzerField {
+    LogFile,
+    Verbose,
+    Number,
+    Data,
+    Emoji,
+    Workspace,
+    Jobs,
+}
+
+impl From<RustAnalyzerField> for usize {
+    fn from(field: RustAnalyzerField) -> usize {
+        match field {
+            RustAnalyzerField::LogFile => 0,
+            RustAnalyzerField::Verbose => 1,
+            RustAnalyzerField::Number => 2,
+            RustAnalyzerField::Data => 3,
+            RustAnalyzerField::Emoji => 4,
+            RustAnalyzerField::Workspace => 5,
+            RustAnalyzerField::Jobs => 6,
+        }
+    }
+}
+
 impl RustAnalyzer {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -28,6 +53,72 @@ impl RustAnalyzer {
     pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         Self::from_vec_(args)
     }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
 }
 
 impl RustAnalyzer {
@@ -35,46 +126,86 @@ impl RustAnalyzer {
         Self::from_env_().unwrap_or_else(|err| err.exit())
     }
     fn from_env_() -> xflags::Result<Self> {
-        let mut p = xflags::rt::Parser::new_from_env();
+        let mut p = xflags::rt::Parser::new_from_env()?;
         Self::parse_(&mut p)
     }
     fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         let mut p = xflags::rt::Parser::new(args);
         Self::parse_(&mut p)
     }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
 }
 
 impl RustAnalyzer {
+    const FLAGS_: &'static [&'static str] =
+        &["--data", "--emoji", "--help", "--log-file", "--number", "--verbose", "-h", "-n", "-v"];
     fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
         #![allow(non_snake_case, unused_mut)]
-        let mut log_file = Vec::new();
-        let mut verbose = Vec::new();
-        let mut number = Vec::new();
-        let mut data = Vec::new();
-        let mut emoji = Vec::new();
-        let mut workspace = (false, Vec::new());
-        let mut jobs = (false, Vec::new());
+        #[derive(Default)]
+        struct Locals_ {
+            log_file: Vec<PathBuf>,
+            verbose: Vec<()>,
+            number: Vec<u32>,
+            data: Vec<OsString>,
+            emoji: Vec<()>,
+            workspace: (bool, Vec<PathBuf>),
+            jobs: (bool, Vec<u32>),
+        }
+        let mut l_ = Locals_::default();
 
-        let mut state_ = 0u8;
         while let Some(arg_) = p_.pop_flag() {
             match arg_ {
-                Ok(flag_) => match (state_, flag_.as_str()) {
-                    (0, "--help" | "-h") => return Err(p_.help(Self::HELP_)),
-                    (0, "--log-file") => log_file.push(p_.next_value(&flag_)?.into()),
-                    (0, "--verbose" | "-v") => verbose.push(()),
-                    (0, "--number" | "-n") => number.push(p_.next_value_from_str::<u32>(&flag_)?),
-                    (0, "--data") => data.push(p_.next_value(&flag_)?.into()),
-                    (0, "--emoji") => emoji.push(()),
-                    _ => return Err(p_.unexpected_flag(&flag_)),
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 3) => l_.log_file.push(p_.next_value(&flag_)?.into()),
+                    (0, 5 | 8) => l_.verbose.push(()),
+                    (0, 4 | 7) => l_.number.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, 0) => l_.data.push(p_.next_value(&flag_)?),
+                    (0, 1) => l_.emoji.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
                 },
-                Err(arg_) => match (state_, arg_.to_str().unwrap_or("")) {
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
                     (0, _) => {
-                        if let (done_ @ false, buf_) = &mut workspace {
+                        if let (done_ @ false, buf_) = &mut l_.workspace {
                             buf_.push(arg_.into());
                             *done_ = true;
                             continue;
                         }
-                        if let (done_ @ false, buf_) = &mut jobs {
+                        if let (done_ @ false, buf_) = &mut l_.jobs {
                             buf_.push(p_.value_from_str::<u32>("jobs", arg_)?);
                             *done_ = true;
                             continue;
@@ -85,34 +216,197 @@ impl RustAnalyzer {
                 },
             }
         }
+        if !l_.log_file.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.number.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.data.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.emoji.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if !l_.workspace.1.is_empty() {
+            p_.set_source(5, xflags::ValueSource::CommandLine);
+        }
+        if !l_.jobs.1.is_empty() {
+            p_.set_source(6, xflags::ValueSource::CommandLine);
+        }
+        if l_.log_file.is_empty() {
+            let values_ = p_.fallback(0, &[], "log-file");
+            for value_ in values_ {
+                l_.log_file.push(value_.into());
+            }
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(1, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if l_.number.is_empty() {
+            let values_ = p_.fallback(2, &[], "number");
+            for value_ in values_ {
+                l_.number.push(p_.value_from_str::<u32>("--number", value_)?);
+            }
+        }
+        if l_.data.is_empty() {
+            let values_ = p_.fallback(3, &[], "data");
+            for value_ in values_ {
+                l_.data.push(value_);
+            }
+        }
+        if l_.emoji.is_empty() {
+            let values_ = p_.fallback(4, &[], "emoji");
+            for _ in 0..p_.switch_count("--emoji", values_, true)? {
+                l_.emoji.push(());
+            }
+        }
+        p_.check_optional("--log-file", l_.log_file.len())?;
+        p_.check_required("--number", l_.number.len())?;
+        p_.check_optional("--emoji", l_.emoji.len())?;
+        p_.check_required("workspace", l_.workspace.1.len())?;
+        p_.check_optional("jobs", l_.jobs.1.len())?;
         Ok(RustAnalyzer {
-            log_file: p_.optional("--log-file", log_file)?,
-            verbose: verbose.len() as u32,
-            number: p_.required("--number", number)?,
-            data: data,
-            emoji: p_.optional("--emoji", emoji)?.is_some(),
-            workspace: p_.required("workspace", workspace.1)?,
-            jobs: p_.optional("jobs", jobs.1)?,
+            log_file: l_.log_file.pop(),
+            verbose: l_.verbose.len() as u32,
+            number: l_.number.pop().unwrap(),
+            data: std::mem::take(&mut l_.data),
+            emoji: !l_.emoji.is_empty(),
+            workspace: l_.workspace.1.pop().unwrap(),
+            jobs: l_.jobs.1.pop(),
         })
     }
 }
+
 impl RustAnalyzer {
-    const HELP_: &'static str = "Usage: rust-analyzer <workspace> [jobs] [--log-file <path>] [-v]... -n <n> [--data <value>]... [--emoji] [-h]
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            log_file: Vec<PathBuf>,
+            verbose: Vec<()>,
+            number: Vec<u32>,
+            data: Vec<OsString>,
+            emoji: Vec<()>,
+            workspace: (bool, Vec<PathBuf>),
+            jobs: (bool, Vec<u32>),
+        }
+        let mut l_ = Locals_::default();
 
-LSP server for rust.
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 6) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0, 3) => l_.log_file.push(p_.next_value(&flag_)?.into()),
+                    (0, 5 | 8) => l_.verbose.push(()),
+                    (0, 4 | 7) => l_.number.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0, 0) => l_.data.push(p_.next_value(&flag_)?),
+                    (0, 1) => l_.emoji.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.workspace {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        if let (done_ @ false, buf_) = &mut l_.jobs {
+                            buf_.push(p_.value_from_str::<u32>("jobs", arg_)?);
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--log-file", l_.log_file.len())?;
+        p_.check_optional("--number", l_.number.len())?;
+        p_.check_optional("--emoji", l_.emoji.len())?;
+        p_.check_optional("workspace", l_.workspace.1.len())?;
+        p_.check_optional("jobs", l_.jobs.1.len())?;
+        if let Some(it) = l_.log_file.pop() {
+            self.log_file = Some(it);
+        }
+        self.verbose += l_.verbose.len() as u32;
+        if let Some(it) = l_.number.pop() {
+            self.number = it;
+        }
+        self.data.extend(std::mem::take(&mut l_.data));
+        if !l_.emoji.is_empty() {
+            self.emoji = true;
+        }
+        if let Some(it) = l_.workspace.1.pop() {
+            self.workspace = it;
+        }
+        if let Some(it) = l_.jobs.1.pop() {
+            self.jobs = Some(it);
+        }
+        Ok(())
+    }
 
-Arguments:
-  <workspace>          
-  [jobs]               Number of concurrent jobs.
+    fn state_(&self) -> u8 {
+        0
+    }
+}
 
-Options:
-  --log-file <path>    Path to log file. By default, logs go to stderr.
-  -v, --verbose        
-  -n, --number <n>     
-  --data <value>       
-  --emoji              
-  -h, --help           Prints help
+impl RustAnalyzer {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        vec!["rust-analyzer"]
+    }
+}
 
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
+impl xflags::rt::Mount for RustAnalyzer {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl RustAnalyzer {
+    const USAGE_: &'static str = "Usage: rust-analyzer <workspace> [jobs] [--log-file <path>] [-v]... -n <n> [--data <value>]... [--emoji] [-h]";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some("LSP server for rust."),
+        &[
+            xflags::HelpSection {
+                title: "Arguments",
+                entries: &[("<workspace>", ""), ("[jobs]", "Number of concurrent jobs.")],
+            },
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("--log-file <path>", "Path to log file. By default, logs go to stderr."),
+                    ("-v, --verbose", ""),
+                    ("-n, --number <n>", ""),
+                    ("--data <value>", ""),
+                    ("--emoji", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"rust-analyzer\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"LSP server for rust.\",\"default\":false,\"args\":[{\"name\":\"workspace\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null},{\"name\":\"jobs\",\"arity\":\"optional\",\"non_empty\":false,\"type\":\"u32\",\"doc\":\"Number of concurrent jobs.\"}],\"flags\":[{\"name\":\"log-file\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"path\",\"type\":\"PathBuf\"},\"doc\":\"Path to log file. By default, logs go to stderr.\"},{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"number\",\"short\":\"n\",\"arity\":\"required\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"data\",\"short\":null,\"arity\":\"repeated\",\"value\":{\"name\":\"value\",\"type\":\"OsString\"},\"doc\":null},{\"name\":\"emoji\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[]}}";
 }
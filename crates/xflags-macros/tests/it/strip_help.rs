@@ -0,0 +1,322 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Tiny {
+    pub count: Option<u32>,
+    pub subcommand: TinyCmd,
+}
+
+#[derive(Debug)]
+pub enum TinyCmd {
+    Run(Run),
+}
+
+#[derive(Debug)]
+pub struct Run;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TinyField {
+    Count,
+}
+
+impl From<TinyField> for usize {
+    fn from(field: TinyField) -> usize {
+        match field {
+            TinyField::Count => 0,
+        }
+    }
+}
+
+impl Tiny {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<TinyField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<TinyField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Run {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tiny::HELP_RUN__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tiny::USAGE_RUN__
+    }
+}
+
+impl Tiny {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<TinyField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<TinyField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Tiny {
+    const FLAGS_: &'static [&'static str] = &["--count", "--help", "-h", "-n"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            count: Vec<u32>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0 | 3) => l_.count.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (1, 1 | 2) => return Err(p_.help(&Self::HELP_RUN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"run") => *state_ = 1,
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.count.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if l_.count.is_empty() {
+            let values_ = p_.fallback(0, &[], "count");
+            for value_ in values_ {
+                l_.count.push(p_.value_from_str::<u32>("--count", value_)?);
+            }
+        }
+        p_.check_optional("--count", l_.count.len())?;
+        Ok(Tiny {
+            count: l_.count.pop(),
+            subcommand: match *state_ {
+                1 => TinyCmd::Run(Run {}),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Tiny {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            count: Vec<u32>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 2) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 0 | 3) => l_.count.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (1, 1 | 2) => return Err(p_.help(&Self::HELP_RUN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"run") => *state_ = 1,
+                    (0, b"generate") => {
+                        let what_ = p_.next_value("generate")?;
+                        return Err(p_.generated(what_, Self::GENERATED_));
+                    }
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--count", l_.count.len())?;
+        if let Some(it) = l_.count.pop() {
+            self.count = Some(it);
+        }
+        match &mut self.subcommand {
+            TinyCmd::Run(_) => {}
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            TinyCmd::Run(_) => 1,
+        }
+    }
+}
+
+impl Tiny {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["tiny"];
+        match &self.subcommand {
+            TinyCmd::Run(_) => {
+                res_.push("run");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Tiny {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Tiny {
+    const USAGE_RUN__: &'static str = "Usage: run";
+    const HELP_RUN__: xflags::Help = xflags::Help::new(Self::USAGE_RUN__, None, &[]);
+    const USAGE_: &'static str = "Usage: tiny [-n <n>] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(Self::USAGE_, None, &[]);
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_RUN__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"tiny\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"count\",\"short\":\"n\",\"arity\":\"optional\",\"value\":{\"name\":\"n\",\"type\":\"u32\"},\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[{\"name\":\"run\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+    const GENERATED_: &'static [(&'static str, &'static str)] = &[
+("bash", "_tiny() {\n    local cur=\"${COMP_WORDS[COMP_CWORD]}\" state=\"tiny\" i\n    for ((i = 1; i < COMP_CWORD; i++)); do\n        case \"$state,${COMP_WORDS[i]}\" in\n            \"tiny,run\") state=\"tiny__run\" ;;\n            \"tiny,generate\") state=\"tiny__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"tiny\") COMPREPLY=($(compgen -W \"-n --count -h --help run help generate\" -- \"$cur\")) ;;\n        \"tiny__run\") COMPREPLY=($(compgen -W \"help\" -- \"$cur\")) ;;\n        \"tiny__generate_\") COMPREPLY=($(compgen -W \"bash zsh fish man\" -- \"$cur\")) ;;\n    esac\n}\ncomplete -o default -F _tiny tiny\n"),
+("zsh", "#compdef tiny\n\n_tiny() {\n    local state=\"tiny\" i\n    for ((i = 2; i < CURRENT; i++)); do\n        case \"$state,${words[i]}\" in\n            \"tiny,run\") state=\"tiny__run\" ;;\n            \"tiny,generate\") state=\"tiny__generate_\" ;;\n        esac\n    done\n    case \"$state\" in\n        \"tiny\") compadd -- -n --count -h --help run help generate ;;\n        \"tiny__run\") compadd -- help ;;\n        \"tiny__generate_\") compadd -- bash zsh fish man; return ;;\n    esac\n    _files\n}\n\n_tiny \"$@\"\n"),
+("fish", "complete -c tiny -s n -l count -r\ncomplete -c tiny -s h -l help\ncomplete -c tiny -f -n '__fish_use_subcommand' -a run\ncomplete -c tiny -f -n '__fish_use_subcommand' -a generate -d 'Print shell completions or a man page'\ncomplete -c tiny -f -n '__fish_seen_subcommand_from generate' -a 'bash zsh fish man'\n"),
+("man", ".TH TINY 1\n.SH NAME\ntiny\n.SH SYNOPSIS\n\\fBtiny\\fR <COMMAND>\n.SH OPTIONS\n.TP\n\\fB\\-n\\fR, \\fB\\-\\-count\\fR \\fIn\\fR\n.TP\n\\fB\\-h\\fR, \\fB\\-\\-help\\fR\n.SH COMMANDS\n.TP\n\\fBrun\\fR\n.SH TINY RUN\n\\fBtiny run\\fR\n"),
+];
+}
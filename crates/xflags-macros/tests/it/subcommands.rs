@@ -40,6 +40,28 @@ pub struct AnalysisStats {
     pub parallel: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum RustAnalyzerField {
+    Verbose,
+    Server_Dir,
+    Server_Launch_Log,
+    AnalysisStats_Parallel,
+    AnalysisStats_Path,
+}
+
+impl From<RustAnalyzerField> for usize {
+    fn from(field: RustAnalyzerField) -> usize {
+        match field {
+            RustAnalyzerField::Verbose => 0,
+            RustAnalyzerField::Server_Dir => 1,
+            RustAnalyzerField::Server_Launch_Log => 2,
+            RustAnalyzerField::AnalysisStats_Parallel => 3,
+            RustAnalyzerField::AnalysisStats_Path => 4,
+        }
+    }
+}
+
 impl RustAnalyzer {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -55,6 +77,120 @@ impl RustAnalyzer {
     pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         Self::from_vec_(args)
     }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Server {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &RustAnalyzer::HELP_SERVER__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        RustAnalyzer::USAGE_SERVER__
+    }
+}
+
+impl Launch {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &RustAnalyzer::HELP_SERVER__LAUNCH__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        RustAnalyzer::USAGE_SERVER__LAUNCH__
+    }
+}
+
+impl Watch {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &RustAnalyzer::HELP_SERVER__WATCH__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        RustAnalyzer::USAGE_SERVER__WATCH__
+    }
+}
+
+impl AnalysisStats {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &RustAnalyzer::HELP_ANALYSIS_STATS__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        RustAnalyzer::USAGE_ANALYSIS_STATS__
+    }
 }
 
 impl RustAnalyzer {
@@ -62,60 +198,100 @@ impl RustAnalyzer {
         Self::from_env_().unwrap_or_else(|err| err.exit())
     }
     fn from_env_() -> xflags::Result<Self> {
-        let mut p = xflags::rt::Parser::new_from_env();
+        let mut p = xflags::rt::Parser::new_from_env()?;
         Self::parse_(&mut p)
     }
     fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
         let mut p = xflags::rt::Parser::new(args);
         Self::parse_(&mut p)
     }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
 }
 
 impl RustAnalyzer {
+    const FLAGS_: &'static [&'static str] =
+        &["--dir", "--help", "--log", "--parallel", "--verbose", "-h", "-v"];
     fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
         #![allow(non_snake_case, unused_mut)]
-        let mut verbose = Vec::new();
-        let mut server__dir = Vec::new();
-        let mut server__launch__log = Vec::new();
-        let mut analysis_stats__parallel = Vec::new();
-        let mut analysis_stats__path = (false, Vec::new());
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            server__dir: Vec<PathBuf>,
+            server__launch__log: Vec<()>,
+            analysis_stats__parallel: Vec<()>,
+            analysis_stats__path: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
 
-        let mut state_ = 0u8;
         while let Some(arg_) = p_.pop_flag() {
             match arg_ {
-                Ok(flag_) => match (state_, flag_.as_str()) {
-                    (0, "--help" | "-h") => return Err(p_.help(Self::HELP_)),
-                    (0 | 1 | 2 | 3 | 4, "--verbose" | "-v") => verbose.push(()),
-                    (1, "--help" | "-h") => return Err(p_.help(Self::HELP_SERVER__)),
-                    (1 | 2 | 3, "--dir") => server__dir.push(p_.next_value(&flag_)?.into()),
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=4, 4 | 6) => l_.verbose.push(()),
+                    (1, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__)),
+                    (1..=3, 0) => l_.server__dir.push(p_.next_value(&flag_)?.into()),
                     (1, _) => {
                         p_.push_back(Ok(flag_));
-                        state_ = 2;
+                        *state_ = 2;
                     }
-                    (2, "--help" | "-h") => return Err(p_.help(Self::HELP_SERVER__LAUNCH__)),
-                    (2, "--log") => server__launch__log.push(()),
-                    (3, "--help" | "-h") => return Err(p_.help(Self::HELP_SERVER__WATCH__)),
-                    (4, "--help" | "-h") => return Err(p_.help(Self::HELP_ANALYSIS_STATS__)),
-                    (4, "--parallel") => analysis_stats__parallel.push(()),
-                    _ => return Err(p_.unexpected_flag(&flag_)),
+                    (2, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__LAUNCH__)),
+                    (2, 2) => l_.server__launch__log.push(()),
+                    (3, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__WATCH__)),
+                    (4, 1 | 5) => return Err(p_.help(&Self::HELP_ANALYSIS_STATS__)),
+                    (4, 3) => l_.analysis_stats__parallel.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
                 },
-                Err(arg_) => match (state_, arg_.to_str().unwrap_or("")) {
-                    (0, "server") => state_ = 1,
-                    (0, "analysis-stats") => state_ = 4,
-                    (0, "help") => return Err(p_.help(Self::HELP_)),
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"server") => *state_ = 1,
+                    (0, b"analysis-stats") => *state_ = 4,
+                    (0, b"help") => p_.defer_help(),
                     (0, _) => {
                         return Err(p_.unexpected_arg(arg_));
                     }
-                    (1, "watch") => state_ = 3,
-                    (1, "help") => return Err(p_.help(Self::HELP_SERVER__)),
+                    (1, b"watch") => *state_ = 3,
+                    (1, b"help") => p_.defer_help(),
                     (1, _) => {
                         p_.push_back(Err(arg_));
-                        state_ = 2;
+                        *state_ = 2;
                     }
-                    (2, "help") => return Err(p_.help(Self::HELP_SERVER__LAUNCH__)),
-                    (3, "help") => return Err(p_.help(Self::HELP_SERVER__WATCH__)),
+                    (2, b"help") => p_.defer_help(),
+                    (3, b"help") => p_.defer_help(),
                     (4, _) => {
-                        if let (done_ @ false, buf_) = &mut analysis_stats__path {
+                        if let (done_ @ false, buf_) = &mut l_.analysis_stats__path {
                             buf_.push(arg_.into());
                             *done_ = true;
                             continue;
@@ -126,63 +302,279 @@ impl RustAnalyzer {
                 },
             }
         }
-        state_ = if state_ == 1 { 2 } else { state_ };
+        *state_ = if *state_ == 1 { 2 } else { *state_ };
+        if !l_.verbose.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.server__dir.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.server__launch__log.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.analysis_stats__parallel.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if !l_.analysis_stats__path.1.is_empty() {
+            p_.set_source(4, xflags::ValueSource::CommandLine);
+        }
+        if l_.verbose.is_empty() {
+            let values_ = p_.fallback(0, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if matches!(*state_, | 1..=3) && l_.server__dir.is_empty() {
+            let values_ = p_.fallback(1, &["server"], "dir");
+            for value_ in values_ {
+                l_.server__dir.push(value_.into());
+            }
+        }
+        if matches!(*state_, | 2) && l_.server__launch__log.is_empty() {
+            let values_ = p_.fallback(2, &["server", "launch"], "log");
+            for _ in 0..p_.switch_count("--log", values_, true)? {
+                l_.server__launch__log.push(());
+            }
+        }
+        if matches!(*state_, | 4) && l_.analysis_stats__parallel.is_empty() {
+            let values_ = p_.fallback(3, &["analysis-stats"], "parallel");
+            for _ in 0..p_.switch_count("--parallel", values_, true)? {
+                l_.analysis_stats__parallel.push(());
+            }
+        }
+        if matches!(*state_, | 2..=3) {
+            p_.check_optional("--dir", l_.server__dir.len())?;
+        }
+        if matches!(*state_, | 2) {
+            p_.check_optional("--log", l_.server__launch__log.len())?;
+        }
+        if matches!(*state_, | 4) {
+            p_.check_optional("--parallel", l_.analysis_stats__parallel.len())?;
+            p_.check_required("path", l_.analysis_stats__path.1.len())?;
+        }
         Ok(RustAnalyzer {
-            verbose: verbose.len() as u32,
-            subcommand: match state_ {
-                2 | 3 => RustAnalyzerCmd::Server(Server {
-                    dir: p_.optional("--dir", server__dir)?,
-                    subcommand: match state_ {
-                        2 => ServerCmd::Launch(Launch {
-                            log: p_.optional("--log", server__launch__log)?.is_some(),
-                        }),
+            verbose: l_.verbose.len() as u32,
+            subcommand: match *state_ {
+                2..=3 => RustAnalyzerCmd::Server(Server {
+                    dir: l_.server__dir.pop(),
+                    subcommand: match *state_ {
+                        2 => ServerCmd::Launch(Launch { log: !l_.server__launch__log.is_empty() }),
                         3 => ServerCmd::Watch(Watch {}),
                         _ => return Err(p_.subcommand_required()),
                     },
                 }),
                 4 => RustAnalyzerCmd::AnalysisStats(AnalysisStats {
-                    parallel: p_.optional("--parallel", analysis_stats__parallel)?.is_some(),
-                    path: p_.required("path", analysis_stats__path.1)?,
+                    parallel: !l_.analysis_stats__parallel.is_empty(),
+                    path: l_.analysis_stats__path.1.pop().unwrap(),
                 }),
                 _ => return Err(p_.subcommand_required()),
             },
         })
     }
 }
+
 impl RustAnalyzer {
-    const HELP_SERVER__LAUNCH__: &'static str = "Usage: launch [--log]
-Options:
-  --log                
-
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_SERVER__WATCH__: &'static str = "Usage: watch
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_SERVER__: &'static str = "Usage: server [--dir <path>] [--log] <COMMAND>
-Options:
-  --dir <path>         
-  --log                
-
-Commands:
-  watch                
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_ANALYSIS_STATS__: &'static str = "Usage: analysis-stats <path> [--parallel]
-Arguments:
-  <path>               
-
-Options:
-  --parallel           
-
-Commands:
-  help                 Print this message or the help of the given subcommand(s)";
-    const HELP_: &'static str = "Usage: rust-analyzer [-v]... [-h] <COMMAND>
-Options:
-  -v, --verbose        
-  -h, --help           Prints help
-
-Commands:
-  server               
-  analysis-stats       
-  help                 Print this message or the help of the given subcommand(s)";
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            server__dir: Vec<PathBuf>,
+            server__launch__log: Vec<()>,
+            analysis_stats__parallel: Vec<()>,
+            analysis_stats__path: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=4, 4 | 6) => l_.verbose.push(()),
+                    (1, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__)),
+                    (1..=3, 0) => l_.server__dir.push(p_.next_value(&flag_)?.into()),
+                    (1, _) => {
+                        p_.push_back(Ok(flag_));
+                        *state_ = 2;
+                    }
+                    (2, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__LAUNCH__)),
+                    (2, 2) => l_.server__launch__log.push(()),
+                    (3, 1 | 5) => return Err(p_.help(&Self::HELP_SERVER__WATCH__)),
+                    (4, 1 | 5) => return Err(p_.help(&Self::HELP_ANALYSIS_STATS__)),
+                    (4, 3) => l_.analysis_stats__parallel.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"server") => *state_ = 1,
+                    (0, b"analysis-stats") => *state_ = 4,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"watch") => *state_ = 3,
+                    (1, b"help") => p_.defer_help(),
+                    (1, _) => {
+                        p_.push_back(Err(arg_));
+                        *state_ = 2;
+                    }
+                    (2, b"help") => p_.defer_help(),
+                    (3, b"help") => p_.defer_help(),
+                    (4, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.analysis_stats__path {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--dir", l_.server__dir.len())?;
+        p_.check_optional("--log", l_.server__launch__log.len())?;
+        p_.check_optional("--parallel", l_.analysis_stats__parallel.len())?;
+        p_.check_optional("path", l_.analysis_stats__path.1.len())?;
+        self.verbose += l_.verbose.len() as u32;
+        match &mut self.subcommand {
+            RustAnalyzerCmd::Server(cmd_) => {
+                if let Some(it) = l_.server__dir.pop() {
+                    cmd_.dir = Some(it);
+                }
+                match &mut cmd_.subcommand {
+                    ServerCmd::Launch(cmd_) => {
+                        if !l_.server__launch__log.is_empty() {
+                            cmd_.log = true;
+                        }
+                    }
+                    ServerCmd::Watch(_) => {}
+                }
+            }
+            RustAnalyzerCmd::AnalysisStats(cmd_) => {
+                if !l_.analysis_stats__parallel.is_empty() {
+                    cmd_.parallel = true;
+                }
+                if let Some(it) = l_.analysis_stats__path.1.pop() {
+                    cmd_.path = it;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            RustAnalyzerCmd::Server(cmd_) => match &cmd_.subcommand {
+                ServerCmd::Launch(_) => 2,
+                ServerCmd::Watch(_) => 3,
+            },
+            RustAnalyzerCmd::AnalysisStats(_) => 4,
+        }
+    }
+}
+
+impl RustAnalyzer {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["rust-analyzer"];
+        match &self.subcommand {
+            RustAnalyzerCmd::Server(cmd_) => {
+                res_.push("server");
+                match &cmd_.subcommand {
+                    ServerCmd::Launch(_) => {
+                        res_.push("launch");
+                    }
+                    ServerCmd::Watch(_) => {
+                        res_.push("watch");
+                    }
+                }
+            }
+            RustAnalyzerCmd::AnalysisStats(_) => {
+                res_.push("analysis-stats");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for RustAnalyzer {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl RustAnalyzer {
+    const USAGE_SERVER__LAUNCH__: &'static str = "Usage: launch [--log]";
+    const HELP_SERVER__LAUNCH__: xflags::Help = xflags::Help::new(
+        Self::USAGE_SERVER__LAUNCH__,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--log", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_SERVER__WATCH__: &'static str = "Usage: watch";
+    const HELP_SERVER__WATCH__: xflags::Help = xflags::Help::new(
+        Self::USAGE_SERVER__WATCH__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_SERVER__: &'static str = "Usage: server [--dir <path>] [--log] <COMMAND>";
+    const HELP_SERVER__: xflags::Help = xflags::Help::new(
+        Self::USAGE_SERVER__,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("--dir <path>", ""), ("--log", "")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("watch", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    const USAGE_ANALYSIS_STATS__: &'static str = "Usage: analysis-stats <path> [--parallel]";
+    const HELP_ANALYSIS_STATS__: xflags::Help = xflags::Help::new(
+        Self::USAGE_ANALYSIS_STATS__,
+        None,
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("<path>", "")] },
+            xflags::HelpSection { title: "Options", entries: &[("--parallel", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: rust-analyzer [-v]... [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[("-v, --verbose", ""), ("-h, --help", "Prints help")],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("server", ""), ("analysis-stats", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_SERVER__,
+            2 => Self::USAGE_SERVER__LAUNCH__,
+            3 => Self::USAGE_SERVER__WATCH__,
+            4 => Self::USAGE_ANALYSIS_STATS__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"rust-analyzer\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"server\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"dir\",\"short\":null,\"arity\":\"optional\",\"value\":{\"name\":\"path\",\"type\":\"PathBuf\"},\"doc\":null}],\"subcommands\":[{\"name\":\"launch\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":true,\"args\":[],\"flags\":[{\"name\":\"log\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[]},{\"name\":\"watch\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]},{\"name\":\"analysis-stats\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[{\"name\":\"path\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[{\"name\":\"parallel\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[]}]}}";
 }
@@ -0,0 +1,447 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Werkzeug {
+    pub ausgabe: Option<PathBuf>,
+    pub größe: Option<u32>,
+    pub übersprungen: u32,
+    pub subcommand: WerkzeugCmd,
+}
+
+#[derive(Debug)]
+pub enum WerkzeugCmd {
+    Übersetzen(Übersetzen),
+    Prüfen(Prüfen),
+}
+
+#[derive(Debug)]
+pub struct Übersetzen {
+    pub eingabe: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct Prüfen;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum WerkzeugField {
+    Ausgabe,
+    Größe,
+    Übersprungen,
+    Übersetzen_Eingabe,
+}
+
+impl From<WerkzeugField> for usize {
+    fn from(field: WerkzeugField) -> usize {
+        match field {
+            WerkzeugField::Ausgabe => 0,
+            WerkzeugField::Größe => 1,
+            WerkzeugField::Übersprungen => 2,
+            WerkzeugField::Übersetzen_Eingabe => 3,
+        }
+    }
+}
+
+impl Werkzeug {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<WerkzeugField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<WerkzeugField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+}
+
+impl Übersetzen {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Werkzeug::HELP_ÜBERSETZEN__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Werkzeug::USAGE_ÜBERSETZEN__
+    }
+}
+
+impl Prüfen {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Werkzeug::HELP_PRÜFEN__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Werkzeug::USAGE_PRÜFEN__
+    }
+}
+
+impl Werkzeug {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<WerkzeugField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<WerkzeugField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Werkzeug {
+    const FLAGS_: &'static [&'static str] =
+        &["--ausgabe", "--größe", "--help", "--übersprungen", "-a", "-h", "-ß", "-ü"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            ausgabe: Vec<PathBuf>,
+            größe: Vec<u32>,
+            übersprungen: Vec<()>,
+            übersetzen__eingabe: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 0 | 4) => l_.ausgabe.push(p_.next_value(&flag_)?.into()),
+                    (0..=2, 1 | 6) => l_.größe.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=2, 3 | 7) => l_.übersprungen.push(()),
+                    (1, 2 | 5) => return Err(p_.help(&Self::HELP_ÜBERSETZEN__)),
+                    (2, 2 | 5) => return Err(p_.help(&Self::HELP_PRÜFEN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"\xc3\xbcbersetzen" | b"\xc3\xbc") => *state_ = 1,
+                    (0, b"pr\xc3\xbcfen") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.übersetzen__eingabe {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.ausgabe.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.größe.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.übersprungen.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        if !l_.übersetzen__eingabe.1.is_empty() {
+            p_.set_source(3, xflags::ValueSource::CommandLine);
+        }
+        if l_.ausgabe.is_empty() {
+            let values_ = p_.fallback(0, &[], "ausgabe");
+            for value_ in values_ {
+                l_.ausgabe.push(value_.into());
+            }
+        }
+        if l_.größe.is_empty() {
+            let values_ = p_.fallback(1, &[], "größe");
+            for value_ in values_ {
+                l_.größe.push(p_.value_from_str::<u32>("--größe", value_)?);
+            }
+        }
+        if l_.übersprungen.is_empty() {
+            let values_ = p_.fallback(2, &[], "übersprungen");
+            for _ in 0..p_.switch_count("--übersprungen", values_, false)? {
+                l_.übersprungen.push(());
+            }
+        }
+        p_.check_optional("--ausgabe", l_.ausgabe.len())?;
+        p_.check_optional("--größe", l_.größe.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_required("eingabe", l_.übersetzen__eingabe.1.len())?;
+        }
+        Ok(Werkzeug {
+            ausgabe: l_.ausgabe.pop(),
+            größe: l_.größe.pop(),
+            übersprungen: l_.übersprungen.len() as u32,
+            subcommand: match *state_ {
+                1 => WerkzeugCmd::Übersetzen(Übersetzen {
+                    eingabe: l_.übersetzen__eingabe.1.pop().unwrap(),
+                }),
+                2 => WerkzeugCmd::Prüfen(Prüfen {}),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Werkzeug {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            ausgabe: Vec<PathBuf>,
+            größe: Vec<u32>,
+            übersprungen: Vec<()>,
+            übersetzen__eingabe: (bool, Vec<PathBuf>),
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 2 | 5) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=2, 0 | 4) => l_.ausgabe.push(p_.next_value(&flag_)?.into()),
+                    (0..=2, 1 | 6) => l_.größe.push(p_.next_value_from_str::<u32>(&flag_)?),
+                    (0..=2, 3 | 7) => l_.übersprungen.push(()),
+                    (1, 2 | 5) => return Err(p_.help(&Self::HELP_ÜBERSETZEN__)),
+                    (2, 2 | 5) => return Err(p_.help(&Self::HELP_PRÜFEN__)),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"\xc3\xbcbersetzen" | b"\xc3\xbc") => *state_ = 1,
+                    (0, b"pr\xc3\xbcfen") => *state_ = 2,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, _) => {
+                        if let (done_ @ false, buf_) = &mut l_.übersetzen__eingabe {
+                            buf_.push(arg_.into());
+                            *done_ = true;
+                            continue;
+                        }
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (2, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--ausgabe", l_.ausgabe.len())?;
+        p_.check_optional("--größe", l_.größe.len())?;
+        p_.check_optional("eingabe", l_.übersetzen__eingabe.1.len())?;
+        if let Some(it) = l_.ausgabe.pop() {
+            self.ausgabe = Some(it);
+        }
+        if let Some(it) = l_.größe.pop() {
+            self.größe = Some(it);
+        }
+        self.übersprungen += l_.übersprungen.len() as u32;
+        match &mut self.subcommand {
+            WerkzeugCmd::Übersetzen(cmd_) => {
+                if let Some(it) = l_.übersetzen__eingabe.1.pop() {
+                    cmd_.eingabe = it;
+                }
+            }
+            WerkzeugCmd::Prüfen(_) => {}
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            WerkzeugCmd::Übersetzen(_) => 1,
+            WerkzeugCmd::Prüfen(_) => 2,
+        }
+    }
+}
+
+impl Werkzeug {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["werkzeug"];
+        match &self.subcommand {
+            WerkzeugCmd::Übersetzen(_) => {
+                res_.push("übersetzen");
+            }
+            WerkzeugCmd::Prüfen(_) => {
+                res_.push("prüfen");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Werkzeug {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Werkzeug {
+    const USAGE_ÜBERSETZEN__: &'static str = "Usage: übersetzen <eingabe>";
+    const HELP_ÜBERSETZEN__: xflags::Help = xflags::Help::new(
+        Self::USAGE_ÜBERSETZEN__,
+        Some("Übersetzt eine Datei."),
+        &[
+            xflags::HelpSection { title: "Arguments", entries: &[("<eingabe>", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_PRÜFEN__: &'static str = "Usage: prüfen";
+    const HELP_PRÜFEN__: xflags::Help = xflags::Help::new(
+        Self::USAGE_PRÜFEN__,
+        None,
+        &[xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] }],
+    );
+    const USAGE_: &'static str = "Usage: werkzeug [-a <datei>] [-ß <größe>] [-ü]... [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        Some("Übersetzungswerkzeug."),
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-a, --ausgabe <datei>", "Ausgabedatei."),
+                    ("-ß, --größe <größe>", "Größe des Puffers."),
+                    ("-ü, --übersprungen", ""),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[
+                    ("übersetzen", "Übersetzt eine Datei."),
+                    ("prüfen", ""),
+                    xflags::rt::HELP_ENTRY,
+                ],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_ÜBERSETZEN__,
+            2 => Self::USAGE_PRÜFEN__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"werkzeug\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":\"Übersetzungswerkzeug.\",\"default\":false,\"args\":[],\"flags\":[{\"name\":\"ausgabe\",\"short\":\"a\",\"arity\":\"optional\",\"value\":{\"name\":\"datei\",\"type\":\"PathBuf\"},\"doc\":\"Ausgabedatei.\"},{\"name\":\"größe\",\"short\":\"ß\",\"arity\":\"optional\",\"value\":{\"name\":\"größe\",\"type\":\"u32\"},\"doc\":\"Größe des Puffers.\"},{\"name\":\"übersprungen\",\"short\":\"ü\",\"arity\":\"repeated\",\"value\":null,\"doc\":null},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"übersetzen\",\"aliases\":[\"ü\"],\"deprecated_aliases\":[],\"doc\":\"Übersetzt eine Datei.\",\"default\":false,\"args\":[{\"name\":\"eingabe\",\"arity\":\"required\",\"non_empty\":false,\"type\":\"PathBuf\",\"doc\":null}],\"flags\":[],\"subcommands\":[]},{\"name\":\"prüfen\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[],\"subcommands\":[]}]}}";
+}
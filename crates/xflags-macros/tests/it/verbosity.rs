@@ -0,0 +1,390 @@
+#[allow(unused)]
+use std::{ffi::OsString, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Tool {
+    pub verbosity: i32,
+    pub subcommand: ToolCmd,
+}
+
+#[derive(Debug)]
+pub enum ToolCmd {
+    Run(Run),
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ToolField {
+    Verbose,
+    Quiet,
+    Run_DryRun,
+}
+
+impl From<ToolField> for usize {
+    fn from(field: ToolField) -> usize {
+        match field {
+            ToolField::Verbose => 0,
+            ToolField::Quiet => 1,
+            ToolField::Run_DryRun => 2,
+        }
+    }
+}
+
+impl Tool {
+    #[allow(dead_code)]
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env_or_exit_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env() -> xflags::Result<Self> {
+        Self::from_env_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        Self::from_vec_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+        Self::from_slice_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        self.apply_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_env_())
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_outcome(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+        xflags::ParseOutcome::from_result(Self::from_vec_(args))
+    }
+
+    #[allow(dead_code)]
+    pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_env_with_sources_()
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_with_sources(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        Self::from_vec_with_sources_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn from_vec_lenient(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        Self::from_vec_lenient_(args)
+    }
+
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Self::HELP_
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Self::USAGE_
+    }
+
+    #[allow(dead_code)]
+    pub fn set_help_epilogue(epilogue: impl Into<String>) {
+        Self::help_epilogue_().set(epilogue.into())
+    }
+
+    #[allow(dead_code)]
+    pub fn subcommand_path(&self) -> Vec<&'static str> {
+        self.subcommand_path_()
+    }
+
+    #[allow(dead_code)]
+    pub fn spec_json() -> &'static str {
+        Self::SPEC_JSON_
+    }
+
+    #[allow(dead_code)]
+    pub fn log_level(&self) -> xflags::LogLevel {
+        xflags::LogLevel::from_verbosity(self.verbosity)
+    }
+}
+
+impl Run {
+    #[allow(dead_code)]
+    pub fn help_message() -> &'static xflags::Help {
+        &Tool::HELP_RUN__
+    }
+
+    #[allow(dead_code)]
+    pub fn usage() -> &'static str {
+        Tool::USAGE_RUN__
+    }
+}
+
+impl Tool {
+    fn from_env_or_exit_() -> Self {
+        Self::from_env_().unwrap_or_else(|err| err.exit())
+    }
+    fn from_env_() -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        Self::parse_(&mut p)
+    }
+    fn from_vec_(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new(args);
+        Self::parse_(&mut p)
+    }
+    fn from_slice_(args: &[&str]) -> xflags::Result<Self> {
+        let mut p = xflags::rt::Parser::new_from_slice(args);
+        Self::parse_(&mut p)
+    }
+    fn from_env_with_sources_() -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new_from_env()?;
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_with_sources_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, xflags::Sources<ToolField>)> {
+        let mut p = xflags::rt::Parser::new(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.sources()))
+    }
+    fn from_vec_lenient_(
+        args: Vec<std::ffi::OsString>,
+    ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+        let mut p = xflags::rt::Parser::new_lenient(args);
+        let flags = Self::parse_(&mut p)?;
+        Ok((flags, p.warnings()))
+    }
+    fn help_epilogue_() -> &'static xflags::rt::Epilogue {
+        static EPILOGUE: xflags::rt::Epilogue = xflags::rt::Epilogue::new();
+        &EPILOGUE
+    }
+}
+
+impl Tool {
+    const FLAGS_: &'static [&'static str] =
+        &["--dry-run", "--help", "--quiet", "--verbose", "-h", "-q", "-v"];
+    fn parse_(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        let mut state_ = 0u8;
+        Self::parse_state_(p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn parse_state_(p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<Self> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            quiet: Vec<()>,
+            run__dry_run: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 3 | 6) => l_.verbose.push(()),
+                    (0..=1, 2 | 5) => l_.quiet.push(()),
+                    (1, 1 | 4) => return Err(p_.help(&Self::HELP_RUN__)),
+                    (1, 0) => l_.run__dry_run.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"run") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        if !l_.verbose.is_empty() {
+            p_.set_source(0, xflags::ValueSource::CommandLine);
+        }
+        if !l_.quiet.is_empty() {
+            p_.set_source(1, xflags::ValueSource::CommandLine);
+        }
+        if !l_.run__dry_run.is_empty() {
+            p_.set_source(2, xflags::ValueSource::CommandLine);
+        }
+        let verbosity_given_ = !l_.verbose.is_empty() || !l_.quiet.is_empty();
+        if !verbosity_given_ {
+            let values_ = p_.fallback(0, &[], "verbose");
+            for _ in 0..p_.switch_count("--verbose", values_, false)? {
+                l_.verbose.push(());
+            }
+        }
+        if !verbosity_given_ {
+            let values_ = p_.fallback(1, &[], "quiet");
+            for _ in 0..p_.switch_count("--quiet", values_, true)? {
+                l_.quiet.push(());
+            }
+        }
+        if matches!(*state_, | 1) && l_.run__dry_run.is_empty() {
+            let values_ = p_.fallback(2, &["run"], "dry-run");
+            for _ in 0..p_.switch_count("--dry-run", values_, true)? {
+                l_.run__dry_run.push(());
+            }
+        }
+        p_.check_optional("--quiet", l_.quiet.len())?;
+        p_.check_exclusive("--verbose", l_.verbose.len(), "--quiet", l_.quiet.len())?;
+        if matches!(*state_, | 1) {
+            p_.check_optional("--dry-run", l_.run__dry_run.len())?;
+        }
+        Ok(Tool {
+            verbosity: l_.verbose.len() as i32 - l_.quiet.len() as i32,
+            subcommand: match *state_ {
+                1 => ToolCmd::Run(Run { dry_run: !l_.run__dry_run.is_empty() }),
+                _ => return Err(p_.subcommand_required()),
+            },
+        })
+    }
+}
+
+impl Tool {
+    fn apply_(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+        let mut p_ = xflags::rt::Parser::new(args);
+        let mut state_ = self.state_();
+        self.apply_state_(&mut p_, &mut state_)
+            .map_err(|err_| p_.with_usage(err_, Self::usage_(state_)))
+    }
+
+    fn apply_state_(&mut self, p_: &mut xflags::rt::Parser, state_: &mut u8) -> xflags::Result<()> {
+        #![allow(non_snake_case, unused_mut)]
+        #[derive(Default)]
+        struct Locals_ {
+            verbose: Vec<()>,
+            quiet: Vec<()>,
+            run__dry_run: Vec<()>,
+        }
+        let mut l_ = Locals_::default();
+
+        while let Some(arg_) = p_.pop_flag() {
+            match arg_ {
+                Ok(flag_) => match (*state_, p_.flag_index(&flag_, Self::FLAGS_)) {
+                    (0, 1 | 4) => {
+                        return Err(p_.help_with_epilogue(&Self::HELP_, Self::help_epilogue_()))
+                    }
+                    (0..=1, 3 | 6) => l_.verbose.push(()),
+                    (0..=1, 2 | 5) => l_.quiet.push(()),
+                    (1, 1 | 4) => return Err(p_.help(&Self::HELP_RUN__)),
+                    (1, 0) => l_.run__dry_run.push(()),
+                    _ => p_.unexpected_flag(&flag_)?,
+                },
+                Err(arg_) => match (*state_, arg_.as_encoded_bytes()) {
+                    (0, b"run") => *state_ = 1,
+                    (0, b"help") => p_.defer_help(),
+                    (0, _) => {
+                        return Err(p_.unexpected_arg(arg_));
+                    }
+                    (1, b"help") => p_.defer_help(),
+                    _ => return Err(p_.unexpected_arg(arg_)),
+                },
+            }
+        }
+        p_.check_optional("--quiet", l_.quiet.len())?;
+        p_.check_exclusive("--verbose", l_.verbose.len(), "--quiet", l_.quiet.len())?;
+        p_.check_optional("--dry-run", l_.run__dry_run.len())?;
+        p_.check_exclusive(
+            "--verbose",
+            l_.verbose.len() + (self.verbosity > 0) as usize,
+            "--quiet",
+            l_.quiet.len() + (self.verbosity < 0) as usize,
+        )?;
+        if !l_.quiet.is_empty() {
+            self.verbosity = -1;
+        } else if !l_.verbose.is_empty() {
+            self.verbosity += l_.verbose.len() as i32;
+        }
+        match &mut self.subcommand {
+            ToolCmd::Run(cmd_) => {
+                if !l_.run__dry_run.is_empty() {
+                    cmd_.dry_run = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state_(&self) -> u8 {
+        match &self.subcommand {
+            ToolCmd::Run(_) => 1,
+        }
+    }
+}
+
+impl Tool {
+    fn subcommand_path_(&self) -> Vec<&'static str> {
+        let mut res_ = vec!["tool"];
+        match &self.subcommand {
+            ToolCmd::Run(_) => {
+                res_.push("run");
+            }
+        }
+        res_
+    }
+}
+
+impl xflags::rt::Mount for Tool {
+    fn parse_mounted(p_: &mut xflags::rt::Parser) -> xflags::Result<Self> {
+        Self::parse_(p_)
+    }
+}
+impl Tool {
+    const USAGE_RUN__: &'static str = "Usage: run [--dry-run]";
+    const HELP_RUN__: xflags::Help = xflags::Help::new(
+        Self::USAGE_RUN__,
+        None,
+        &[
+            xflags::HelpSection { title: "Options", entries: &[("--dry-run", "")] },
+            xflags::HelpSection { title: "Commands", entries: &[xflags::rt::HELP_ENTRY] },
+        ],
+    );
+    const USAGE_: &'static str = "Usage: tool [-v]... [-q] [-h] <COMMAND>";
+    const HELP_: xflags::Help = xflags::Help::new(
+        Self::USAGE_,
+        None,
+        &[
+            xflags::HelpSection {
+                title: "Options",
+                entries: &[
+                    ("-v, --verbose", "Print more output, can be repeated"),
+                    ("-q, --quiet", "Print less output"),
+                    ("-h, --help", "Prints help"),
+                ],
+            },
+            xflags::HelpSection {
+                title: "Commands",
+                entries: &[("run", ""), xflags::rt::HELP_ENTRY],
+            },
+        ],
+    );
+    fn usage_(state_: u8) -> &'static str {
+        match state_ {
+            0 => Self::USAGE_,
+            1 => Self::USAGE_RUN__,
+            _ => "",
+        }
+    }
+    const SPEC_JSON_: &'static str = "{\"version\":1,\"command\":{\"name\":\"tool\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"verbose\",\"short\":\"v\",\"arity\":\"repeated\",\"value\":null,\"doc\":\"Print more output, can be repeated\"},{\"name\":\"quiet\",\"short\":\"q\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Print less output\"},{\"name\":\"help\",\"short\":\"h\",\"arity\":\"optional\",\"value\":null,\"doc\":\"Prints help\"}],\"subcommands\":[{\"name\":\"run\",\"aliases\":[],\"deprecated_aliases\":[],\"doc\":null,\"default\":false,\"args\":[],\"flags\":[{\"name\":\"dry-run\",\"short\":null,\"arity\":\"optional\",\"value\":null,\"doc\":null}],\"subcommands\":[]}]}}";
+}
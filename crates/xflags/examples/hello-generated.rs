@@ -24,7 +24,27 @@ mod flags {
         pub emoji: bool,
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum HelloField {
+        Emoji,
+        Name,
+    }
+
+    impl From<HelloField> for usize {
+        fn from(field: HelloField) -> usize {
+            match field {
+                HelloField::Emoji => 0,
+                HelloField::Name => 1,
+            }
+        }
+    }
+
     impl Hello {
+        #[allow(dead_code)]
+        pub fn from_env_or_exit() -> Self {
+            Self::from_env_or_exit_()
+        }
+
         #[allow(dead_code)]
         pub fn from_env() -> xflags::Result<Self> {
             Self::from_env_()
@@ -34,6 +54,72 @@ mod flags {
         pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
             Self::from_vec_(args)
         }
+
+        #[allow(dead_code)]
+        pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+            Self::from_slice_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+            self.apply_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_env_())
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_outcome(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_vec_(args))
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<HelloField>)> {
+            Self::from_env_with_sources_()
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_with_sources(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, xflags::Sources<HelloField>)> {
+            Self::from_vec_with_sources_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_lenient(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+            Self::from_vec_lenient_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &Self::HELP_
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            Self::USAGE_
+        }
+
+        #[allow(dead_code)]
+        pub fn set_help_epilogue(epilogue: impl Into<String>) {
+            Self::help_epilogue_().set(epilogue.into())
+        }
+
+        #[allow(dead_code)]
+        pub fn subcommand_path(&self) -> Vec<&'static str> {
+            self.subcommand_path_()
+        }
+
+        #[allow(dead_code)]
+        pub fn spec_json() -> &'static str {
+            Self::SPEC_JSON_
+        }
     }
     // generated end
 }
@@ -7,15 +7,11 @@ mod flags {
     }
 }
 
-fn main() {
-    match flags::Hello::from_env() {
-        Ok(flags) => {
+fn main() -> xflags::Exit {
+    flags::Hello::from_env()
+        .map(|flags| {
             let bang = if flags.emoji { "❣️" } else { "!" };
             println!("Hello {}{}", flags.name, bang);
-        }
-        Err(err) => {
-            eprintln!("{}", err);
-            std::process::exit(1)
-        }
-    }
+        })
+        .into()
 }
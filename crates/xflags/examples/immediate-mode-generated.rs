@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+fn main() {
+    let flags = xflags::parse_or_exit! {
+        src "./examples/immediate-mode-generated.rs"
+
+        /// Remove directories and their contents recursively.
+        optional -r,--recursive
+        /// File or directory to remove
+        required path: PathBuf
+    };
+
+    // generated start
+    // The following code is generated by `xflags` macro.
+    // Run `env UPDATE_XFLAGS=1 cargo build` to regenerate.
+    #[derive(Debug)]
+    pub struct Flags {
+        pub path: PathBuf,
+
+        pub recursive: bool,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum FlagsField {
+        Recursive,
+        Path,
+    }
+
+    impl From<FlagsField> for usize {
+        fn from(field: FlagsField) -> usize {
+            match field {
+                FlagsField::Recursive => 0,
+                FlagsField::Path => 1,
+            }
+        }
+    }
+
+    impl Flags {
+        #[allow(dead_code)]
+        pub fn from_env_or_exit() -> Self {
+            Self::from_env_or_exit_()
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env() -> xflags::Result<Self> {
+            Self::from_env_()
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
+            Self::from_vec_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+            Self::from_slice_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+            self.apply_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_env_())
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_outcome(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_vec_(args))
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<FlagsField>)> {
+            Self::from_env_with_sources_()
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_with_sources(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, xflags::Sources<FlagsField>)> {
+            Self::from_vec_with_sources_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_lenient(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+            Self::from_vec_lenient_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &Self::HELP_
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            Self::USAGE_
+        }
+
+        #[allow(dead_code)]
+        pub fn set_help_epilogue(epilogue: impl Into<String>) {
+            Self::help_epilogue_().set(epilogue.into())
+        }
+
+        #[allow(dead_code)]
+        pub fn subcommand_path(&self) -> Vec<&'static str> {
+            self.subcommand_path_()
+        }
+
+        #[allow(dead_code)]
+        pub fn spec_json() -> &'static str {
+            Self::SPEC_JSON_
+        }
+    }
+    // generated end
+
+    println!(
+        "removing {}{}",
+        flags.path.display(),
+        if flags.recursive { "recursively" } else { "" },
+    )
+}
@@ -70,7 +70,38 @@ mod flags {
         pub line: Option<u32>,
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[allow(non_camel_case_types)]
+    pub enum RustAnalyzerField {
+        Verbose,
+        LogFile,
+        RunServer_Version,
+        Parse_NoDump,
+        AnalysisBench_Highlight,
+        AnalysisBench_Line,
+        AnalysisBench_Path,
+    }
+
+    impl From<RustAnalyzerField> for usize {
+        fn from(field: RustAnalyzerField) -> usize {
+            match field {
+                RustAnalyzerField::Verbose => 0,
+                RustAnalyzerField::LogFile => 1,
+                RustAnalyzerField::RunServer_Version => 2,
+                RustAnalyzerField::Parse_NoDump => 3,
+                RustAnalyzerField::AnalysisBench_Highlight => 4,
+                RustAnalyzerField::AnalysisBench_Line => 5,
+                RustAnalyzerField::AnalysisBench_Path => 6,
+            }
+        }
+    }
+
     impl RustAnalyzer {
+        #[allow(dead_code)]
+        pub fn from_env_or_exit() -> Self {
+            Self::from_env_or_exit_()
+        }
+
         #[allow(dead_code)]
         pub fn from_env() -> xflags::Result<Self> {
             Self::from_env_()
@@ -80,6 +111,109 @@ mod flags {
         pub fn from_vec(args: Vec<std::ffi::OsString>) -> xflags::Result<Self> {
             Self::from_vec_(args)
         }
+
+        #[allow(dead_code)]
+        pub fn from_slice(args: &[&str]) -> xflags::Result<Self> {
+            Self::from_slice_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn apply(&mut self, args: Vec<std::ffi::OsString>) -> xflags::Result<()> {
+            self.apply_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_outcome() -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_env_())
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_outcome(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<xflags::ParseOutcome<Self>> {
+            xflags::ParseOutcome::from_result(Self::from_vec_(args))
+        }
+
+        #[allow(dead_code)]
+        pub fn from_env_with_sources() -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)>
+        {
+            Self::from_env_with_sources_()
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_with_sources(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, xflags::Sources<RustAnalyzerField>)> {
+            Self::from_vec_with_sources_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn from_vec_lenient(
+            args: Vec<std::ffi::OsString>,
+        ) -> xflags::Result<(Self, Vec<xflags::Warning>)> {
+            Self::from_vec_lenient_(args)
+        }
+
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &Self::HELP_
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            Self::USAGE_
+        }
+
+        #[allow(dead_code)]
+        pub fn set_help_epilogue(epilogue: impl Into<String>) {
+            Self::help_epilogue_().set(epilogue.into())
+        }
+
+        #[allow(dead_code)]
+        pub fn subcommand_path(&self) -> Vec<&'static str> {
+            self.subcommand_path_()
+        }
+
+        #[allow(dead_code)]
+        pub fn spec_json() -> &'static str {
+            Self::SPEC_JSON_
+        }
+    }
+
+    impl RunServer {
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &RustAnalyzer::HELP_RUN_SERVER__
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            RustAnalyzer::USAGE_RUN_SERVER__
+        }
+    }
+
+    impl Parse {
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &RustAnalyzer::HELP_PARSE__
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            RustAnalyzer::USAGE_PARSE__
+        }
+    }
+
+    impl AnalysisBench {
+        #[allow(dead_code)]
+        pub fn help_message() -> &'static xflags::Help {
+            &RustAnalyzer::HELP_ANALYSIS_BENCH__
+        }
+
+        #[allow(dead_code)]
+        pub fn usage() -> &'static str {
+            RustAnalyzer::USAGE_ANALYSIS_BENCH__
+        }
     }
     // generated end
 }
@@ -38,5 +38,18 @@ fn main() {
     eprintln!("flags = {:?}", flags);
 }
 
-#[cfg(not(any(unix, windows)))]
+#[cfg(target_os = "wasi")]
+fn main() {
+    use std::os::wasi::ffi::OsStringExt;
+
+    let flags = flags::Cmd::from_vec(vec![
+        OsString::from_vec(vec![254]),
+        OsString::from_vec(vec![255]),
+        "utf8".into(),
+    ]);
+
+    eprintln!("flags = {:?}", flags);
+}
+
+#[cfg(not(any(unix, windows, target_os = "wasi")))]
 fn main() {}
@@ -0,0 +1,18 @@
+fn main() {
+    xflags::run! {
+        cmd app {
+            repeated -v, --verbose
+
+            cmd build {
+                optional --release
+            }
+            cmd test {
+                repeated filter: String
+            }
+        }
+        match app {
+            AppCmd::Build(build) => println!("building, release: {}", build.release),
+            AppCmd::Test(test) => println!("testing {:?}, verbosity: {}", test.filter, app.verbose),
+        }
+    }
+}
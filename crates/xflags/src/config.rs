@@ -0,0 +1,180 @@
+//! A tiny subset of TOML, sufficient for specifying default values of flags.
+//!
+//! Supported are `[table.headers]`, `key = value` pairs with bare or quoted
+//! keys, strings, numbers, booleans and single-line arrays of those. Every
+//! value is kept as a list of strings, to be parsed like command line values.
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Default)]
+pub(crate) struct Config {
+    values: HashMap<String, Vec<String>>,
+}
+
+impl Config {
+    /// Loads config from `path`. A missing file is treated as an empty config.
+    pub(crate) fn load(path: &Path) -> Result<Config> {
+        match fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text)
+                .map_err(|(line, msg)| Error::new(format!("{}:{line}: {msg}", path.display()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(err) => Err(Error::new(format!("failed to read {}: {err}", path.display()))),
+        }
+    }
+
+    /// Values for the `key` in the table for the command `path`.
+    pub(crate) fn get(&self, path: &[&str], key: &str) -> Option<&[String]> {
+        let mut full_key = String::new();
+        for name in path {
+            full_key.push_str(name);
+            full_key.push('.');
+        }
+        full_key.push_str(key);
+        self.values.get(&full_key).map(|it| it.as_slice())
+    }
+
+    fn parse(text: &str) -> Result<Config, (usize, String)> {
+        let mut res = Config::default();
+        let mut table = String::new();
+        for (i, line) in text.lines().enumerate() {
+            let err = |msg: String| (i + 1, msg);
+            let mut l = Lexer { rest: line.trim() };
+            if l.at_end() {
+                continue;
+            }
+            if l.eat('[') {
+                table.clear();
+                loop {
+                    table.push_str(&l.key().map_err(err)?);
+                    if !l.eat('.') {
+                        break;
+                    }
+                    table.push('.');
+                }
+                if !l.eat(']') || !l.at_end() {
+                    return Err(err("expected `]`".to_string()));
+                }
+                continue;
+            }
+            let mut key = table.clone();
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(&l.key().map_err(err)?);
+            if !l.eat('=') {
+                return Err(err(format!("expected `=` after `{key}`")));
+            }
+            let values = l.values().map_err(err)?;
+            if !l.at_end() {
+                return Err(err(format!("unexpected `{}`", l.rest)));
+            }
+            if res.values.insert(key.clone(), values).is_some() {
+                return Err(err(format!("`{key}` is defined multiple times")));
+            }
+        }
+        Ok(res)
+    }
+}
+
+struct Lexer<'a> {
+    rest: &'a str,
+}
+
+impl Lexer<'_> {
+    fn at_end(&mut self) -> bool {
+        self.skip_ws();
+        self.rest.is_empty() || self.rest.starts_with('#')
+    }
+
+    fn skip_ws(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        self.skip_ws();
+        if self.rest.starts_with(['"', '\'']) {
+            return self.string();
+        }
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(format!("expected a key, got `{}`", self.rest));
+        }
+        let (key, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Ok(key.to_string())
+    }
+
+    fn values(&mut self) -> Result<Vec<String>, String> {
+        if !self.eat('[') {
+            return Ok(vec![self.scalar()?]);
+        }
+        let mut res = Vec::new();
+        while !self.eat(']') {
+            res.push(self.scalar()?);
+            if !self.eat(',') {
+                if !self.eat(']') {
+                    return Err("expected `,` or `]`".to_string());
+                }
+                break;
+            }
+        }
+        Ok(res)
+    }
+
+    fn scalar(&mut self) -> Result<String, String> {
+        self.skip_ws();
+        if self.rest.starts_with(['"', '\'']) {
+            return self.string();
+        }
+        let end = self
+            .rest
+            .find(|c: char| c.is_whitespace() || c == ',' || c == ']' || c == '#')
+            .unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(format!("expected a value, got `{}`", self.rest));
+        }
+        let (value, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Ok(value.to_string())
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let mut chars = self.rest.char_indices();
+        let quote = chars.next().map(|(_, c)| c).unwrap_or_default();
+        let mut res = String::new();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                _ if c == quote => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(res);
+                }
+                '\\' if quote == '"' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => res.push('\n'),
+                    Some('t') => res.push('\t'),
+                    Some('\\') => res.push('\\'),
+                    Some('"') => res.push('"'),
+                    Some(c) => return Err(format!("unsupported escape: `\\{c}`")),
+                    None => break,
+                },
+                c => res.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+}
@@ -0,0 +1,75 @@
+//! Parsing of `.env` files with `KEY=value` lines.
+//!
+//! Supported are `#` comments, an optional `export` before the key, and
+//! values which are bare, single-quoted (taken literally) or double-quoted
+//! (with `\n`, `\t`, `\\` and `\"` escapes).
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::{Error, Result};
+
+/// Loads variables from `path`. A missing file is treated as an empty one.
+pub(crate) fn load(path: &Path) -> Result<HashMap<String, String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text)
+            .map_err(|(line, msg)| Error::new(format!("{}:{line}: {msg}", path.display()))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(err) => Err(Error::new(format!("failed to read {}: {err}", path.display()))),
+    }
+}
+
+fn parse(text: &str) -> Result<HashMap<String, String>, (usize, String)> {
+    let mut res = HashMap::new();
+    for (i, line) in text.lines().enumerate() {
+        let err = |msg: String| (i + 1, msg);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").map(str::trim_start).unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(err(format!("expected `=` in `{line}`")));
+        };
+        let key = key.trim_end();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(err(format!("invalid variable name: `{key}`")));
+        }
+        let value = unquote(value.trim_start()).map_err(err)?;
+        res.insert(key.to_string(), value);
+    }
+    Ok(res)
+}
+
+fn unquote(text: &str) -> Result<String, String> {
+    let mut chars = text.char_indices();
+    let quote = match chars.next() {
+        Some((_, c @ ('"' | '\''))) => c,
+        // Unquoted values end at a comment.
+        _ => {
+            let end = text.find(" #").unwrap_or(text.len());
+            return Ok(text[..end].trim_end().to_string());
+        }
+    };
+    let mut res = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            _ if c == quote => {
+                let rest = text[i + 1..].trim_start();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(format!("unexpected `{rest}`"));
+                }
+                return Ok(res);
+            }
+            '\\' if quote == '"' => match chars.next().map(|(_, c)| c) {
+                Some('n') => res.push('\n'),
+                Some('t') => res.push('\t'),
+                Some('\\') => res.push('\\'),
+                Some('"') => res.push('"'),
+                Some(c) => return Err(format!("unsupported escape: `\\{c}`")),
+                None => break,
+            },
+            c => res.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
@@ -0,0 +1,63 @@
+//! Parsing of human-friendly durations, like `30s`, `1h30m` or `1.5d`.
+
+use std::{str::FromStr, time::Duration};
+
+/// A `Duration` which can be parsed with `FromStr`.
+pub(crate) struct DurationValue(pub(crate) Duration);
+
+/// Units, with their length in nanoseconds.
+const UNITS: &[(&str, f64)] = &[
+    ("ns", 1.0),
+    ("us", 1e3),
+    ("µs", 1e3),
+    ("ms", 1e6),
+    ("s", 1e9),
+    ("sec", 1e9),
+    ("m", 60e9),
+    ("min", 60e9),
+    ("h", 3600e9),
+    ("d", 86400e9),
+];
+
+impl FromStr for DurationValue {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, String> {
+        let mut rest = text.trim();
+        if rest.is_empty() {
+            return Err("expected a duration, like `30s` or `1h30m`".to_string());
+        }
+        let mut nanos = 0.0;
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+            let number = rest[..end].parse::<f64>().map_err(|_| {
+                // Without any digits, show the first character instead.
+                let got = match end {
+                    0 => rest.chars().next().map_or("", |c| &rest[..c.len_utf8()]),
+                    _ => &rest[..end],
+                };
+                format!("expected a number, got `{got}`")
+            })?;
+            rest = &rest[end..];
+            let end = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+            let unit = rest[..end].trim();
+            let Some(&(_, scale)) = UNITS.iter().find(|(name, _)| *name == unit) else {
+                return Err(if unit.is_empty() {
+                    format!("missing unit after `{number}`, expected one of: {}", unit_names())
+                } else {
+                    format!("unknown unit `{unit}`, expected one of: {}", unit_names())
+                });
+            };
+            nanos += number * scale;
+            rest = &rest[end..];
+        }
+        if nanos >= u64::MAX as f64 {
+            return Err("duration is too large".to_string());
+        }
+        Ok(DurationValue(Duration::from_nanos(nanos.round() as u64)))
+    }
+}
+
+fn unit_names() -> String {
+    UNITS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+}
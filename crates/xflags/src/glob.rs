@@ -0,0 +1,133 @@
+//! Expansion of `*`, `?`, and `[...]` patterns in paths, for platforms where
+//! the shell doesn't do it.
+
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// Whether `pattern` contains any wildcards.
+pub(crate) fn is_pattern(pattern: &OsStr) -> bool {
+    pattern.to_str().is_some_and(|it| it.contains(['*', '?', '[']))
+}
+
+/// Returns paths matching `pattern`, sorted. A `**` component matches any
+/// number of nested directories.
+pub(crate) fn expand(pattern: &OsStr) -> Vec<PathBuf> {
+    let Some(text) = pattern.to_str() else { return Vec::new() };
+
+    let mut paths = vec![PathBuf::new()];
+    for component in Path::new(text).components() {
+        let name = match component {
+            Component::Normal(name) => name.to_str().unwrap_or_default(),
+            _ => {
+                paths.iter_mut().for_each(|it| it.push(component));
+                continue;
+            }
+        };
+        if name == "**" {
+            let mut next = Vec::new();
+            for dir in paths {
+                subdirs(&mut next, dir);
+            }
+            paths = next;
+            continue;
+        }
+        if !name.contains(['*', '?', '[']) {
+            paths.iter_mut().for_each(|it| it.push(name));
+            continue;
+        }
+        let pattern = name.chars().collect::<Vec<_>>();
+        let mut next = Vec::new();
+        for dir in &paths {
+            for (path, file_name) in entries(dir) {
+                // Like shells, only match hidden files explicitly.
+                if file_name.starts_with('.') && pattern[0] != '.' {
+                    continue;
+                }
+                if matches(&pattern, &file_name.chars().collect::<Vec<_>>()) {
+                    next.push(path);
+                }
+            }
+        }
+        paths = next;
+    }
+
+    paths.retain(|it| it.exists());
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Pushes `dir` and all directories nested in it, except hidden ones.
+fn subdirs(acc: &mut Vec<PathBuf>, dir: PathBuf) {
+    for (path, file_name) in entries(&dir) {
+        if !file_name.starts_with('.') && path.is_dir() {
+            subdirs(acc, path);
+        }
+    }
+    acc.push(dir);
+}
+
+/// Entries of `dir` with UTF-8 names, where an empty `dir` is the current one.
+fn entries(dir: &Path) -> Vec<(PathBuf, String)> {
+    let dir_or_cwd = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    let Ok(entries) = fs::read_dir(dir_or_cwd) else { return Vec::new() };
+    entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .map(|file_name| (dir.join(&file_name), file_name))
+        .collect()
+}
+
+fn matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| matches(rest, &name[i..])),
+        Some(('?', rest)) => !name.is_empty() && matches(rest, &name[1..]),
+        Some(('[', rest)) => match (name.split_first(), class(rest)) {
+            (Some((&c, name)), Some((set, negated, rest))) => {
+                set.contains(c) != negated && matches(rest, name)
+            }
+            (None, _) => false,
+            // Unterminated `[` matches literally.
+            (Some((&c, name)), None) => c == '[' && matches(rest, name),
+        },
+        Some((&p, rest)) => name.first() == Some(&p) && matches(rest, &name[1..]),
+    }
+}
+
+/// Parses the character class following `[`, returning the set, whether it is
+/// negated, and the rest of the pattern.
+fn class(pattern: &[char]) -> Option<(Class, bool, &[char])> {
+    let (negated, mut pattern) = match pattern.split_first() {
+        Some(('!' | '^', rest)) => (true, rest),
+        _ => (false, pattern),
+    };
+    let mut ranges = Vec::new();
+    let mut first = true;
+    loop {
+        match pattern {
+            [']', rest @ ..] if !first => return Some((Class(ranges), negated, rest)),
+            [lo, '-', hi, rest @ ..] if *hi != ']' => {
+                ranges.push((*lo, *hi));
+                pattern = rest;
+            }
+            [c, rest @ ..] => {
+                ranges.push((*c, *c));
+                pattern = rest;
+            }
+            [] => return None,
+        }
+        first = false;
+    }
+}
+
+struct Class(Vec<(char, char)>);
+
+impl Class {
+    fn contains(&self, c: char) -> bool {
+        self.0.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+    }
+}
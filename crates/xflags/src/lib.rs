@@ -56,6 +56,14 @@
 //!   help                 Print this message or the help of the given subcommand(s)
 //! ```
 //!
+//! The `help` command takes a path of subcommands, so `app help foo bar` prints
+//! the same help as `app foo bar --help`. Where a positional argument is
+//! expected instead of a subcommand, `help` is just its value.
+//!
+//! Text only known at runtime, like a list of discovered plugins, can be
+//! appended to the help of the top-level command with the generated
+//! `set_help_epilogue` function, which is to be called before parsing.
+//!
 //! For larger programs, you'd typically want to use `xflags!` macro, which
 //! generates _named_ structs for you. Unlike a typical macro, `xflags` writes
 //! generated code into the source file, to make it easy to understand the rust
@@ -83,6 +91,21 @@
 //!         pub verbose: bool,
 //!     }
 //!
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub enum MyCommandField {
+//!         Verbose,
+//!         Path,
+//!     }
+//!
+//!     impl From<MyCommandField> for usize {
+//!         fn from(field: MyCommandField) -> usize {
+//!             match field {
+//!                 MyCommandField::Verbose => 0,
+//!                 MyCommandField::Path => 1,
+//!             }
+//!         }
+//!     }
+//!
 //!     impl MyCommand {
 //!         pub fn from_env_or_exit() -> Self {
 //!             Self::from_env_or_exit_()
@@ -121,7 +144,9 @@
 //!
 //! Switches are specified inside the curly braces. Long names (`--switch`) are
 //! mandatory, short names (`-s`) are optional. Each switch can be **optional**,
-//! **required**, or **repeated**. Dashes are allowed in switch names.
+//! **required**, or **repeated**. Dashes are allowed in switch names. Names of
+//! switches and commands may also contain non-ASCII letters, like `--größe`.
+//! Short names can also be a digit or one of `#`, `@`, `%`, `+`, like `-1`.
 //!
 //! ```
 //! xflags::xflags! {
@@ -135,7 +160,8 @@
 //!
 //! Switches can also take values. If the value type is `OsString` or `PathBuf`,
 //! it is created directly from the underlying argument. Otherwise, `FromStr` is
-//! used for parsing
+//! used for parsing. The `FromStr` error is kept as the
+//! [`source`](std::error::Error::source) of the resulting [`Error`].
 //!
 //! ```
 //! use std::{path::PathBuf, ffi::OsString};
@@ -149,6 +175,157 @@
 //! }
 //! ```
 //!
+//! Switches with a `bool` value are set to `true` when given alone, and also
+//! accept an explicit value attached with `=`: `true`/`false`, `yes`/`no`,
+//! `on`/`off` or `1`/`0`. This allows overriding them in either direction:
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd deploy {
+//!         optional --color value: bool
+//!     }
+//! }
+//!
+//! let flags = Deploy::from_vec(vec!["--color=no".into()]).unwrap();
+//! assert_eq!(flags.color, Some(false));
+//! let flags = Deploy::from_vec(vec!["--color".into()]).unwrap();
+//! assert_eq!(flags.color, Some(true));
+//! ```
+//!
+//! Custom types which need the raw, possibly non-UTF-8 argument can be parsed
+//! with `TryFrom<&OsStr>` instead, by adding **from OsStr** after the type:
+//!
+//! ```
+//! use std::ffi::{OsStr, OsString};
+//!
+//! #[derive(Debug)]
+//! struct Label(OsString);
+//!
+//! impl TryFrom<&OsStr> for Label {
+//!     type Error = &'static str;
+//!     fn try_from(value: &OsStr) -> Result<Label, Self::Error> {
+//!         if value.is_empty() {
+//!             return Err("label is empty");
+//!         }
+//!         Ok(Label(value.to_os_string()))
+//!     }
+//! }
+//!
+//! xflags::xflags! {
+//!     cmd tag {
+//!         repeated --label label: Label from OsStr
+//!     }
+//! }
+//!
+//! let err = Tag::from_vec(vec!["--label".into(), "".into()]).unwrap_err();
+//! assert_eq!(err.to_string(), "Invalid value `` for `--label`: label is empty");
+//! ```
+//!
+//! The type `Duration` is parsed into [`std::time::Duration`] from a sequence
+//! of numbers with units, such as `250ms`, `30s`, `1h30m` or `1.5d`. The
+//! supported units are `ns`, `us` or `µs`, `ms`, `s` or `sec`, `m` or `min`,
+//! `h` and `d`.
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd fetch {
+//!         optional --timeout duration: Duration
+//!     }
+//! }
+//!
+//! let flags = Fetch::from_vec(vec!["--timeout".into(), "1m30s".into()]).unwrap();
+//! assert_eq!(flags.timeout, Some(std::time::Duration::from_secs(90)));
+//! ```
+//!
+//! The name is reserved on its own, so a type of yours that's also called
+//! `Duration` should be written with its path, like `units::Duration`.
+//!
+//! The types `ExistingFile`, `ExistingDir` and `NewPath` are parsed into a
+//! `PathBuf`, but are also checked while parsing: the first two that the path
+//! exists and is a file or a directory, and `NewPath` that nothing exists at
+//! the path yet, while its parent directory does.
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd render {
+//!         required --config path: ExistingFile
+//!         optional --output path: NewPath
+//!     }
+//! }
+//!
+//! let err = Render::from_vec(vec!["--config".into(), "/no/such.toml".into()]).unwrap_err();
+//! assert_eq!(err.to_string(), "Invalid value `/no/such.toml` for `--config`: file does not exist");
+//! ```
+//!
+//! Like `Duration`, these names are reserved only on their own, and a type of
+//! yours with one of them can be used through its path.
+//!
+//! Sizes, such as limits or cache sizes, can use [`ByteSize`], which accepts
+//! suffixes like `64k`, `2MiB` or `1.5G`.
+//!
+//! ```
+//! use xflags::ByteSize;
+//!
+//! xflags::xflags! {
+//!     cmd cache {
+//!         optional --max-size size: ByteSize
+//!     }
+//! }
+//!
+//! let flags = Cache::from_vec(vec!["--max-size".into(), "2MiB".into()]).unwrap();
+//! assert_eq!(flags.max_size, Some(ByteSize(2 * 1024 * 1024)));
+//! ```
+//!
+//! The **verbosity** keyword declares the conventional `repeated -v, --verbose`
+//! and `optional -q, --quiet` pair, which can't be used together, also not
+//! when `apply` adds one of them to flags parsed with the other. Both are
+//! stored in a single `verbosity: i32` field, which is the number of `-v`
+//! flags, or `-1` for `-q`. The generated `log_level()` function returns the
+//! corresponding [`LogLevel`].
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd tool {
+//!         verbosity
+//!     }
+//! }
+//!
+//! let flags = Tool::from_vec(vec!["-v".into()]).unwrap();
+//! assert_eq!(flags.log_level(), xflags::LogLevel::Info);
+//! assert_eq!(flags.verbosity, 1);
+//! assert!(Tool::from_vec(vec!["-v".into(), "-q".into()]).is_err());
+//! ```
+//!
+//! Repeated switches count their occurrences in a `u32`. With **as**, another
+//! integer type can be used instead, or a type such as an enum, given match
+//! arms mapping the count to its variants:
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd tool {
+//!         repeated -v, --verbose as xflags::LogLevel { 0 => Warn, 1 => Info, 2.. => Debug }
+//!         repeated -d, --debug as u8
+//!     }
+//! }
+//!
+//! let flags = Tool::from_vec(vec!["-v".into(), "-v".into()]).unwrap();
+//! assert_eq!(flags.verbose, xflags::LogLevel::Debug);
+//! ```
+//!
+//! With the `prompt` cargo feature enabled, required flags with values can be
+//! marked with **prompt**. If such a flag is missing and stdin is a terminal,
+//! the user is asked to enter the value; `prompt(hidden)` disables echo while
+//! typing. Otherwise, the usual "Flag is required" error is reported.
+//!
+//! ```ignore
+//! xflags::xflags! {
+//!     cmd login {
+//!         required --user name: String prompt
+//!         required --token token: String prompt(hidden)
+//!     }
+//! }
+//! ```
+//!
 //! Arguments without `--` in then are are positional.
 //!
 //! ```
@@ -162,6 +339,62 @@
 //! }
 //! ```
 //!
+//! Required arguments can come after optional or repeated ones, like in
+//! `cp SRC... DST`. Values are then assigned once all arguments are seen: each
+//! required argument gets one, and the remaining values go to the optional
+//! and repeated arguments in order. There can be only one repeated argument.
+//!
+//! ```
+//! use std::path::PathBuf;
+//!
+//! xflags::xflags! {
+//!     cmd cp {
+//!         repeated sources: PathBuf
+//!         required dest: PathBuf
+//!     }
+//! }
+//!
+//! let flags = Cp::from_vec(vec!["a".into(), "b".into(), "dir".into()]).unwrap();
+//! assert_eq!(flags.sources, [PathBuf::from("a"), PathBuf::from("b")]);
+//! assert_eq!(flags.dest, PathBuf::from("dir"));
+//! ```
+//!
+//! A repeated argument declared with **repeated!** must be given at least
+//! once. It is still a `Vec`, which is never empty:
+//!
+//! ```
+//! use std::path::PathBuf;
+//!
+//! xflags::xflags! {
+//!     cmd cat {
+//!         repeated! files: PathBuf
+//!     }
+//! }
+//!
+//! let err = Cat::from_vec(vec![]).unwrap_err();
+//! assert_eq!(err.to_string(), "expected at least one <files>");
+//! ```
+//!
+//! Windows shells don't expand wildcards like `*.rs`. Repeated `PathBuf`
+//! arguments marked with **glob** expand `*`, `?`, `[...]` and `**` patterns
+//! while parsing, on all platforms or, with `glob(windows)`, only on Windows.
+//! Patterns which don't match anything are kept as is.
+//!
+//! Repeated `PathBuf` flags can be marked with `glob` as well, for quoted
+//! patterns like `--input 'src/**/*.proto'`. There, a pattern which doesn't
+//! match anything is an error, unless the flag is marked `glob(lenient)`.
+//!
+//! ```
+//! use std::path::PathBuf;
+//!
+//! xflags::xflags! {
+//!     cmd lint {
+//!         repeated files: PathBuf glob(windows)
+//!         repeated --input pattern: PathBuf glob
+//!     }
+//! }
+//! ```
+//!
 //! You can create aliases if desired, which is as simple as adding extra names to the `cmd` definition.
 //! In this case, `run` can be called as `run`, `r` and `exec`:
 //!
@@ -171,6 +404,17 @@
 //! }
 //! ```
 //!
+//! Old names which should keep working for a while can be listed as
+//! `deprecated:` aliases. They are accepted, but print a notice pointing to
+//! the new name (or a warning with `from_vec_lenient`), and are left out of
+//! help and completions:
+//!
+//! ```rust
+//! xflags::xflags! {
+//!     cmd check (deprecated: lint verify) {}
+//! }
+//! ```
+//!
 //! Nesting **cmd** is allowed. `xflag` automatically generates boilerplate
 //! enums for subcommands:
 //!
@@ -280,44 +524,360 @@
 //! the `UPDATE_XFLAGS` environmental variable is set, the macro will write them
 //! directly to the specified file.
 //!
+//! `parse_or_exit!` and `parse!` accept **src** too. The generated `Flags`
+//! struct is then written into the function right after the invocation, where
+//! it can also be referred to by name.
+//!
 //! By convention, `xflag!` macro should be invoked from the `flags` submodule.
+//! For quick scripts, it can also be invoked inside a function, and the
+//! generated items are then local to that function; with **src**, they are
+//! written right after the invocation:
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd script {
+//!         required name: String
+//!     }
+//! }
+//!
+//! let flags = Script::from_vec(vec!["world".into()]).unwrap();
+//! assert_eq!(flags.name, "world");
+//! ```
+//!
 //! The `flags::` prefix should be used to refer to command names. Additional
-//! validation logic can go to the `flags` module:
+//! validation logic can go to the `flags` module. The **validate** keyword
+//! names a function which is called with the parsed command, so that every
+//! parsing entry point, including `from_env_or_exit`, runs it:
 //!
 //! ```
 //! mod flags {
 //!     xflags::xflags! {
 //!         cmd my-command {
+//!             validate check_my_command
+//!
 //!             repeated -v, --verbose
 //!             optional -q, --quiet
 //!         }
 //!     }
 //!
-//!     impl MyCommand {
-//!         fn validate(&self) -> xflags::Result<()> {
-//!             if self.quiet && self.verbose > 0 {
-//!                 return Err(xflags::Error::new(
-//!                     "`-q` and `-v` can't be specified at the same time"
-//!                 ));
-//!             }
-//!             Ok(())
+//!     fn check_my_command(flags: &MyCommand) -> xflags::Result<()> {
+//!         if flags.quiet && flags.verbose > 0 {
+//!             return Err(xflags::Error::new(
+//!                 "`-q` and `-v` can't be specified at the same time"
+//!             ));
 //!         }
+//!         Ok(())
 //!     }
 //! }
 //! ```
 //!
+//! A top-level command defined elsewhere, for example in a library shared by
+//! several binaries, can be used as a subcommand with the **mount** keyword.
+//! The name of the subcommand is derived from the name of the struct, unless
+//! it's given before the path, as in `mount tools super::common::CommonTools`.
+//! The mounted command parses all of the remaining arguments. Its flags are
+//! also read from the environment variables and the config file of the parent,
+//! as if it was declared in place. It's a separate grammar though, so the
+//! completions, the man page and `spec_json()` of the parent only list its
+//! name:
+//!
+//! ```
+//! mod common {
+//!     xflags::xflags! {
+//!         cmd common-tools {
+//!             optional --fix
+//!         }
+//!     }
+//! }
+//!
+//! mod flags {
+//!     xflags::xflags! {
+//!         cmd app {
+//!             mount super::common::CommonTools
+//!             cmd run {}
+//!         }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let flags = flags::App::from_vec(vec!["common-tools".into(), "--fix".into()]).unwrap();
+//!     assert!(matches!(flags.subcommand, flags::AppCmd::CommonTools(tools) if tools.fix));
+//! }
+//! ```
+//!
+//! Parsed flags can be adjusted with additional arguments using the generated
+//! `apply` function, which is handy for interactive shells. The arguments are
+//! parsed in the context of the already selected subcommand. Values of
+//! repeated flags and arguments are appended, other values are overridden.
+//! The `validate` functions run on the result, and if one of them fails, the
+//! flags are left as they were.
+//!
+//! ```
+//! mod flags {
+//!     xflags::xflags! {
+//!         cmd build {
+//!             optional -j, --jobs n: u32
+//!             repeated --feature name: String
+//!         }
+//!     }
+//! }
+//!
+//! let mut flags = flags::Build::from_vec(vec!["--feature".into(), "a".into()]).unwrap();
+//! flags.apply(vec!["-j".into(), "8".into(), "--feature".into(), "b".into()]).unwrap();
+//! assert_eq!(flags.jobs, Some(8));
+//! assert_eq!(flags.feature, ["a", "b"]);
+//! ```
+//!
+//! To find out whether a field was explicitly set by the user, use
+//! `from_env_with_sources` or `from_vec_with_sources`. Alongside the parsed
+//! flags, they return [`Sources`], which can be queried with the generated
+//! `Field` enum. Fields of subcommands are prefixed with their path, like
+//! `AppField::Build_Jobs` for `--jobs` of `app build`:
+//!
+//! ```
+//! mod flags {
+//!     xflags::xflags! {
+//!         cmd build {
+//!             optional -j, --jobs n: u32
+//!         }
+//!     }
+//! }
+//!
+//! let args = vec!["-j".into(), "8".into()];
+//! let (_flags, sources) = flags::Build::from_vec_with_sources(args).unwrap();
+//! assert_eq!(sources.get(flags::BuildField::Jobs), xflags::ValueSource::CommandLine);
+//! ```
+//!
+//! During a migration, `from_vec_lenient` accepts command lines which would
+//! otherwise be rejected, and returns the problems as [`Warning`]s: unknown
+//! flags are skipped, and for flags given more than once the last value is
+//! used. Other errors, like a missing required flag, are still reported:
+//!
+//! ```
+//! mod flags {
+//!     xflags::xflags! {
+//!         cmd build {
+//!             optional -j, --jobs n: u32
+//!         }
+//!     }
+//! }
+//!
+//! let args = vec!["--fast".into(), "-j".into(), "8".into()];
+//! let (flags, warnings) = flags::Build::from_vec_lenient(args).unwrap();
+//! assert_eq!(flags.jobs, Some(8));
+//! assert_eq!(warnings[0].to_string(), "Unknown flag: `--fast`");
+//! ```
+//!
+//! The **env-prefix** keyword makes every flag readable from an environment
+//! variable, named by the prefix followed by the names of the subcommand and
+//! the flag in upper snake case. Values given on the command line take
+//! precedence. Switches accept `true`, `false`, or a count, which can be
+//! above one only for `repeated` switches. The `-v` and `-q` of `verbosity`
+//! are read from the environment only when neither is on the command line:
+//!
+//! ```
+//! xflags::xflags! {
+//!     env-prefix "MYTOOL_"
+//!     cmd my-tool {
+//!         // `MYTOOL_JOBS=8`
+//!         optional -j, --jobs n: u32
+//!         // `MYTOOL_VERBOSE=1`
+//!         repeated -v, --verbose
+//!     }
+//! }
+//! ```
+//!
+//! The **dotenv** keyword additionally reads such variables from a `.env`
+//! file, given as a path relative to the current directory or `.env` by
+//! default. A missing file is ignored, and variables set in the environment
+//! take precedence:
+//!
+//! ```
+//! xflags::xflags! {
+//!     env-prefix "MYTOOL_"
+//!     dotenv
+//!     cmd my-tool {
+//!         optional --port n: u16
+//!     }
+//! }
+//! ```
+//!
+//! With the `config` cargo feature enabled, the **config** keyword specifies a
+//! TOML file with default values for flags not given on the command line. The
+//! path is relative to the current directory, and a missing file is ignored.
+//! Flags of subcommands are looked up in the table named after the subcommand.
+//! Environment variables take precedence over the config file. Such values
+//! are reported as [`ValueSource::Config`].
+//!
+//! ```ignore
+//! xflags::xflags! {
+//!     config "app.toml"
+//!     cmd app {
+//!         optional -j, --jobs n: u32
+//!         cmd build { repeated --feature name: String }
+//!     }
+//! }
+//! ```
+//!
+//! ```toml
+//! jobs = 4
+//!
+//! [build]
+//! feature = ["a", "b"]
+//! ```
+//!
+//! The **settings** block opts into parsing behaviors which are off by
+//! default, for the whole command tree:
+//!
+//! - `gnu-short-flags`: short flags can be grouped, as in `-xvf`, and the
+//!   value can be glued to the flag, as in `-j4`.
+//! - `flag-assign`: values can be attached with `=`, as in `--jobs=4`.
+//! - `strict-ordering`: everything after the first positional argument is
+//!   positional too, which is handy for wrapping other commands.
+//! - `strip-help`: `--help` prints only the usage line, leaving the rest of the
+//!   help text out of size-constrained binaries. The doc comments are left out
+//!   of the `generate` output and of `spec_json()` as well.
+//!
+//! ```
+//! xflags::xflags! {
+//!     settings { gnu-short-flags, flag-assign, strict-ordering }
+//!     cmd exec {
+//!         repeated -v, --verbose
+//!         optional -j, --jobs n: u32
+//!         repeated command: String
+//!     }
+//! }
+//!
+//! let args = ["-vj4", "ls", "-la"].map(Into::into).to_vec();
+//! let flags = Exec::from_vec(args).unwrap();
+//! assert_eq!((flags.verbose, flags.jobs), (1, Some(4)));
+//! assert_eq!(flags.command, ["ls", "-la"]);
+//! ```
+//!
+//! The **builtin generate** declaration adds a `generate` command to the
+//! top-level command, which prints shell completions (`bash`, `zsh`, `fish`)
+//! or a man page (`man`) for the program. Like `--help`, the output is
+//! reported as an [`Error`] for which [`Error::is_help`] is true, so that
+//! [`Error::exit`] prints it to stdout:
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd my-tool {
+//!         builtin generate
+//!         repeated -v, --verbose
+//!     }
+//! }
+//!
+//! let err = MyTool::from_vec(vec!["generate".into(), "zsh".into()]).unwrap_err();
+//! assert!(err.is_help());
+//! assert!(err.to_string().starts_with("#compdef my-tool"));
+//! ```
+//!
+//! Similarly, **builtin color** adds a `--color <when>` flag, which takes
+//! `auto`, `always` or `never`. [`Error::exit`] follows it when printing help
+//! and errors: `auto` colors only terminals, and only if `NO_COLOR` is not set.
+//! The flag is also accepted after subcommands. The generated `color_choice()`
+//! returns the parsed [`ColorChoice`], so that the rest of the output of the
+//! program can follow the same setting:
+//!
+//! ```
+//! xflags::xflags! {
+//!     cmd my-tool {
+//!         builtin color
+//!     }
+//! }
+//!
+//! let flags = MyTool::from_slice(&["--color", "never"]).unwrap();
+//! assert!(!flags.color_choice().enabled_for(&std::io::stdout()));
+//! ```
+//!
+//! For use by external tools, such as documentation generators or completion
+//! engines, the generated `spec_json()` function returns a JSON description of
+//! the whole grammar. The top-level object has a `version` field, which is
+//! incremented on incompatible changes of the format, and a `command` field
+//! with `name`, `aliases`, `deprecated_aliases`, `doc`, `default`, `args`,
+//! `flags`, and `subcommands` of the root command.
+//!
 //! The `parse_or_exit!` macro is a syntactic sure for `xflags!`, which
 //! immediately parses the argument, exiting the process if needed.
 //! `parse_or_exit` only supports single top-level command and doesn't need the
 //! `cmd`  keyword.
 //!
+//! It can have subcommands too. As the generated types can't be named, the
+//! subcommand is accessed with a method named after it, returning `None` if a
+//! different one was given. Names of generated methods, like `apply` or
+//! `usage`, can't be used for such subcommands:
+//!
+//! ```no_run
+//! let flags = xflags::parse_or_exit! {
+//!     repeated -v, --verbose
+//!     cmd build {
+//!         optional --release
+//!     }
+//!     cmd clean {}
+//! };
+//!
+//! if let Some(build) = flags.build() {
+//!     println!("building, release: {}", build.release);
+//! } else if flags.clean().is_some() {
+//!     println!("cleaning");
+//! }
+//! ```
+//!
+//! The `parse!` macro is like `parse_or_exit!`, but returns an
+//! [`xflags::Result`](Result) instead of exiting on errors. Optionally, the
+//! arguments to parse, excluding the program name, can be given with
+//! `args = ...;`, which is handy for tests:
+//!
+//! ```
+//! let flags = xflags::parse! {
+//!     args = vec!["-j".into(), "4".into()];
+//!     optional -j, --jobs n: u32
+//! };
+//! assert_eq!(flags.unwrap().jobs, Some(4));
+//!
+//! let err = xflags::parse! {
+//!     args = vec!["-j".into(), "many".into()];
+//!     optional -j, --jobs n: u32
+//! }
+//! .unwrap_err();
+//! assert_eq!(err.to_string(), "Invalid value `many` for `-j`: invalid digit found in string");
+//! ```
+//!
+//! The `run!` macro removes the rest of the `main` boilerplate: it defines the
+//! commands, parses the arguments, exits on errors, and dispatches on the
+//! subcommand. The name after `match` binds the parsed root command:
+//!
+//! ```no_run
+//! xflags::run! {
+//!     cmd app {
+//!         repeated -v, --verbose
+//!         cmd build {}
+//!         cmd test { repeated filter: String }
+//!     }
+//!     match app {
+//!         AppCmd::Build(_) => println!("building"),
+//!         AppCmd::Test(test) => println!("testing {:?} ({})", test.filter, app.verbose),
+//!     }
+//! }
+//! ```
+//!
+//! Like with `parse!`, the arguments can be given with `args = ...;` before the
+//! command, which makes the dispatch testable.
+//!
+//! With the `testing` cargo feature enabled, the `testing` module provides
+//! helpers for checking a grammar in the tests of a downstream crate: the
+//! `assert_parses!` and `assert_error_contains!` macros, snapshots of the help
+//! of each command, and `with_env`, which provides the variables read by
+//! `env-prefix` without changing the environment of the process.
+//!
 //! ## Limitations
 //!
 //! `xflags` follows
 //! [Fuchsia](https://fuchsia.dev/fuchsia-src/development/api/cli#command_line_arguments)
 //! conventions for command line arguments. GNU conventions such as grouping
 //! short-flags (`-xyz`) or gluing short flag and a value `(-fVAL)` are not
-//! supported.
+//! supported unless enabled with **settings**.
 //!
 //! `xflags` requires the command line interface to be fully static. It's
 //! impossible to include additional flags at runtime.
@@ -325,12 +885,15 @@
 //! Implementation is not fully robust, there might be some residual bugs in
 //! edge cases.
 
-use std::fmt;
+use std::{
+    ffi::{OsStr, OsString},
+    fmt, io,
+};
 
 /// Generates a parser for command line arguments from a DSL.
 ///
 /// See the module-level for detailed syntax specification.
-pub use xflags_macros::{parse_or_exit, xflags};
+pub use xflags_macros::{parse, parse_or_exit, run, xflags};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -342,6 +905,13 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub struct Error {
     msg: String,
     help: bool,
+    usage: Option<&'static str>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    arg: Option<(usize, OsString)>,
+    /// Set by `builtin color`, otherwise output is never colored.
+    color: Option<ColorChoice>,
+    /// Colored version of a `--help` message.
+    styled: Option<String>,
 }
 
 impl fmt::Display for Error {
@@ -350,14 +920,26 @@ impl fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|it| it as _)
+    }
+}
 
 impl Error {
     /// Creates a new `Error` from a given message.
     ///
     /// Use this to report custom validation errors.
     pub fn new(message: impl Into<String>) -> Error {
-        Error { msg: message.into(), help: false }
+        Error {
+            msg: message.into(),
+            help: false,
+            usage: None,
+            source: None,
+            arg: None,
+            color: None,
+            styled: None,
+        }
     }
 
     /// Error that carries `--help` message.
@@ -365,14 +947,56 @@ impl Error {
         self.help
     }
 
+    /// Usage line of the innermost command being parsed when the error
+    /// occurred.
+    pub fn usage(&self) -> Option<&'static str> {
+        self.usage
+    }
+
+    /// Position of the argument which caused the error.
+    ///
+    /// For `from_env`, this is an index into `std::env::args_os()`, where `0`
+    /// is the program name. For `from_vec`, this is an index into the vector.
+    pub fn arg_index(&self) -> Option<usize> {
+        self.arg.as_ref().map(|(idx, _)| *idx)
+    }
+
+    /// The raw argument which caused the error.
+    pub fn arg(&self) -> Option<&OsStr> {
+        self.arg.as_ref().map(|(_, arg)| arg.as_os_str())
+    }
+
     /// Prints the error and exists the process.
+    ///
+    /// Parse errors are followed by the usage line and a hint to run `--help`.
+    /// With `builtin color`, the output is colored as selected by `--color`.
     pub fn exit(self) -> ! {
+        std::process::exit(self.print().into())
+    }
+
+    /// Prints the error and returns the exit code for it.
+    fn print(&self) -> u8 {
         if self.is_help() {
-            println!("{self}");
-            std::process::exit(0)
+            match &self.styled {
+                Some(styled) if self.color.is_some_and(|it| it.enabled_for(&io::stdout())) => {
+                    println!("{styled}")
+                }
+                _ => println!("{self}"),
+            }
+            0
+        } else if self.color.is_some_and(|it| it.enabled_for(&io::stderr())) {
+            eprintln!("{RED}{self}{RESET}");
+            if let Some(usage) = self.usage {
+                let usage = style_usage(usage);
+                eprintln!("\n{usage}\n\nFor more information, try `{BOLD}--help{RESET}`.");
+            }
+            2
         } else {
             eprintln!("{self}");
-            std::process::exit(2)
+            if let Some(usage) = self.usage {
+                eprintln!("\n{usage}\n\nFor more information, try `--help`.");
+            }
+            2
         }
     }
 
@@ -383,6 +1007,439 @@ impl Error {
     }
 }
 
+/// A problem which the generated `from_vec_lenient` function tolerated,
+/// instead of failing with an [`Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    msg: String,
+    arg: Option<(usize, OsString)>,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.msg, f)
+    }
+}
+
+impl Warning {
+    /// Position of the argument which caused the warning, like
+    /// [`Error::arg_index`].
+    pub fn arg_index(&self) -> Option<usize> {
+        self.arg.as_ref().map(|(idx, _)| *idx)
+    }
+
+    /// The raw argument which caused the warning.
+    pub fn arg(&self) -> Option<&OsStr> {
+        self.arg.as_ref().map(|(_, arg)| arg.as_os_str())
+    }
+}
+
+/// Help message of a command, returned by the generated `help_message`
+/// functions.
+///
+/// The message is put together from static pieces when it is displayed, so
+/// that it can also be rendered differently, for example wrapped to the width
+/// of the terminal, using [`Help::usage`], [`Help::doc`] and
+/// [`Help::sections`].
+#[derive(Debug)]
+pub struct Help {
+    usage: &'static str,
+    doc: Option<&'static str>,
+    sections: &'static [HelpSection],
+}
+
+/// A list of entries in a [`Help`], like `Options:`.
+#[derive(Debug)]
+pub struct HelpSection {
+    /// Like `Options`, without the colon.
+    pub title: &'static str,
+    /// Names, like `-j, --jobs <n>`, together with their docs.
+    pub entries: &'static [(&'static str, &'static str)],
+}
+
+impl Help {
+    #[doc(hidden)]
+    pub const fn new(
+        usage: &'static str,
+        doc: Option<&'static str>,
+        sections: &'static [HelpSection],
+    ) -> Help {
+        Help { usage, doc, sections }
+    }
+
+    /// The usage line, like `Usage: app [-v]... <COMMAND>`.
+    pub fn usage(&self) -> &'static str {
+        self.usage
+    }
+
+    /// Doc comment of the command.
+    pub fn doc(&self) -> Option<&'static str> {
+        self.doc
+    }
+
+    /// `Arguments`, `Options` and `Commands`, the empty ones left out.
+    pub fn sections(&self) -> &'static [HelpSection] {
+        self.sections
+    }
+}
+
+impl Help {
+    /// The message with headings and names highlighted for a terminal.
+    pub(crate) fn styled(&self) -> String {
+        let mut res = String::new();
+        let _ = self.render(&mut res, true);
+        res
+    }
+
+    fn render(&self, f: &mut impl fmt::Write, color: bool) -> fmt::Result {
+        if color {
+            f.write_str(&style_usage(self.usage))?;
+        } else {
+            f.write_str(self.usage)?;
+        }
+        if let Some(doc) = self.doc {
+            write!(f, "\n\n{doc}\n")?;
+        }
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let title = section.title;
+            if color {
+                write!(f, "\n{HEADING}{title}:{RESET}")?;
+            } else {
+                write!(f, "\n{title}:")?;
+            }
+            for (name, doc) in section.entries {
+                if color {
+                    let pad = 20usize.saturating_sub(name.chars().count());
+                    write!(f, "\n  {BOLD}{name}{RESET}{:pad$} {doc}", "")?;
+                } else {
+                    write!(f, "\n  {name:<20} {doc}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Help {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, false)
+    }
+}
+
+const BOLD: &str = "\x1b[1m";
+const HEADING: &str = "\x1b[1;4m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Highlights the `Usage:` heading of a usage line.
+fn style_usage(usage: &str) -> String {
+    match usage.strip_prefix("Usage:") {
+        Some(rest) => format!("{HEADING}Usage:{RESET}{rest}"),
+        None => usage.to_string(),
+    }
+}
+
+/// Result of a successful parse, which distinguishes explicit `--help`
+/// requests from parsed flags.
+///
+/// Returned by the generated `from_env_outcome` and `from_vec_outcome`
+/// functions.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseOutcome<T> {
+    /// Arguments were parsed successfully.
+    Flags(T),
+    /// `--help` was requested, contains the help message.
+    Help(String),
+}
+
+impl<T> ParseOutcome<T> {
+    /// Converts a `--help` error into [`ParseOutcome::Help`], passing through
+    /// other errors.
+    pub fn from_result(res: Result<T>) -> Result<ParseOutcome<T>> {
+        match res {
+            Ok(flags) => Ok(ParseOutcome::Flags(flags)),
+            Err(err) if err.is_help() => Ok(ParseOutcome::Help(err.msg)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Where the value of a field came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueSource {
+    /// The value was specified on the command line.
+    CommandLine,
+    /// The value was read from an environment variable.
+    Env,
+    /// The value was read from the config file.
+    Config,
+    /// The value was entered interactively, see the `prompt` modifier.
+    Prompt,
+    /// The value wasn't specified, the field has its default value (`None`,
+    /// `false`, `0`, or an empty `Vec`).
+    Default,
+}
+
+/// Sources of values of all fields, returned by the generated
+/// `from_env_with_sources` and `from_vec_with_sources` functions.
+///
+/// `F` is the generated `Field` enum, which lists fields of the command and
+/// all of its subcommands.
+#[derive(Debug)]
+pub struct Sources<F> {
+    sources: Vec<ValueSource>,
+    field: std::marker::PhantomData<F>,
+}
+
+impl<F: Into<usize>> Sources<F> {
+    /// Returns the source of the value of the given field.
+    pub fn get(&self, field: F) -> ValueSource {
+        self.sources.get(field.into()).copied().unwrap_or(ValueSource::Default)
+    }
+
+    /// Whether the field was explicitly set by the user.
+    pub fn is_explicit(&self, field: F) -> bool {
+        self.get(field) != ValueSource::Default
+    }
+}
+
+/// Return type for `main` which reports errors the same way as [`Error::exit`].
+///
+/// ```no_run
+/// mod flags {
+///     xflags::xflags! {
+///         cmd hello {
+///             required name: String
+///         }
+///     }
+/// }
+///
+/// fn main() -> xflags::Exit {
+///     flags::Hello::from_env().map(|flags| println!("Hello {}!", flags.name)).into()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Exit<T = ()>(pub Result<T>);
+
+impl<T> From<Result<T>> for Exit<T> {
+    fn from(res: Result<T>) -> Exit<T> {
+        Exit(res)
+    }
+}
+
+impl<T> From<Error> for Exit<T> {
+    fn from(err: Error) -> Exit<T> {
+        Exit(Err(err))
+    }
+}
+
+impl<T: std::process::Termination> std::process::Termination for Exit<T> {
+    fn report(self) -> std::process::ExitCode {
+        match self.0 {
+            Ok(it) => it.report(),
+            Err(err) => std::process::ExitCode::from(err.print()),
+        }
+    }
+}
+
+/// Log level selected by the `-v` and `-q` flags, mirroring
+/// `log::LevelFilter`.
+///
+/// The generated `log_level()` function of commands declared with the
+/// `verbosity` keyword returns it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Maps the `verbosity` field to a level: `--quiet`, or any negative value,
+    /// turns logging off, otherwise each `--verbose` raises the level starting
+    /// from [`LogLevel::Warn`].
+    pub fn from_verbosity(verbosity: i32) -> LogLevel {
+        match verbosity {
+            i32::MIN..=-1 => LogLevel::Off,
+            0 => LogLevel::Warn,
+            1 => LogLevel::Info,
+            2 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+
+    /// Lowercase name of the level, which `log::LevelFilter` and most logging
+    /// libraries can parse.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Value of the `--color` flag, which `builtin color` declares.
+///
+/// The generated `color_choice()` function returns it, so that the output of
+/// the program can follow the same setting as help and errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Color terminals, unless the `NO_COLOR` environment variable is set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to color the output written to `stream`.
+    ///
+    /// ```
+    /// assert!(xflags::ColorChoice::Always.enabled_for(&std::io::stdout()));
+    /// ```
+    pub fn enabled_for(self, stream: &impl io::IsTerminal) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|it| it.is_empty()) && stream.is_terminal()
+            }
+        }
+    }
+
+    /// Name of the choice, as given to `--color`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ColorChoice::Auto => "auto",
+            ColorChoice::Always => "always",
+            ColorChoice::Never => "never",
+        }
+    }
+}
+
+impl std::str::FromStr for ColorChoice {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<ColorChoice> {
+        match text {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(Error::new("expected `auto`, `always` or `never`")),
+        }
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A number of bytes, parsed from values like `512`, `64k`, `2MiB` or `1.5G`.
+///
+/// Suffixes are case-insensitive. `k`, `KiB`, `m`, `MiB`, and so on up to
+/// exbibytes are powers of 1024, while `kB`, `MB`, ... are powers of 1000.
+///
+/// ```
+/// let size: xflags::ByteSize = "1.5k".parse().unwrap();
+/// assert_eq!(size.as_u64(), 1536);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> u64 {
+        size.0
+    }
+}
+
+impl std::str::FromStr for ByteSize {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<ByteSize> {
+        let text = text.trim();
+        let end = text.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(text.len());
+        let (number, suffix) = text.split_at(end);
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| Error::new("expected a size, like `512`, `64k` or `2MiB`"))?;
+        let suffix = suffix.trim_start();
+        let unknown = || {
+            Error::new(format!(
+                "unknown size suffix `{suffix}`, expected one like `k`, `MiB` or `GB`"
+            ))
+        };
+        let (exp, decimal) = match suffix.to_ascii_lowercase().as_bytes() {
+            [] | [b'b'] => (0, false),
+            [c, rest @ ..] => {
+                let exp = match b"kmgtpe".iter().position(|it| it == c) {
+                    Some(i) => i as i32 + 1,
+                    None => return Err(unknown()),
+                };
+                match rest {
+                    [] | b"ib" => (exp, false),
+                    b"b" => (exp, true),
+                    _ => return Err(unknown()),
+                }
+            }
+        };
+        let base: f64 = if decimal { 1000.0 } else { 1024.0 };
+        let bytes = (number * base.powi(exp)).round();
+        if bytes >= u64::MAX as f64 {
+            return Err(Error::new("size is too large"));
+        }
+        Ok(ByteSize(bytes as u64))
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut n = self.0;
+        for unit in ["B", "KiB", "MiB", "GiB", "TiB", "PiB"] {
+            if n == 0 || !n.is_multiple_of(1024) {
+                return write!(f, "{n}{unit}");
+            }
+            n /= 1024;
+        }
+        write!(f, "{n}EiB")
+    }
+}
+
 /// Private impl details for macros.
 #[doc(hidden)]
 pub mod rt;
+
+#[cfg(feature = "config")]
+mod config;
+mod dotenv;
+mod duration;
+mod glob;
+#[cfg(feature = "prompt")]
+mod prompt;
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,59 @@
+//! Interactive input of missing values.
+
+use std::io::{self, IsTerminal, Write};
+
+/// Reads a line from the terminal, returning `None` if stdin isn't a terminal,
+/// the input is empty, or the input can't be hidden when `hidden` is set.
+pub(crate) fn read(label: &str, hidden: bool) -> io::Result<Option<String>> {
+    let stdin = io::stdin();
+    if !stdin.is_terminal() {
+        return Ok(None);
+    }
+    let _echo = if hidden {
+        match EchoOff::new() {
+            Some(it) => Some(it),
+            None => return Ok(None),
+        }
+    } else {
+        None
+    };
+
+    eprint!("{label}: ");
+    io::stderr().flush()?;
+    let mut line = String::new();
+    stdin.read_line(&mut line)?;
+    if hidden {
+        eprintln!();
+    }
+
+    let value = line.trim_end_matches(['\n', '\r']);
+    Ok(if value.is_empty() { None } else { Some(value.to_string()) })
+}
+
+/// Disables terminal echo until dropped.
+struct EchoOff(());
+
+impl EchoOff {
+    #[cfg(unix)]
+    fn new() -> Option<EchoOff> {
+        stty("-echo").then_some(EchoOff(()))
+    }
+
+    #[cfg(not(unix))]
+    fn new() -> Option<EchoOff> {
+        None
+    }
+}
+
+impl Drop for EchoOff {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        stty("echo");
+    }
+}
+
+#[cfg(unix)]
+fn stty(arg: &str) -> bool {
+    let Ok(tty) = std::fs::File::open("/dev/tty") else { return false };
+    std::process::Command::new("stty").arg(arg).stdin(tty).status().is_ok_and(|it| it.success())
+}
@@ -1,10 +1,16 @@
-use std::{ffi::OsString, fmt, str::FromStr};
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::FromStr,
+    sync::Mutex,
+};
 
-use crate::{Error, Result};
+use crate::{ColorChoice, Error, Help, Result, Sources, ValueSource, Warning};
 
 macro_rules! format_err {
     ($($tt:tt)*) => {
-        Error { msg: format!($($tt)*), help: false }
+        Error::new(format!($($tt)*))
     };
 }
 
@@ -16,27 +22,183 @@ macro_rules! bail {
 
 pub struct Parser {
     after_double_dash: bool,
-    rargs: Vec<OsString>,
+    /// Set after the first positional argument with `strict-ordering`.
+    after_positional: bool,
+    /// The arguments as given. They are moved out one by one, without copying
+    /// or reordering, so that only the stored values are allocated.
+    args: Vec<OsString>,
+    /// Position of the next argument in `args`.
+    pos: usize,
+    /// Arguments returned by `push_back`, to be processed before `args`.
+    pushed_back: Vec<(usize, OsString)>,
+    /// Position of the `help` command, which is processed as `--help` after
+    /// all the other arguments.
+    help: Option<usize>,
+    /// Set once the `--help` of the `help` command is handed out, so that it
+    /// is recognized even after a positional argument with `strict-ordering`.
+    help_taken: bool,
+    /// Position of the most recently popped argument, `None` while processing
+    /// fallback values.
+    current: Option<usize>,
+    sources: Vec<ValueSource>,
+    /// Where to look for values not specified on the command line, in the
+    /// order of precedence.
+    layers: Vec<Layer>,
+    /// Environment variable of the most recent fallback value, if it came
+    /// from one.
+    fallback_var: Option<String>,
+    /// Problems tolerated in lenient mode, `None` if they are errors.
+    warnings: Option<Vec<Warning>>,
+    /// Value of `--color`, `None` without `builtin color`.
+    color: Option<ColorChoice>,
+}
+
+#[derive(Clone)]
+struct Layer {
+    source: ValueSource,
+    /// Prefix of the variables of `env-prefix` layers, for error messages.
+    env_prefix: Option<String>,
+    /// Path of the mounted command, which the layer was inherited by, in the
+    /// command tree of the layer.
+    scope: Vec<&'static str>,
+    /// Looks up values of a flag, given the path of the command it belongs to.
+    lookup: Rc<Lookup>,
+}
+
+type Lookup = dyn Fn(&[&str], &str) -> Option<Vec<OsString>>;
+
+fn env_var_name(prefix: &str, path: &[&str], flag: &str) -> String {
+    let mut res = prefix.to_string();
+    for name in path.iter().copied().chain([flag]) {
+        if !res.is_empty() && !res.ends_with('_') {
+            res.push('_');
+        }
+        res.extend(name.chars().map(|c| if c == '-' { '_' } else { c.to_ascii_uppercase() }));
+    }
+    res
+}
+
+/// Reads an environment variable, or its value given to `testing::with_env`.
+fn env_var(name: &str) -> Option<OsString> {
+    #[cfg(feature = "testing")]
+    if let Some(value) = crate::testing::env_var(name) {
+        return value;
+    }
+    std::env::var_os(name)
+}
+
+/// Quotes an argument for an error message. Arguments which are not valid
+/// UTF-8 are shown with replacement characters, followed by a note.
+fn quote(arg: &OsStr) -> String {
+    match arg.to_str() {
+        Some(it) => format!("`{it}`"),
+        None => format!("`{}` (not valid UTF-8)", arg.to_string_lossy()),
+    }
+}
+
+/// Entries of the `Commands:` section of help which are shared by all
+/// commands.
+pub const HELP_ENTRY: (&str, &str) =
+    ("help", "Print this message or the help of the given subcommand(s)");
+pub const GENERATE_ENTRY: (&str, &str) =
+    ("generate", "Print shell completions (bash, zsh, fish) or a man page (man)");
+
+/// Text appended to the help of the root command, set at runtime with the
+/// generated `set_help_epilogue`.
+#[derive(Default)]
+pub struct Epilogue(Mutex<Option<String>>);
+
+impl Epilogue {
+    pub const fn new() -> Epilogue {
+        Epilogue(Mutex::new(None))
+    }
+
+    pub fn set(&self, text: String) {
+        *self.0.lock().unwrap_or_else(|it| it.into_inner()) = Some(text);
+    }
+}
+
+/// Kinds of `PathBuf` values which are validated while parsing.
+#[derive(Debug, Clone, Copy)]
+pub enum PathCheck {
+    ExistingFile,
+    ExistingDir,
+    NewPath,
+}
+
+/// Implemented by the generated top-level commands, so that they can be
+/// mounted into commands defined elsewhere.
+pub trait Mount: Sized {
+    fn parse_mounted(p: &mut Parser) -> Result<Self>;
 }
 
 impl Parser {
-    pub fn new(mut args: Vec<OsString>) -> Self {
-        // parse `help` command last when encountered somewhere along the way to be able to do
-        // `help <commands>` or `cmd help sub` without creating a bunch of leafs in the parse tree for it
-        if let Some((i, _)) = args.iter().enumerate().find(|(_, arg)| *arg == "help") {
-            args.remove(i);
-            args.push("--help".into())
+    pub fn new(args: Vec<OsString>) -> Self {
+        Self {
+            after_double_dash: false,
+            after_positional: false,
+            args,
+            pos: 0,
+            pushed_back: Vec::new(),
+            help: None,
+            help_taken: false,
+            current: None,
+            sources: Vec::new(),
+            layers: Vec::new(),
+            fallback_var: None,
+            warnings: None,
+            color: None,
         }
+    }
 
-        args.reverse();
+    /// Parser which reports unknown flags and repeated values as warnings.
+    pub fn new_lenient(args: Vec<OsString>) -> Self {
+        Parser { warnings: Some(Vec::new()), ..Parser::new(args) }
+    }
 
-        Self { after_double_dash: false, rargs: args }
+    pub fn new_from_slice(args: &[&str]) -> Self {
+        Parser::new(args.iter().map(OsString::from).collect())
     }
 
-    pub fn new_from_env() -> Self {
+    pub fn new_from_env() -> Result<Self> {
+        // There's no way to pass arguments to a `wasm32-unknown-unknown` module,
+        // `args_os` is always empty there.
+        if cfg!(all(target_family = "wasm", target_os = "unknown")) {
+            bail!("command line arguments are not available on this platform, use `from_vec`")
+        }
         let args = std::env::args_os().collect::<Vec<_>>();
         let mut res = Parser::new(args);
         let _progn = res.next();
+        Ok(res)
+    }
+
+    /// Parses the rest of the arguments as the mounted command `T`, which is
+    /// at `path` in the command tree. Values of its flags which are missing
+    /// from the command line are also looked up as if they were declared
+    /// there.
+    pub fn mount<T: Mount>(&mut self, path: &[&'static str]) -> Result<T> {
+        let layers = self
+            .layers
+            .iter()
+            .map(|layer| Layer { scope: [&layer.scope[..], path].concat(), ..layer.clone() })
+            .collect();
+        let mut p = Parser {
+            after_double_dash: self.after_double_dash,
+            after_positional: self.after_positional,
+            args: std::mem::take(&mut self.args),
+            pos: self.pos,
+            pushed_back: std::mem::take(&mut self.pushed_back),
+            help: self.help.take(),
+            help_taken: self.help_taken,
+            current: self.current,
+            sources: Vec::new(),
+            layers,
+            fallback_var: None,
+            warnings: self.warnings.take(),
+            color: self.color,
+        };
+        let res = T::parse_mounted(&mut p);
+        self.warnings = p.warnings;
         res
     }
 
@@ -45,9 +207,11 @@ impl Parser {
             self.next().map(Err)
         } else {
             let arg = self.next()?;
-            let arg_str = arg.to_str().unwrap_or_default();
-            if arg_str.starts_with('-') {
-                if arg_str == "--" {
+            // Only flags are checked to be valid UTF-8, values are kept as is.
+            let bytes = arg.as_encoded_bytes();
+            let deferred_help = self.help_taken && bytes == b"--help";
+            if bytes.starts_with(b"-") && (!self.after_positional || deferred_help) {
+                if bytes == b"--" {
                     self.after_double_dash = true;
                     return self.next().map(Err);
                 }
@@ -58,25 +222,66 @@ impl Parser {
         }
     }
 
+    /// Position of `flag` in the sorted list of known `flags`, or `usize::MAX`
+    /// if it is unknown.
+    pub fn flag_index(&self, flag: &str, flags: &[&str]) -> usize {
+        flags.binary_search(&flag).unwrap_or(usize::MAX)
+    }
+
+    /// Treats the rest of the arguments as positional ones.
+    pub fn end_of_flags(&mut self) {
+        self.after_positional = true;
+    }
+
     pub fn push_back(&mut self, arg: Result<String, OsString>) {
         let arg = match arg {
             Ok(it) => it.into(),
             Err(it) => it,
         };
-        self.rargs.push(arg)
+        self.pushed_back.push((self.current.unwrap_or_default(), arg))
+    }
+
+    /// Handles the `help` command. The subcommands following it are parsed as
+    /// usual, and then `--help` prints the help of the innermost one.
+    pub fn defer_help(&mut self) {
+        if self.help.is_none() {
+            self.help = Some(self.current.unwrap_or_default());
+        }
     }
 
     fn next(&mut self) -> Option<OsString> {
-        self.rargs.pop()
+        let (idx, arg) = match self.pushed_back.pop() {
+            Some(it) => it,
+            None => match self.args.get_mut(self.pos) {
+                Some(arg) => {
+                    self.pos += 1;
+                    (self.pos - 1, std::mem::take(arg))
+                }
+                None => {
+                    let idx = self.help.take()?;
+                    self.help_taken = true;
+                    (idx, "--help".into())
+                }
+            },
+        };
+        self.current = Some(idx);
+        Some(arg)
+    }
+
+    /// Attaches the most recently popped argument to the error.
+    fn at(&self, mut err: Error, arg: OsString) -> Error {
+        err.arg = self.current.map(|idx| (idx, arg));
+        err
     }
 
     pub fn next_value(&mut self, flag: &str) -> Result<OsString> {
-        self.next().ok_or_else(|| format_err!("expected a value for `{flag}`"))
+        self.next()
+            .ok_or_else(|| self.at(format_err!("expected a value for `{flag}`"), flag.into()))
     }
 
     pub fn next_value_from_str<T: FromStr>(&mut self, flag: &str) -> Result<T>
     where
-        T::Err: fmt::Display,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
     {
         let value = self.next_value(flag)?;
         self.value_from_str(flag, value)
@@ -84,50 +289,421 @@ impl Parser {
 
     pub fn value_from_str<T: FromStr>(&mut self, flag: &str, value: OsString) -> Result<T>
     where
-        T::Err: fmt::Display,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
     {
         match value.into_string() {
-            Ok(str) => str.parse::<T>().map_err(|err| format_err!("Can't parse `{flag}`, {err}")),
+            Ok(str) => str.parse::<T>().map_err(|err| {
+                let source = err.into();
+                let mut res = format_err!("Invalid value `{str}` for `{flag}`: {source}");
+                res.source = Some(source);
+                self.at(res, str.into())
+            }),
             Err(it) => {
-                bail!("Can't parse `{flag}`, invalid utf8: {it:?}")
+                let err = format_err!(
+                    "Invalid value `{}` for `{flag}`: not valid UTF-8",
+                    it.to_string_lossy()
+                );
+                Err(self.at(err, it))
             }
         }
     }
 
-    pub fn unexpected_flag(&self, flag: &str) -> Error {
-        format_err!("Unknown flag: `{flag}`. Use `help` for more information")
+    pub fn next_value_from_os_str<T>(&mut self, flag: &str) -> Result<T>
+    where
+        T: for<'a> TryFrom<&'a OsStr>,
+        for<'a> <T as TryFrom<&'a OsStr>>::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let value = self.next_value(flag)?;
+        self.value_from_os_str(flag, value)
     }
 
-    pub fn unexpected_arg(&self, arg: OsString) -> Error {
-        // `to_string_lossy()` seems appropriate here but OsString's debug implementation actually
-        // escapes codes that are not valid utf-8, rather than replace them with `FFFD`
-        let dbg = format!("{arg:?}");
-        let arg = dbg.trim_matches('"');
+    pub fn value_from_os_str<T>(&mut self, flag: &str, value: OsString) -> Result<T>
+    where
+        T: for<'a> TryFrom<&'a OsStr>,
+        for<'a> <T as TryFrom<&'a OsStr>>::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        T::try_from(value.as_os_str()).map_err(|err| {
+            let source = err.into();
+            let mut res = format_err!("Invalid value {} for `{flag}`: {source}", quote(&value));
+            res.source = Some(source);
+            self.at(res, value.clone())
+        })
+    }
+
+    /// Parses the value of a `bool` switch, like `--color=no`.
+    pub fn value_bool(&self, flag: &str, value: OsString) -> Result<bool> {
+        match value.to_str().map(|it| it.to_ascii_lowercase()).as_deref() {
+            Some("true" | "yes" | "on" | "1") => Ok(true),
+            Some("false" | "no" | "off" | "0") => Ok(false),
+            _ => {
+                let err = format_err!(
+                    "Invalid value {} for `{flag}`: expected one of: true, false, yes, no, on, off, 1, 0",
+                    quote(&value)
+                );
+                Err(self.at(err, value))
+            }
+        }
+    }
+
+    pub fn next_value_duration(&mut self, flag: &str) -> Result<std::time::Duration> {
+        let value = self.next_value(flag)?;
+        self.value_duration(flag, value)
+    }
+
+    pub fn value_duration(&mut self, flag: &str, value: OsString) -> Result<std::time::Duration> {
+        self.value_from_str::<crate::duration::DurationValue>(flag, value).map(|it| it.0)
+    }
+
+    pub fn next_checked_path(&mut self, flag: &str, check: PathCheck) -> Result<PathBuf> {
+        let value = self.next_value(flag)?;
+        self.checked_path(flag, value, check)
+    }
+
+    pub fn checked_path(&self, flag: &str, value: OsString, check: PathCheck) -> Result<PathBuf> {
+        let path = Path::new(&value);
+        let problem = match check {
+            PathCheck::ExistingFile if !path.exists() => Some("file does not exist"),
+            PathCheck::ExistingFile if path.is_dir() => Some("expected a file, found a directory"),
+            PathCheck::ExistingDir if !path.exists() => Some("directory does not exist"),
+            PathCheck::ExistingDir if !path.is_dir() => Some("expected a directory, found a file"),
+            PathCheck::NewPath if path.exists() => Some("path already exists"),
+            PathCheck::NewPath => match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => {
+                    Some("parent directory does not exist")
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        match problem {
+            Some(problem) => {
+                let err = format_err!("Invalid value `{}` for `{flag}`: {problem}", path.display());
+                Err(self.at(err, value))
+            }
+            None => Ok(value.into()),
+        }
+    }
 
-        format_err!("Unknown command: `{arg}`. Use `help` for more information")
+    /// Expands a glob pattern in a positional argument. Like in shells, a
+    /// pattern which doesn't match anything is kept as is.
+    /// Expands a glob pattern in a positional argument. A pattern which
+    /// doesn't match anything is kept as is, like shells do.
+    pub fn glob(&self, pattern: OsString, windows_only: bool) -> Vec<PathBuf> {
+        // Lenient expansion never fails.
+        let res = self.glob_value("", pattern.clone(), windows_only, true).unwrap_or_default();
+        if res.is_empty() {
+            return vec![pattern.into()];
+        }
+        res
+    }
+
+    pub fn next_glob(
+        &mut self,
+        flag: &str,
+        windows_only: bool,
+        lenient: bool,
+    ) -> Result<Vec<PathBuf>> {
+        let value = self.next_value(flag)?;
+        self.glob_value(flag, value, windows_only, lenient)
+    }
+
+    /// Expands a glob pattern in the value of a flag. Unless `lenient`, a
+    /// pattern which doesn't match anything is an error.
+    pub fn glob_value(
+        &self,
+        flag: &str,
+        pattern: OsString,
+        windows_only: bool,
+        lenient: bool,
+    ) -> Result<Vec<PathBuf>> {
+        if (windows_only && !cfg!(windows)) || !crate::glob::is_pattern(&pattern) {
+            return Ok(vec![pattern.into()]);
+        }
+        let res = crate::glob::expand(&pattern);
+        if res.is_empty() && !lenient {
+            let err = format_err!(
+                "Invalid value {} for `{flag}`: no files match the pattern",
+                quote(&pattern)
+            );
+            return Err(self.at(err, pattern));
+        }
+        Ok(res)
+    }
+
+    /// Fails on an unknown flag, unless in lenient mode.
+    pub fn unexpected_flag(&mut self, flag: &str) -> Result<()> {
+        let err = self.at(format_err!("Unknown flag: `{flag}`"), flag.into());
+        self.warn(err)
+    }
+
+    /// Records `err` as a warning in lenient mode, or returns it.
+    fn warn(&mut self, err: Error) -> Result<()> {
+        match &mut self.warnings {
+            Some(warnings) => {
+                warnings.push(Warning { msg: err.msg, arg: err.arg });
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
+    /// Notes the use of a deprecated name of a subcommand. The notice is
+    /// printed to stderr, or collected as a warning in lenient mode.
+    pub fn deprecated(&mut self, old: &str, new: &str) {
+        let err = self.at(format_err!("`{old}` is deprecated, use `{new}` instead"), old.into());
+        if self.warn(err).is_err() {
+            eprintln!("warning: `{old}` is deprecated, use `{new}` instead");
+        }
+    }
+
+    pub fn warnings(&mut self) -> Vec<Warning> {
+        self.warnings.take().unwrap_or_default()
+    }
+
+    pub fn unexpected_arg(&self, arg: OsString) -> Error {
+        // Flags which are not valid UTF-8 end up here as well.
+        let kind = if arg.as_encoded_bytes().starts_with(b"-") && !self.after_double_dash {
+            "flag"
+        } else {
+            "command"
+        };
+        let err = format_err!("Unknown {kind}: {}", quote(&arg));
+        self.at(err, arg)
     }
 
     pub fn subcommand_required(&self) -> Error {
-        format_err!("A subcommand is required. Use `help` for more information")
+        format_err!("A subcommand is required")
+    }
+
+    /// Output of the `generate` builtin, which is reported like `--help`.
+    pub fn generated(&self, what: OsString, outputs: &[(&str, &'static str)]) -> Error {
+        match outputs.iter().find(|(name, _)| what == *name) {
+            Some((_, text)) => Error { help: true, ..Error::new(*text) },
+            None => {
+                let names = outputs.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ");
+                let err =
+                    format_err!("Unknown generator {}, expected one of: {names}", quote(&what));
+                self.at(err, what)
+            }
+        }
+    }
+
+    pub fn help(&self, help: &Help) -> Error {
+        self.help_with(help, None)
     }
 
-    pub fn help(&self, help: &'static str) -> Error {
-        Error { msg: help.to_string(), help: true }
+    pub fn help_with_epilogue(&self, help: &Help, epilogue: &Epilogue) -> Error {
+        let epilogue = epilogue.0.lock().unwrap_or_else(|it| it.into_inner());
+        self.help_with(help, epilogue.as_deref())
     }
 
-    pub fn optional<T>(&self, flag: &str, mut vals: Vec<T>) -> Result<Option<T>> {
-        if vals.len() > 1 {
-            bail!("Flag specified more than once: `{flag}`")
+    fn help_with(&self, help: &Help, epilogue: Option<&str>) -> Error {
+        let mut msg = help.to_string();
+        let mut styled = self.color.map(|_| help.styled());
+        if let Some(text) = epilogue {
+            for it in std::iter::once(&mut msg).chain(styled.as_mut()) {
+                it.push_str("\n\n");
+                it.push_str(text.trim_end());
+            }
         }
-        Ok(vals.pop())
+        Error { help: true, color: self.color, styled, ..Error::new(msg) }
     }
 
-    pub fn required<T>(&self, flag: &str, mut vals: Vec<T>) -> Result<T> {
-        if vals.len() > 1 {
-            bail!("Flag specified more than once: `{flag}`")
+    /// Looks ahead for the value of `--color`, so that it also applies to
+    /// errors about the arguments before it. With `flag_assign`, the value
+    /// can also be attached, as in `--color=never`.
+    pub fn scan_color(&mut self, flag_assign: bool) {
+        let mut color = ColorChoice::Auto;
+        let mut args = self.args[self.pos..].iter();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            let value = if arg == "--color" {
+                args.next().and_then(|it| it.to_str())
+            } else if flag_assign {
+                arg.to_str().and_then(|it| it.strip_prefix("--color="))
+            } else {
+                None
+            };
+            if let Some(value) = value.and_then(|it| it.parse().ok()) {
+                color = value;
+            }
         }
-        vals.pop().ok_or_else(|| {
-            format_err!("Flag is required: `{flag}`. Use `help` for more information")
-        })
+        self.color = Some(color);
+    }
+
+    pub fn with_usage(&self, mut err: Error, usage: &'static str) -> Error {
+        if !err.help && err.usage.is_none() && !usage.is_empty() {
+            err.usage = Some(usage);
+        }
+        if err.color.is_none() {
+            err.color = self.color;
+        }
+        err
+    }
+
+    pub fn set_source(&mut self, field: usize, source: ValueSource) {
+        if self.sources.len() <= field {
+            self.sources.resize(field + 1, ValueSource::Default);
+        }
+        self.sources[field] = source;
+    }
+
+    /// Reads values of flags from environment variables like
+    /// `PREFIX_SUBCOMMAND_FLAG_NAME`. Empty variables are ignored.
+    pub fn load_env(&mut self, prefix: &str) {
+        let env_prefix = Some(prefix.to_string());
+        let prefix = prefix.to_string();
+        let lookup = move |path: &[&str], flag: &str| {
+            let var = env_var_name(&prefix, path, flag);
+            env_var(&var).filter(|it| !it.is_empty()).map(|it| vec![it])
+        };
+        self.push_layer(ValueSource::Env, env_prefix, lookup);
+    }
+
+    /// Reads variables for [`Parser::load_env`] from a `.env` file, a missing
+    /// file is ignored. Variables set in the environment take precedence.
+    pub fn load_dotenv(&mut self, prefix: &str, path: &str) -> Result<()> {
+        let vars = crate::dotenv::load(std::path::Path::new(path))?;
+        let env_prefix = Some(prefix.to_string());
+        let prefix = prefix.to_string();
+        let lookup = move |path: &[&str], flag: &str| {
+            let var = env_var_name(&prefix, path, flag);
+            vars.get(&var).filter(|it| !it.is_empty()).map(|it| vec![OsString::from(it)])
+        };
+        self.push_layer(ValueSource::Env, env_prefix, lookup);
+        Ok(())
+    }
+
+    #[cfg(feature = "config")]
+    pub fn load_config(&mut self, path: &str) -> Result<()> {
+        let config = crate::config::Config::load(std::path::Path::new(path))?;
+        let lookup = move |path: &[&str], flag: &str| {
+            config.get(path, flag).map(|values| values.iter().map(OsString::from).collect())
+        };
+        self.push_layer(ValueSource::Config, None, lookup);
+        Ok(())
+    }
+
+    fn push_layer<F>(&mut self, source: ValueSource, env_prefix: Option<String>, lookup: F)
+    where
+        F: Fn(&[&str], &str) -> Option<Vec<OsString>> + 'static,
+    {
+        self.layers.push(Layer { source, env_prefix, scope: Vec::new(), lookup: Rc::new(lookup) });
+    }
+
+    /// Asks the user for the value of a missing flag, if stdin is a terminal.
+    #[cfg(feature = "prompt")]
+    pub fn prompt(&mut self, field: usize, flag: &str, hidden: bool) -> Result<Option<OsString>> {
+        let value = crate::prompt::read(flag, hidden)
+            .map_err(|err| format_err!("failed to read a value for `--{flag}`: {err}"))?;
+        if value.is_some() {
+            self.set_source(field, ValueSource::Prompt);
+        }
+        Ok(value.map(OsString::from))
+    }
+
+    /// Looks up values for a flag which wasn't specified on the command line.
+    pub fn fallback(&mut self, field: usize, path: &[&str], flag: &str) -> Vec<OsString> {
+        self.current = None;
+        self.fallback_var = None;
+        let found = self.layers.iter().find_map(|layer| {
+            let path = [&layer.scope[..], path].concat();
+            let values = (layer.lookup)(&path, flag)?;
+            let var = layer.env_prefix.as_ref().map(|it| env_var_name(it, &path, flag));
+            Some((layer.source, var, values))
+        });
+        match found {
+            Some((source, var, values)) => {
+                self.fallback_var = var;
+                self.set_source(field, source);
+                values
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Interprets fallback values of a switch as the number of times it was
+    /// specified. An `optional` switch can only be specified once.
+    pub fn switch_count(
+        &mut self,
+        flag: &str,
+        values: Vec<OsString>,
+        optional: bool,
+    ) -> Result<u32> {
+        let mut res = 0u32;
+        for value in values {
+            let n = match value.to_str() {
+                Some("true") => Some(1),
+                Some("false") => Some(0),
+                Some(n) => n.parse::<u32>().ok(),
+                None => None,
+            };
+            let problem = match n.map(|n| res.checked_add(n)) {
+                Some(Some(n)) if optional && n > 1 => "can be specified only once",
+                Some(Some(n)) => {
+                    res = n;
+                    continue;
+                }
+                Some(None) => "the count is too large",
+                None => "expected `true`, `false`, or a count",
+            };
+            let from = match &self.fallback_var {
+                Some(var) => format!(" from `{var}`"),
+                None => String::new(),
+            };
+            let err = format_err!("Invalid value {} for `{flag}`{from}: {problem}", quote(&value));
+            return Err(self.at(err, value));
+        }
+        Ok(res)
+    }
+
+    pub fn sources<F>(&mut self) -> Sources<F> {
+        Sources { sources: std::mem::take(&mut self.sources), field: std::marker::PhantomData }
+    }
+
+    /// Checks that a flag or an argument which can't be repeated was given
+    /// at most once. In lenient mode, the last value is used instead.
+    pub fn check_optional(&mut self, name: &str, n: usize) -> Result<()> {
+        if n > 1 {
+            self.warn(format_err!("Flag specified more than once: `{name}`"))?;
+        }
+        Ok(())
+    }
+
+    pub fn check_exclusive(&self, a: &str, n_a: usize, b: &str, n_b: usize) -> Result<()> {
+        if n_a > 0 && n_b > 0 {
+            bail!("`{a}` can't be used together with `{b}`");
+        }
+        Ok(())
+    }
+
+    /// Keeps the position of a positional argument, which is assigned to one
+    /// of the arguments after all of them are seen.
+    pub fn defer_arg(&self, arg: OsString) -> (Option<usize>, OsString) {
+        (self.current, arg)
+    }
+
+    /// Makes errors about a deferred argument point to it.
+    pub fn resume_arg(&mut self, (idx, arg): (Option<usize>, OsString)) -> OsString {
+        self.current = idx;
+        arg
+    }
+
+    /// Checks that an argument declared with `repeated!` was given.
+    pub fn check_non_empty(&self, name: &str, n: usize) -> Result<()> {
+        if n == 0 {
+            bail!("expected at least one <{name}>")
+        }
+        Ok(())
+    }
+
+    pub fn check_required(&mut self, name: &str, n: usize) -> Result<()> {
+        self.check_optional(name, n)?;
+        if n == 0 {
+            bail!("Flag is required: `{name}`")
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,125 @@
+//! Helpers for testing grammars, available with the `testing` cargo feature.
+//!
+//! They work with any top-level command declared with `xflags!`, and take the
+//! command line as a single string, which is split on whitespace:
+//!
+//! ```ignore
+//! use xflags::testing::{assert_snapshot, help};
+//!
+//! xflags::assert_parses!("build --release", Cli { release: true, .. });
+//! xflags::assert_error_contains!(Cli, "build --jobs", "expected a value");
+//! assert_snapshot("tests/help/build.txt", &help::<Cli>("build"));
+//! ```
+
+use std::{cell::RefCell, collections::HashMap, env, ffi::OsString, fmt, fs, io, path::Path};
+
+use crate::{
+    rt::{Mount, Parser},
+    Error, Result,
+};
+
+/// Parses `args` as the command `T`, like the generated `from_vec`.
+pub fn parse<T: Mount>(args: &str) -> Result<T> {
+    let mut p = Parser::new(args.split_whitespace().map(Into::into).collect());
+    T::parse_mounted(&mut p)
+}
+
+/// The error reported for `args`, panicking if they are accepted.
+#[track_caller]
+pub fn error<T: Mount + fmt::Debug>(args: &str) -> Error {
+    match parse::<T>(args) {
+        Ok(flags) => panic!("`{args}` was expected to fail, but parsed as {flags:#?}"),
+        Err(err) => err,
+    }
+}
+
+/// The `--help` message of the subcommand `path`, or of `T` itself if the
+/// `path` is empty.
+#[track_caller]
+pub fn help<T: Mount + fmt::Debug>(path: &str) -> String {
+    let err = error::<T>(&format!("{path} --help"));
+    assert!(err.is_help(), "`{path} --help` failed instead of printing help: {err}");
+    err.to_string()
+}
+
+thread_local! {
+    static ENV: RefCell<Option<HashMap<String, OsString>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `vars` as the only environment variables seen by commands
+/// with `env-prefix`. Unlike `std::env::set_var`, this affects only the
+/// current thread, so it's safe in tests running in parallel.
+pub fn with_env<R>(vars: &[(&str, &str)], f: impl FnOnce() -> R) -> R {
+    let vars = vars.iter().map(|&(name, value)| (name.to_string(), value.into())).collect();
+    let prev = ENV.replace(Some(vars));
+    let res = f();
+    ENV.set(prev);
+    res
+}
+
+/// The value of the variable `name` within `with_env`, or `None` outside of
+/// it.
+pub(crate) fn env_var(name: &str) -> Option<Option<OsString>> {
+    ENV.with_borrow(|vars| vars.as_ref().map(|it| it.get(name).cloned()))
+}
+
+/// Compares `actual` with the contents of the file at `path`, which is
+/// relative to the root of the crate being tested.
+///
+/// With the `UPDATE_XFLAGS` environment variable set, the file is written
+/// instead, like the generated code of the `src` keyword.
+#[track_caller]
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let actual = format!("{}\n", actual.trim_end());
+    if env::var_os("UPDATE_XFLAGS").is_some() {
+        let res = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => fs::create_dir_all(dir),
+            _ => Ok(()),
+        };
+        if let Err(err) = res.and_then(|()| fs::write(path, &actual)) {
+            panic!("failed to write {}: {err}", path.display());
+        }
+        return;
+    }
+    match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => (),
+        Ok(expected) => panic!(
+            "snapshot {} is outdated, run with `UPDATE_XFLAGS=1` to update it\n\n\
+             expected:\n{expected}\nactual:\n{actual}",
+            path.display()
+        ),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => panic!(
+            "snapshot {} is missing, run with `UPDATE_XFLAGS=1` to create it\n\nactual:\n{actual}",
+            path.display()
+        ),
+        Err(err) => panic!("failed to read {}: {err}", path.display()),
+    }
+}
+
+/// Asserts that the command line parses into a value matching the pattern.
+///
+/// The type of the command is inferred from the pattern, so it should name a
+/// struct, like `Cli { release: true, .. }`. An `if` guard is allowed too.
+#[macro_export]
+macro_rules! assert_parses {
+    ($args:expr, $pat:pat $(if $guard:expr)? $(,)?) => {{
+        let args: &str = $args;
+        match $crate::testing::parse(args) {
+            Ok($pat) $(if $guard)? => (),
+            Ok(flags) => panic!("`{}` parsed as {:#?}", args, flags),
+            Err(err) => panic!("`{}` failed to parse: {}", args, err),
+        }
+    }};
+}
+
+/// Asserts that the command `$ty` rejects the command line, with an error
+/// message containing the given text.
+#[macro_export]
+macro_rules! assert_error_contains {
+    ($ty:ty, $args:expr, $text:expr $(,)?) => {{
+        let (args, text): (&str, &str) = ($args, $text);
+        let msg = $crate::testing::error::<$ty>(args).to_string();
+        assert!(msg.contains(text), "error for `{}` doesn't contain `{}`: {}", args, text, msg);
+    }};
+}
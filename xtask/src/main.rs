@@ -20,6 +20,13 @@ fn main() -> xshell::Result<()> {
         cmd!(sh, "cargo test --workspace --no-run").run()?;
     }
 
+    {
+        let _s = section("WASM");
+        cmd!(sh, "rustup target add wasm32-wasip1 wasm32-unknown-unknown").run()?;
+        cmd!(sh, "cargo build -p xflags --examples --target wasm32-wasip1").run()?;
+        cmd!(sh, "cargo build -p xflags --target wasm32-unknown-unknown").run()?;
+    }
+
     {
         let _s = section("TEST");
         cmd!(sh, "cargo test --workspace -- --nocapture").run()?;